@@ -131,7 +131,7 @@ fn build_action_rule_in_200k(c: &mut Criterion) {
     group.bench_function("build_action_rule_in_200k", |b| {
         b.iter(|| {
             let rules = router.match_request(&request);
-            let mut action = Action::from_routes_rule(rules.clone(), &request, None);
+            let mut action = Action::from_routes_rule(&router, rules.clone(), &request, None);
 
             let action_status_code = action.get_status_code(0, None);
             let (_, backend_status_code) = if action_status_code != 0 {
@@ -183,7 +183,7 @@ fn impact(c: &mut Criterion) {
     group.bench_function("impact", |b| {
         b.iter(|| {
             let rules = router.match_request(&request);
-            let mut action = Action::from_routes_rule(rules.clone(), &request, None);
+            let mut action = Action::from_routes_rule(&router, rules.clone(), &request, None);
             let unit_trace = Some(Rc::new(RefCell::new(UnitTrace::default())));
 
             let action_status_code = action.get_status_code(0, unit_trace.clone());
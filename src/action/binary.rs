@@ -0,0 +1,92 @@
+use crate::action::Action;
+
+impl Action {
+    /// Encode the action in its compact binary form (MessagePack), to be sent in place of the JSON one
+    /// when the proxy module speaks protocol 1.2 or later
+    ///
+    /// Field names are kept so optional fields can be omitted and older actions still decode
+    pub fn to_binary(&self) -> Result<Vec<u8>, rmp_serde::encode::Error> {
+        rmp_serde::to_vec_named(self)
+    }
+
+    /// Decode an action encoded with `Action::to_binary`
+    pub fn from_binary(data: &[u8]) -> Result<Action, rmp_serde::decode::Error> {
+        rmp_serde::from_slice(data)
+    }
+}
+
+#[cfg(all(test, feature = "router"))]
+mod tests {
+    use serde_json::{from_str as json_decode, to_value as json_value};
+
+    use crate::{
+        action::Action,
+        api::{Rule, TestExamplesInput},
+        http::Request,
+        router::Router,
+    };
+
+    fn assert_round_trip(action: &Action) {
+        let binary = action.to_binary().unwrap();
+        let decoded = Action::from_binary(&binary).unwrap();
+
+        assert_eq!(json_value(action).unwrap(), json_value(&decoded).unwrap());
+    }
+
+    #[test]
+    fn default_action_round_trips() {
+        let mut action = Action::default();
+        action.set_agent_protocol_version(1, 2);
+
+        assert_round_trip(&action);
+    }
+
+    #[test]
+    fn binary_is_smaller_than_json() {
+        let json_in = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/test_examples/filter_html.in.json")).unwrap();
+        let input: TestExamplesInput = json_decode(&json_in).unwrap();
+        let mut router = Router::<Rule>::from_config(input.router_config.clone());
+
+        for rule in input.rules {
+            router.insert(rule);
+        }
+
+        let request = Request::from_config(&router.config, "/source".to_string(), None, None, None, None, None);
+        let (action, _) = Action::from_router(&router, &request, None);
+
+        assert!(action.to_binary().unwrap().len() < serde_json::to_vec(&action).unwrap().len());
+    }
+
+    #[test]
+    fn actions_of_test_examples_round_trip() {
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/test_examples");
+        let mut count = 0;
+
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+
+            if !path.to_string_lossy().ends_with(".in.json") {
+                continue;
+            }
+
+            let input: TestExamplesInput = json_decode(&std::fs::read_to_string(&path).unwrap()).unwrap();
+            let mut router = Router::<Rule>::from_config(input.router_config.clone());
+
+            for rule in input.rules.iter() {
+                router.insert(rule.clone());
+            }
+
+            for example in input.rules.iter().filter_map(|rule| rule.examples.as_ref()).flatten() {
+                let Ok(request) = Request::from_example(&router.config, example) else {
+                    continue;
+                };
+                let (action, _) = Action::from_router(&router, &request, None);
+
+                assert_round_trip(&action);
+                count += 1;
+            }
+        }
+
+        assert!(count > 0);
+    }
+}
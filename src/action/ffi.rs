@@ -1,13 +1,15 @@
-use std::{os::raw::c_char, ptr::null};
+use std::{os::raw::c_char, ptr::null, sync::Arc};
 
 use serde_json::{from_str as json_decode, to_string as json_encode};
 
 use crate::{
-    action::Action,
+    action::{Action, LogSampler, PeerHealth},
     ffi_helpers::{c_char_to_str, string_to_c_char},
     filter::{Buffer, FilterBodyAction},
     http::ffi::{HeaderMap, header_map_to_http_headers, http_headers_to_header_map},
 };
+#[cfg(feature = "router")]
+use crate::{api::Rule, router::Router, router_config::RouterConfig};
 
 /// Deserialize a string to an action
 ///
@@ -54,6 +56,57 @@ pub extern "C" fn redirectionio_action_json_serialize(_action: *mut Action) -> *
     string_to_c_char(action_serialized)
 }
 
+/// Deserialize an action from its binary encoding, sent instead of the JSON one by agents
+/// speaking protocol >= 1.2
+///
+/// Returns null if an error happens, otherwise it returns a pointer to an action
+///
+/// # Safety
+///
+/// `data` must point to at least `len` readable bytes, or be null when `len` is 0.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn redirectionio_action_binary_deserialize(data: *const u8, len: usize) -> *const Action {
+    if data.is_null() || len == 0 {
+        return null();
+    }
+
+    // SAFETY: data points to len readable bytes as guaranteed by the caller
+    let bytes = unsafe { std::slice::from_raw_parts(data, len) };
+
+    let action = match Action::from_binary(bytes) {
+        Err(error) => {
+            tracing::error!("unable to deserialize binary action of {len} bytes: {error}");
+
+            return null();
+        }
+        Ok(action) => action,
+    };
+
+    Box::into_raw(Box::new(action))
+}
+
+/// Serialize an action to its binary encoding
+///
+/// Returns an empty buffer if an error happens
+#[unsafe(no_mangle)]
+pub extern "C" fn redirectionio_action_binary_serialize(_action: *mut Action) -> Buffer {
+    if _action.is_null() {
+        return Buffer::default();
+    }
+
+    // SAFETY: _action is a valid pointer to an Action
+    let action = unsafe { &*_action };
+
+    match action.to_binary() {
+        Err(error) => {
+            tracing::error!("unable to serialize action to binary: {error}");
+
+            Buffer::default()
+        }
+        Ok(action_serialized) => Buffer::from_vec(action_serialized),
+    }
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn redirectionio_action_drop(_action: *mut Action) {
     if _action.is_null() {
@@ -76,6 +129,25 @@ pub extern "C" fn redirectionio_action_get_status_code(_action: *mut Action, res
     action.get_status_code(response_status_code, None)
 }
 
+/// Same as `redirectionio_action_get_status_code`, with the headers of the backend response,
+/// needed by rules conditioned on them. Call it before the headers are filtered.
+#[unsafe(no_mangle)]
+pub extern "C" fn redirectionio_action_get_status_code_with_response_headers(
+    _action: *mut Action,
+    response_status_code: u16,
+    response_header_map: *const HeaderMap,
+) -> u16 {
+    if _action.is_null() {
+        return 0;
+    }
+
+    // SAFETY: _action is a valid pointer to an Action
+    let action = unsafe { &mut *_action };
+    let headers = header_map_to_http_headers(response_header_map);
+
+    action.get_status_code_with_response_headers(response_status_code, Some(&headers), None)
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn redirectionio_action_header_filter_filter(
     _action: *mut Action,
@@ -96,6 +168,104 @@ pub extern "C" fn redirectionio_action_header_filter_filter(
     http_headers_to_header_map(headers)
 }
 
+/// Filter the headers of the request before sending it to the upstream
+///
+/// Returns the given header map when there is no action
+#[unsafe(no_mangle)]
+pub extern "C" fn redirectionio_action_request_header_filter_filter(
+    _action: *mut Action,
+    header_map: *const HeaderMap,
+) -> *const HeaderMap {
+    if _action.is_null() {
+        return header_map;
+    }
+
+    // SAFETY: _action is a valid pointer to an Action
+    let action = unsafe { &mut *_action };
+    let mut headers = header_map_to_http_headers(header_map);
+
+    headers = action.filter_request_headers(headers, None);
+
+    http_headers_to_header_map(headers)
+}
+
+/// Path and query to send to the upstream instead of the requested ones, the client url is
+/// unchanged
+///
+/// Returns null when the request is not rewritten. The returned string must be freed with
+/// `redirectionio_string_drop`.
+#[unsafe(no_mangle)]
+pub extern "C" fn redirectionio_action_get_rewrite(_action: *mut Action) -> *const c_char {
+    if _action.is_null() {
+        return null();
+    }
+
+    // SAFETY: _action is a valid pointer to an Action
+    let action = unsafe { &mut *_action };
+
+    match action.get_rewrite(None) {
+        None => null(),
+        Some(path_and_query) => string_to_c_char(path_and_query.to_string()),
+    }
+}
+
+/// Status code of the response to serve instead of calling the backend
+///
+/// Returns 0 when the backend must be called
+#[unsafe(no_mangle)]
+pub extern "C" fn redirectionio_action_synthetic_response_status_code(_action: *mut Action) -> u16 {
+    if _action.is_null() {
+        return 0;
+    }
+
+    // SAFETY: _action is a valid pointer to an Action
+    let action = unsafe { &mut *_action };
+
+    match action.get_synthetic_response(None) {
+        None => 0,
+        Some(synthetic_response) => synthetic_response.status_code,
+    }
+}
+
+/// Headers of the response to serve instead of calling the backend, they should then go
+/// through `redirectionio_action_header_filter_filter` like backend response headers
+///
+/// Returns null when the backend must be called
+#[unsafe(no_mangle)]
+pub extern "C" fn redirectionio_action_synthetic_response_headers(_action: *mut Action) -> *const HeaderMap {
+    if _action.is_null() {
+        return null();
+    }
+
+    // SAFETY: _action is a valid pointer to an Action
+    let action = unsafe { &mut *_action };
+
+    match action.get_synthetic_response(None) {
+        None => null(),
+        Some(synthetic_response) => http_headers_to_header_map(synthetic_response.headers.clone()),
+    }
+}
+
+/// Body of the response to serve instead of calling the backend
+///
+/// Returns an empty buffer when the backend must be called
+#[unsafe(no_mangle)]
+pub extern "C" fn redirectionio_action_synthetic_response_body(_action: *mut Action) -> Buffer {
+    if _action.is_null() {
+        return Buffer::default();
+    }
+
+    // SAFETY: _action is a valid pointer to an Action
+    let action = unsafe { &mut *_action };
+
+    match action.get_synthetic_response(None) {
+        None => Buffer::default(),
+        Some(synthetic_response) => Buffer::from_vec(synthetic_response.body.clone().into_bytes()),
+    }
+}
+
+/// `redirectionio_action_header_filter_filter` must have been called on this action before, as it
+/// decides whether the response body is compressed
 #[unsafe(no_mangle)]
 pub extern "C" fn redirectionio_action_body_filter_create(
     _action: *mut Action,
@@ -169,6 +339,63 @@ pub extern "C" fn redirectionio_action_should_log_request(_action: *mut Action,
     action.should_log_request(allow_log_config, response_status_code, None)
 }
 
+/// Same as `redirectionio_action_should_log_request`, with the headers of the backend response,
+/// needed by rules conditioned on them
+#[unsafe(no_mangle)]
+pub extern "C" fn redirectionio_action_should_log_request_with_response_headers(
+    _action: *mut Action,
+    allow_log_config: bool,
+    response_status_code: u16,
+    response_header_map: *const HeaderMap,
+) -> bool {
+    if _action.is_null() {
+        return allow_log_config;
+    }
+
+    // SAFETY: _action is a valid pointer to an Action
+    let action = unsafe { &mut *_action };
+    let headers = header_map_to_http_headers(response_header_map);
+
+    action.should_log_request_with_response_headers(allow_log_config, response_status_code, Some(&headers), None)
+}
+
+/// Create the counters of sampled logs, to share between all requests and threads of a proxy,
+/// it must be dropped with `redirectionio_log_sampler_drop`
+#[unsafe(no_mangle)]
+pub extern "C" fn redirectionio_log_sampler_create() -> *const LogSampler {
+    Arc::into_raw(Arc::new(LogSampler::default()))
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn redirectionio_log_sampler_drop(_sampler: *const LogSampler) {
+    if _sampler.is_null() {
+        return;
+    }
+
+    // SAFETY: _sampler is a valid pointer returned by redirectionio_log_sampler_create
+    drop(unsafe { Arc::from_raw(_sampler) });
+}
+
+/// Use the counters of sampled logs of the proxy for this action, before calling
+/// `redirectionio_action_should_log_request`, so the limit of logs per interval of a rule applies
+#[unsafe(no_mangle)]
+pub extern "C" fn redirectionio_action_set_log_sampler(_action: *mut Action, _sampler: *const LogSampler) {
+    if _action.is_null() || _sampler.is_null() {
+        return;
+    }
+
+    // SAFETY: _action is a valid pointer to an Action
+    let action = unsafe { &mut *_action };
+    // SAFETY: _sampler is a valid pointer returned by redirectionio_log_sampler_create, the
+    // action keeps its own reference
+    let sampler = unsafe {
+        Arc::increment_strong_count(_sampler);
+        Arc::from_raw(_sampler)
+    };
+
+    action.set_log_sampler(sampler);
+}
+
 /// Serialize the ids of the rules applied by this action as a JSON array of strings.
 ///
 /// Used to count rule executions when logging is disabled for the request (so no log
@@ -198,6 +425,252 @@ pub extern "C" fn redirectionio_action_get_applied_rule_ids(_action: *mut Action
     }
 }
 
+/// Serialize the peer to retry the request on as JSON, after the backend responded with
+/// `response_status_code`. `attempt` is the number of fallback peers already tried, starting
+/// at 0.
+///
+/// Returns null when the request must not be retried: no fallback peer is left, the status
+/// code does not trigger a retry, or the method is not allowed to be sent again (only GET
+/// and HEAD by default). The returned string must be freed with `redirectionio_string_drop`.
+#[unsafe(no_mangle)]
+pub extern "C" fn redirectionio_action_get_retry_peer(
+    _action: *mut Action,
+    method: *const c_char,
+    response_status_code: u16,
+    attempt: usize,
+) -> *const c_char {
+    if _action.is_null() {
+        return null();
+    }
+
+    let method = match c_char_to_str(method) {
+        None => return null(),
+        Some(method) => method,
+    };
+
+    // SAFETY: _action is a valid pointer to an Action
+    let action = unsafe { &mut *_action };
+
+    let peer = match action.get_retry_peer(method, response_status_code, attempt, None) {
+        None => return null(),
+        Some(peer) => peer,
+    };
+
+    match json_encode(peer) {
+        Err(error) => {
+            tracing::error!("unable to serialize retry peer: {error}");
+
+            null()
+        }
+        Ok(serialized) => string_to_c_char(serialized),
+    }
+}
+
+/// Same as `redirectionio_action_get_retry_peer`, with the headers of the backend response,
+/// needed by rules conditioned on them. Call it before the headers are filtered.
+#[unsafe(no_mangle)]
+pub extern "C" fn redirectionio_action_get_retry_peer_with_response_headers(
+    _action: *mut Action,
+    method: *const c_char,
+    response_status_code: u16,
+    response_header_map: *const HeaderMap,
+    attempt: usize,
+) -> *const c_char {
+    if _action.is_null() {
+        return null();
+    }
+
+    let method = match c_char_to_str(method) {
+        None => return null(),
+        Some(method) => method,
+    };
+
+    // SAFETY: _action is a valid pointer to an Action
+    let action = unsafe { &mut *_action };
+    let headers = header_map_to_http_headers(response_header_map);
+
+    let peer = match action.get_retry_peer_with_response_headers(method, response_status_code, Some(&headers), attempt, None) {
+        None => return null(),
+        Some(peer) => peer,
+    };
+
+    match json_encode(peer) {
+        Err(error) => {
+            tracing::error!("unable to serialize retry peer: {error}");
+
+            null()
+        }
+        Ok(serialized) => string_to_c_char(serialized),
+    }
+}
+
+/// Create the router where the `rewrite_links` html body filters and the `edit_xml` edits with
+/// `redirect` look up links, from a JSON array of rules and an optional JSON router config.
+/// It is shared between all requests and threads of a proxy, and must be dropped with
+/// `redirectionio_link_router_drop`.
+///
+/// Returns null if the rules or the config cannot be deserialized
+#[cfg(feature = "router")]
+#[unsafe(no_mangle)]
+pub extern "C" fn redirectionio_link_router_create(rules: *const c_char, config: *const c_char) -> *const Router<Rule> {
+    let rules_str = match c_char_to_str(rules) {
+        None => return null(),
+        Some(str) => str,
+    };
+
+    let rules: Vec<Rule> = match json_decode(rules_str) {
+        Err(error) => {
+            tracing::error!("unable to deserialize rules of link router: {error}");
+
+            return null();
+        }
+        Ok(rules) => rules,
+    };
+
+    let config: RouterConfig = match c_char_to_str(config) {
+        None => RouterConfig::default(),
+        Some(config_str) => match json_decode(config_str) {
+            Err(error) => {
+                tracing::error!("unable to deserialize \"{config_str}\" to router config: {error}");
+
+                return null();
+            }
+            Ok(config) => config,
+        },
+    };
+
+    let mut router = Router::<Rule>::from_config(config);
+
+    for rule in rules {
+        router.insert(rule);
+    }
+
+    router.cache(None);
+
+    Arc::into_raw(Arc::new(router))
+}
+
+#[cfg(feature = "router")]
+#[unsafe(no_mangle)]
+pub extern "C" fn redirectionio_link_router_drop(_router: *const Router<Rule>) {
+    if _router.is_null() {
+        return;
+    }
+
+    // SAFETY: _router is a valid pointer returned by redirectionio_link_router_create
+    drop(unsafe { Arc::from_raw(_router) });
+}
+
+/// Look up the links found by body filters of this action in the router, before calling
+/// `redirectionio_action_body_filter_create`. Relative links are resolved against `request_url`
+/// when not null, at most `max_link_lookups` distinct links are looked up by response, 0 for
+/// the default.
+#[cfg(feature = "router")]
+#[unsafe(no_mangle)]
+pub extern "C" fn redirectionio_action_set_link_router(
+    _action: *mut Action,
+    _router: *const Router<Rule>,
+    request_url: *const c_char,
+    max_link_lookups: usize,
+) {
+    if _action.is_null() || _router.is_null() {
+        return;
+    }
+
+    // SAFETY: _action is a valid pointer to an Action
+    let action = unsafe { &mut *_action };
+    // SAFETY: _router is a valid pointer returned by redirectionio_link_router_create, the
+    // action keeps its own reference
+    let router = unsafe {
+        Arc::increment_strong_count(_router);
+        Arc::from_raw(_router)
+    };
+
+    action.set_link_router(
+        router,
+        c_char_to_str(request_url),
+        (max_link_lookups > 0).then_some(max_link_lookups),
+    );
+}
+
+/// Create the failure state of the peers of peer pools, to share between all requests and
+/// threads of a proxy, it must be dropped with `redirectionio_peer_health_drop`
+#[unsafe(no_mangle)]
+pub extern "C" fn redirectionio_peer_health_create() -> *const PeerHealth {
+    Arc::into_raw(Arc::new(PeerHealth::default()))
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn redirectionio_peer_health_drop(_health: *const PeerHealth) {
+    if _health.is_null() {
+        return;
+    }
+
+    // SAFETY: _health is a valid pointer returned by redirectionio_peer_health_create
+    drop(unsafe { Arc::from_raw(_health) });
+}
+
+/// Report a failure of the peer with this address, it is skipped by peer pools for their cooldown
+#[unsafe(no_mangle)]
+pub extern "C" fn redirectionio_peer_health_report_failure(_health: *const PeerHealth, address: *const c_char) {
+    if _health.is_null() {
+        return;
+    }
+
+    // SAFETY: _health is a valid pointer to a PeerHealth
+    let health = unsafe { &*_health };
+
+    if let Some(address) = c_char_to_str(address) {
+        health.report_failure(address);
+    }
+}
+
+/// Report a success of the peer with this address, ending its cooldown
+#[unsafe(no_mangle)]
+pub extern "C" fn redirectionio_peer_health_report_success(_health: *const PeerHealth, address: *const c_char) {
+    if _health.is_null() {
+        return;
+    }
+
+    // SAFETY: _health is a valid pointer to a PeerHealth
+    let health = unsafe { &*_health };
+
+    if let Some(address) = c_char_to_str(address) {
+        health.report_success(address);
+    }
+}
+
+/// Serialize the peer to send the request to as JSON, chosen in the peer pool of the action
+/// when there is one. `_health` may be null, then no peer is skipped.
+///
+/// Returns null when there is no peer override. The returned string must be freed with
+/// `redirectionio_string_drop`.
+#[unsafe(no_mangle)]
+pub extern "C" fn redirectionio_action_select_peer(_action: *mut Action, _health: *const PeerHealth) -> *const c_char {
+    if _action.is_null() {
+        return null();
+    }
+
+    // SAFETY: _action is a valid pointer to an Action
+    let action = unsafe { &mut *_action };
+    // SAFETY: _health is null or a valid pointer to a PeerHealth
+    let health = unsafe { _health.as_ref() };
+
+    let peer = match action.select_peer(health, None) {
+        None => return null(),
+        Some(peer) => peer,
+    };
+
+    match json_encode(peer) {
+        Err(error) => {
+            tracing::error!("unable to serialize selected peer: {error}");
+
+            null()
+        }
+        Ok(serialized) => string_to_c_char(serialized),
+    }
+}
+
 /// Whether the agent that produced this action understands the `RULE_COUNT` command
 /// (protocol >= 1.1), as advertised in the MATCH response. A proxy module must check this
 /// before sending `RULE_COUNT`, so it never sends it to an older agent that would reject
@@ -214,3 +687,18 @@ pub extern "C" fn redirectionio_action_agent_supports_rule_count(_action: *mut A
 
     action.agent_supports_rule_count()
 }
+
+/// Whether the agent that produced this action sends the following ones in their binary
+/// encoding (protocol >= 1.2), to be read with `redirectionio_action_binary_deserialize`.
+/// Returns false on a null action or when the agent advertised an older version.
+#[unsafe(no_mangle)]
+pub extern "C" fn redirectionio_action_agent_supports_binary_action(_action: *mut Action) -> bool {
+    if _action.is_null() {
+        return false;
+    }
+
+    // SAFETY: _action is a valid pointer to an Action
+    let action = unsafe { &*_action };
+
+    action.agent_supports_binary_action()
+}
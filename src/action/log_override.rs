@@ -1,27 +1,41 @@
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    action::{ResponseHeaderCondition, log_sampling::LogSampling},
+    api::StatusCodes,
+};
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct LogOverride {
     pub log_override: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sampling: Option<LogSampling>,
     pub rule_id: Option<String>,
-    pub on_response_status_codes: Vec<u16>,
+    pub on_response_status_codes: StatusCodes,
     pub exclude_response_status_codes: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub on_response_headers: Vec<ResponseHeaderCondition>,
     pub fallback_log_override: Option<bool>,
     pub fallback_rule_id: Option<String>,
     pub unit_id: Option<String>,
 }
 
 impl LogOverride {
-    pub fn get_log_override(&self, response_status_code: u16) -> (Option<bool>, Option<String>, bool) {
-        if self.on_response_status_codes.is_empty() {
-            return (Some(self.log_override), self.rule_id.clone(), true);
-        }
-
-        if self.exclude_response_status_codes && !self.on_response_status_codes.contains(&response_status_code) {
-            return (Some(self.log_override), self.rule_id.clone(), true);
-        }
+    /// Whether this override depends on the backend response
+    pub fn is_conditional(&self) -> bool {
+        !self.on_response_status_codes.is_empty() || !self.on_response_headers.is_empty()
+    }
 
-        if !self.exclude_response_status_codes && self.on_response_status_codes.contains(&response_status_code) {
+    /// Log override for this response, `response_headers_matched` telling whether the response
+    /// headers satisfy `on_response_headers`
+    pub fn get_log_override(&self, response_status_code: u16, response_headers_matched: bool) -> (Option<bool>, Option<String>, bool) {
+        if (self.on_response_headers.is_empty() || response_headers_matched)
+            && (self.on_response_status_codes.is_empty()
+                || self
+                    .on_response_status_codes
+                    .matches(self.exclude_response_status_codes, response_status_code))
+        {
             return (Some(self.log_override), self.rule_id.clone(), true);
         }
 
@@ -0,0 +1,110 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// Sampling of the logs of a rule, decided for the request when the action is created
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LogSampling {
+    pub rate: f64,
+    /// Whether the hash of the request falls in the sampling rate
+    pub sampled: bool,
+    pub limit: Option<u32>,
+    pub interval: u64,
+}
+
+impl LogSampling {
+    /// Number of requests a logged request stands for, `sampled_requests` being the number of
+    /// sampled requests it accounts for, above 1 when sampled requests were dropped by the limit
+    pub fn weight(&self, sampled_requests: u32) -> f64 {
+        let weight = if self.rate > 0.0 { 1.0 / self.rate.min(1.0) } else { 1.0 };
+
+        weight * sampled_requests.max(1) as f64
+    }
+}
+
+/// Number of requests logged per rule in the current interval, shared between requests and
+/// threads to enforce sampling limits
+#[derive(Debug, Default)]
+pub struct LogSampler {
+    windows: Mutex<HashMap<String, LogWindow>>,
+}
+
+#[derive(Debug)]
+struct LogWindow {
+    started_at: i64,
+    count: u32,
+    // Sampled requests dropped by the limit since the last logged one
+    dropped: u32,
+}
+
+impl LogSampler {
+    /// Count a request to log for the rule, returns None when its limit is reached in the
+    /// interval. Otherwise returns the number of sampled requests the log accounts for: this
+    /// request and the ones dropped since the last logged request, so the weights of the logs
+    /// still add up to the number of requests.
+    pub fn acquire(&self, rule_id: &str, limit: u32, interval: u64) -> Option<u32> {
+        let mut windows = match self.windows.lock() {
+            Ok(windows) => windows,
+            Err(_) => return Some(1),
+        };
+
+        let now = Utc::now().timestamp_millis();
+        let window = windows.entry(rule_id.to_string()).or_insert(LogWindow {
+            started_at: now,
+            count: 0,
+            dropped: 0,
+        });
+
+        if now - window.started_at >= (interval as i64).saturating_mul(1000) {
+            window.started_at = now;
+            window.count = 0;
+        }
+
+        if window.count >= limit {
+            window.dropped = window.dropped.saturating_add(1);
+
+            return None;
+        }
+
+        window.count += 1;
+
+        Some(1 + std::mem::take(&mut window.dropped))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn acquire_respects_limit_per_rule() {
+        let sampler = LogSampler::default();
+
+        assert_eq!(sampler.acquire("a", 2, 3600), Some(1));
+        assert_eq!(sampler.acquire("a", 2, 3600), Some(1));
+        assert_eq!(sampler.acquire("a", 2, 3600), None);
+        assert_eq!(sampler.acquire("b", 2, 3600), Some(1));
+    }
+
+    #[test]
+    fn acquire_accounts_for_dropped_requests() {
+        let sampler = LogSampler::default();
+
+        assert_eq!(sampler.acquire("a", 1, 3600), Some(1));
+        assert_eq!(sampler.acquire("a", 1, 3600), None);
+        assert_eq!(sampler.acquire("a", 1, 3600), None);
+        assert_eq!(sampler.acquire("a", 1, 0), Some(3));
+        assert_eq!(sampler.acquire("a", 1, 0), Some(1));
+
+        let sampling = LogSampling {
+            rate: 0.5,
+            sampled: true,
+            limit: Some(1),
+            interval: 0,
+        };
+
+        assert_eq!(sampling.weight(3), 6.0);
+    }
+}
@@ -1,17 +1,25 @@
+mod binary;
 #[cfg(not(target_arch = "wasm32"))]
 mod ffi;
 mod log_override;
+mod log_sampling;
+mod peer_fallback;
 mod peer_override;
+mod peer_pool;
+mod response_compression;
+mod response_header_condition;
+mod rewrite_override;
 #[cfg(feature = "router")]
 mod run;
 mod status_code_update;
+mod synthetic_response_override;
 #[cfg(feature = "router")]
 mod trace;
 mod unit_trace;
 
-use std::{cell::RefCell, fmt::Debug, rc::Rc};
+use std::{cell::RefCell, fmt::Debug, rc::Rc, sync::Arc};
 #[cfg(feature = "router")]
-use std::{iter::FromIterator, sync::Arc};
+use std::{collections::HashSet, iter::FromIterator};
 
 use linked_hash_set::LinkedHashSet;
 #[cfg(feature = "router")]
@@ -21,20 +29,33 @@ pub use status_code_update::StatusCodeUpdate;
 #[cfg(feature = "router")]
 pub use trace::TraceAction;
 
-pub use crate::action::unit_trace::UnitTrace;
 #[cfg(feature = "router")]
-use crate::api::Rule;
+use crate::action::log_sampling::LogSampling;
+pub use crate::action::{
+    log_sampling::LogSampler, peer_pool::PeerHealth, response_compression::DEFAULT_MIN_COMPRESSION_SIZE,
+    response_header_condition::ResponseHeaderCondition, unit_trace::UnitTrace,
+};
+#[cfg(feature = "router")]
+use crate::api::{HTMLBodyFilter, HTMLBodyFilterAttribute, TextAction, TextBodyFilter, escape_variables};
+#[cfg(feature = "router")]
+use crate::api::{LogSampling as RuleLogSampling, Rule, RuleSuggestion};
+#[cfg(feature = "router")]
+use crate::filter::{LinkRedirector, LinkRouter};
 #[cfg(feature = "router")]
-use crate::api::TextBodyFilter;
+use crate::hash::stable_unit_hash;
 #[cfg(feature = "router")]
 use crate::http::Request;
 #[cfg(feature = "router")]
 use crate::marker::StaticOrDynamic;
 #[cfg(feature = "router")]
-use crate::router::Route;
+use crate::router::{Route, Router};
 use crate::{
-    action::{log_override::LogOverride, peer_override::PeerOverride},
-    api::{BodyFilter, HeaderFilter, Peer, VariableValue},
+    action::{
+        log_override::LogOverride, peer_fallback::PeerFallback, peer_override::PeerOverride, peer_pool::PeerPoolOverride,
+        response_compression::ResponseCompressionOverride, response_header_condition::ResponseHeaderCondition as HeaderCondition,
+        rewrite_override::RewriteOverride, synthetic_response_override::SyntheticResponseOverride,
+    },
+    api::{BodyFilter, HeaderFilter, Peer, StatusCodes, SyntheticResponse, VariableValue},
     filter::{FilterBodyAction, FilterHeaderAction},
     http::Header,
 };
@@ -43,23 +64,39 @@ use crate::{
 /// advertises it inside the MATCH action (see `Action::agent_protocol_version_*`) so a
 /// proxy module can tell whether the agent understands newer commands before sending
 /// them. Mirrors `REDIRECTIONIO_PROTOCOL_VERSION_MAJOR`/`_MINOR` in the C modules.
-/// `RULE_COUNT` was introduced in 1.1.
+/// `RULE_COUNT` was introduced in 1.1, the binary encoding of the action (see
+/// `Action::to_binary`) in 1.2.
 pub const PROTOCOL_VERSION_MAJOR: u8 = 1;
-pub const PROTOCOL_VERSION_MINOR: u8 = 1;
+pub const PROTOCOL_VERSION_MINOR: u8 = 2;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Action {
     status_code_update: Option<StatusCodeUpdate>,
     header_filters: Vec<HeaderFilterAction>,
     body_filters: Vec<BodyFilterAction>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    request_header_filters: Vec<RequestHeaderFilterAction>,
     // In 3.0 remove this
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<String>"))]
     pub rule_ids: LinkedHashSet<String>,
     #[serde(default)]
     rule_traces: Vec<RuleTrace>,
     #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<String>"))]
     pub rules_applied: LinkedHashSet<String>,
     log_override: Option<LogOverride>,
     peer_override: Option<PeerOverride>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    peer_pool: Option<PeerPoolOverride>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    peer_fallback: Option<PeerFallback>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rewrite_override: Option<RewriteOverride>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    synthetic_response: Option<SyntheticResponseOverride>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    response_compression: Option<ResponseCompressionOverride>,
     #[serde(default)]
     variables: Vec<(String, VariableValue)>,
     // Protocol version the agent speaks, advertised to proxy modules in the MATCH
@@ -69,45 +106,95 @@ pub struct Action {
     agent_protocol_version_major: u8,
     #[serde(default)]
     agent_protocol_version_minor: u8,
+    // Shared counters of sampled logs, set by the proxy
+    #[serde(skip)]
+    log_sampler: Option<Arc<LogSampler>>,
+    // Weight of the request decided by `should_log_request` when its logs are sampled
+    #[serde(skip)]
+    log_weight: Option<f64>,
+    // Router where body filters look up links, set by the proxy
+    #[cfg(feature = "router")]
+    #[serde(skip)]
+    link_router: Option<LinkRouter>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RuleTrace {
     id: String,
-    on_response_status_codes: Vec<u16>,
+    on_response_status_codes: StatusCodes,
     exclude_response_status_codes: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    on_response_headers: Vec<HeaderCondition>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 struct HeaderFilterAction {
     filter: HeaderFilter,
-    on_response_status_codes: Vec<u16>,
+    on_response_status_codes: StatusCodes,
     exclude_response_status_codes: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    on_response_headers: Vec<HeaderCondition>,
     rule_id: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+struct RequestHeaderFilterAction {
+    filter: HeaderFilter,
+    rule_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 struct BodyFilterAction {
     filter: BodyFilter,
-    on_response_status_codes: Vec<u16>,
+    on_response_status_codes: StatusCodes,
     exclude_response_status_codes: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    on_response_headers: Vec<HeaderCondition>,
     rule_id: Option<String>,
 }
 
+impl BodyFilterAction {
+    fn matches(&self, response_status_code: u16, headers: &[Header], unit_trace: &Option<Rc<RefCell<UnitTrace>>>) -> bool {
+        if !self.on_response_status_codes.is_empty()
+            && !self
+                .on_response_status_codes
+                .matches(self.exclude_response_status_codes, response_status_code)
+        {
+            return false;
+        }
+
+        match_response_headers(&self.on_response_headers, self.rule_id.as_deref(), Some(headers), unit_trace)
+    }
+}
+
 impl Default for Action {
     fn default() -> Action {
         Action {
             status_code_update: None,
             header_filters: Vec::new(),
             body_filters: Vec::new(),
+            request_header_filters: Vec::new(),
             rule_ids: LinkedHashSet::new(),
             rule_traces: Vec::new(),
             rules_applied: LinkedHashSet::new(),
             log_override: None,
             peer_override: None,
+            peer_pool: None,
+            peer_fallback: None,
+            rewrite_override: None,
+            synthetic_response: None,
+            response_compression: None,
             variables: Vec::new(),
             agent_protocol_version_major: 0,
             agent_protocol_version_minor: 0,
+            log_sampler: None,
+            #[cfg(feature = "router")]
+            link_router: None,
+            log_weight: None,
         }
     }
 }
@@ -130,6 +217,12 @@ impl Action {
         (self.agent_protocol_version_major, self.agent_protocol_version_minor) >= (1, 1)
     }
 
+    /// Whether the agent that produced this action can send the following ones in their binary
+    /// encoding, introduced in protocol 1.2.
+    pub fn agent_supports_binary_action(&self) -> bool {
+        (self.agent_protocol_version_major, self.agent_protocol_version_minor) >= (1, 2)
+    }
+
     pub fn get_applied_rule_ids_vec(&self) -> Vec<String> {
         self.rules_applied.iter().cloned().collect::<Vec<String>>()
     }
@@ -174,10 +267,8 @@ impl Action {
             }
         }
 
-        let on_response_status_codes = match rule.source.response_status_codes.as_ref() {
-            None => Vec::new(),
-            Some(codes) => codes.clone(),
-        };
+        let on_response_status_codes = rule.source.response_status_codes.clone().unwrap_or_default();
+        let on_response_headers = rule.response_header_conditions();
 
         let status_code_update = match rule.status_code.unwrap_or(0) {
             0 => None,
@@ -185,6 +276,7 @@ impl Action {
                 status_code: redirect_code,
                 on_response_status_codes: on_response_status_codes.clone(),
                 exclude_response_status_codes: rule.source.exclude_response_status_codes.is_some(),
+                on_response_headers: on_response_headers.clone(),
                 fallback_status_code: 0,
                 rule_id: Some(rule.id.clone()),
                 fallback_rule_id: None,
@@ -219,11 +311,9 @@ impl Action {
                     id: rule.redirect_unit_id.clone(),
                     target_hash: rule.target_hash.clone(),
                 },
-                on_response_status_codes: match rule.source.response_status_codes.as_ref() {
-                    None => Vec::new(),
-                    Some(on_response) => on_response.clone(),
-                },
+                on_response_status_codes: on_response_status_codes.clone(),
                 exclude_response_status_codes: rule.source.exclude_response_status_codes.is_some(),
+                on_response_headers: on_response_headers.clone(),
                 rule_id: Some(rule.id.clone()),
             })
         }
@@ -240,6 +330,29 @@ impl Action {
                     },
                     on_response_status_codes: on_response_status_codes.clone(),
                     exclude_response_status_codes: rule.source.exclude_response_status_codes.is_some(),
+                    on_response_headers: on_response_headers.clone(),
+                    rule_id: Some(rule.id.clone()),
+                });
+            }
+        }
+
+        let mut request_header_filters = Vec::new();
+
+        // Request headers are sent before the response is known, like the peer override they
+        // cannot depend on it
+        if let Some(rule_request_header_filters) = rule.request_header_filters.as_ref()
+            && on_response_status_codes.is_empty()
+            && on_response_headers.is_empty()
+        {
+            for filter in rule_request_header_filters {
+                request_header_filters.push(RequestHeaderFilterAction {
+                    filter: HeaderFilter {
+                        action: filter.action.clone(),
+                        header: filter.header.clone(),
+                        value: StaticOrDynamic::replace(filter.value.clone(), &variables, true),
+                        id: filter.id.clone(),
+                        target_hash: filter.target_hash.clone(),
+                    },
                     rule_id: Some(rule.id.clone()),
                 });
             }
@@ -249,20 +362,59 @@ impl Action {
             for filter in rule_body_filters {
                 body_filters.push(BodyFilterAction {
                     filter: match filter {
+                        BodyFilter::HTML(HTMLBodyFilter::ReplaceAttribute(filter)) => {
+                            // Same as replace_regex, variables are inserted literally
+                            let pattern_variables = escape_variables(&variables, regex::escape);
+                            let value_variables = escape_variables(&variables, |value| value.replace('$', "$$"));
+
+                            BodyFilter::HTML(HTMLBodyFilter::ReplaceAttribute(HTMLBodyFilterAttribute {
+                                css_selector: filter.css_selector.clone(),
+                                attribute: filter.attribute.clone(),
+                                value: StaticOrDynamic::replace(filter.value.clone(), &value_variables, true),
+                                pattern: filter
+                                    .pattern
+                                    .as_ref()
+                                    .map(|pattern| StaticOrDynamic::replace(pattern.clone(), &pattern_variables, true)),
+                                id: filter.id.clone(),
+                                target_hash: filter.target_hash.clone(),
+                            }))
+                        }
                         BodyFilter::HTML(html_body_filter) => BodyFilter::HTML(html_body_filter.clone_with_variables_replaced(&variables)),
+                        BodyFilter::Text(text_body_filter) if matches!(text_body_filter.action, TextAction::ReplaceRegex) => {
+                            // Variables are inserted literally: escaped in the pattern, and `$` is not a capture group in the replacement
+                            let pattern_variables = escape_variables(&variables, regex::escape);
+                            let content_variables = escape_variables(&variables, |value| value.replace('$', "$$"));
+
+                            BodyFilter::Text(TextBodyFilter {
+                                action: text_body_filter.action.clone(),
+                                content: StaticOrDynamic::replace(text_body_filter.content.clone(), &content_variables, true),
+                                pattern: text_body_filter
+                                    .pattern
+                                    .as_ref()
+                                    .map(|pattern| StaticOrDynamic::replace(pattern.clone(), &pattern_variables, true)),
+                                lookbehind: text_body_filter.lookbehind,
+                                id: text_body_filter.id.clone(),
+                                target_hash: text_body_filter.target_hash.clone(),
+                            })
+                        }
                         BodyFilter::Text(text_body_filter) => BodyFilter::Text(TextBodyFilter {
                             action: text_body_filter.action.clone(),
                             content: StaticOrDynamic::replace(text_body_filter.content.clone(), &variables, true),
+                            pattern: text_body_filter.pattern.clone(),
+                            lookbehind: text_body_filter.lookbehind,
                             id: text_body_filter.id.clone(),
                             target_hash: text_body_filter.target_hash.clone(),
                         }),
                         BodyFilter::HTMLToMarkdown(html_to_markdown_filter) => BodyFilter::HTMLToMarkdown(html_to_markdown_filter.clone()),
+                        BodyFilter::JSON(json_body_filter) => BodyFilter::JSON(json_body_filter.clone_with_variables_replaced(&variables)),
+                        BodyFilter::XML(xml_body_filter) => BodyFilter::XML(xml_body_filter.clone_with_variables_replaced(&variables)),
                         BodyFilter::Other(_) => {
                             continue;
                         }
                     },
                     on_response_status_codes: on_response_status_codes.clone(),
                     exclude_response_status_codes: rule.source.exclude_response_status_codes.is_some(),
+                    on_response_headers: on_response_headers.clone(),
                     rule_id: Some(rule.id.clone()),
                 });
             }
@@ -272,24 +424,31 @@ impl Action {
             status_code_update,
             header_filters,
             body_filters,
+            request_header_filters,
             rule_ids: LinkedHashSet::from_iter(vec![rule.id.clone()]),
             rule_traces: vec![RuleTrace {
                 on_response_status_codes: on_response_status_codes.clone(),
                 exclude_response_status_codes: rule.source.exclude_response_status_codes.is_some(),
+                on_response_headers: on_response_headers.clone(),
                 id: rule.id.clone(),
             }],
             rules_applied: LinkedHashSet::new(),
-            log_override: rule.log_override.map(|log_override| LogOverride {
-                log_override,
+            log_override: rule.log_override.as_ref().map(|log_override| LogOverride {
+                log_override: log_override.is_enabled(),
+                sampling: log_override
+                    .sampling()
+                    .map(|sampling| Self::log_sampling(rule, sampling, &variables, request)),
                 rule_id: Some(rule.id.clone()),
                 on_response_status_codes: on_response_status_codes.clone(),
                 exclude_response_status_codes: rule.source.exclude_response_status_codes.is_some(),
+                on_response_headers: on_response_headers.clone(),
                 fallback_log_override: None,
                 fallback_rule_id: None,
                 unit_id: rule.configuration_log_unit_id.clone(),
             }),
             peer_override: if let Some(peer) = rule.peer_override.clone()
                 && on_response_status_codes.is_empty()
+                && on_response_headers.is_empty()
             {
                 Some(PeerOverride {
                     peer,
@@ -299,9 +458,81 @@ impl Action {
             } else {
                 None
             },
+            peer_pool: if let Some(pool) = rule.peer_pool.as_ref()
+                && !pool.peers.is_empty()
+                && on_response_status_codes.is_empty()
+                && on_response_headers.is_empty()
+            {
+                Some(PeerPoolOverride {
+                    peers: pool.peers.clone(),
+                    key: match pool.sticky_key.as_ref().filter(|key| !key.is_empty()) {
+                        Some(key) => StaticOrDynamic::replace(key.clone(), &variables, true),
+                        None => request.remote_addr.map(|addr| addr.to_string()).unwrap_or_default(),
+                    },
+                    failure_cooldown: pool.failure_cooldown,
+                    rule_id: Some(rule.id.clone()),
+                    unit_id: pool.id.clone(),
+                })
+            } else {
+                None
+            },
+            peer_fallback: Self::peer_fallback(rule, &on_response_status_codes, &on_response_headers),
+            rewrite_override: if let Some(rewrite) = rule.rewrite.as_ref()
+                && !rewrite.is_empty()
+                && on_response_status_codes.is_empty()
+                && on_response_headers.is_empty()
+            {
+                Some(RewriteOverride {
+                    path_and_query: Self::rewrite_path_and_query(rewrite, &variables, request),
+                    rule_id: Some(rule.id.clone()),
+                    unit_id: rule.rewrite_unit_id.clone(),
+                })
+            } else {
+                None
+            },
+            // Served instead of the backend response, so it cannot depend on it
+            synthetic_response: if let Some(response) = rule.synthetic_response.as_ref()
+                && on_response_status_codes.is_empty()
+                && on_response_headers.is_empty()
+            {
+                Some(SyntheticResponseOverride {
+                    response: SyntheticResponse {
+                        status_code: response.status_code,
+                        headers: response
+                            .headers
+                            .iter()
+                            .map(|header| Header {
+                                name: header.name.clone(),
+                                value: StaticOrDynamic::replace(header.value.clone(), &variables, true),
+                            })
+                            .collect(),
+                        body: StaticOrDynamic::replace(response.body.clone(), &variables, true),
+                        id: response.id.clone(),
+                    },
+                    rule_id: Some(rule.id.clone()),
+                })
+            } else {
+                None
+            },
+            response_compression: rule.compress_response.as_ref().and_then(|compression| {
+                ResponseCompressionOverride::negotiate_encoding(&request.headers).map(|encoding| ResponseCompressionOverride {
+                    encoding: encoding.to_string(),
+                    min_size: compression.min_size.unwrap_or(DEFAULT_MIN_COMPRESSION_SIZE),
+                    on_response_status_codes: on_response_status_codes.clone(),
+                    exclude_response_status_codes: rule.source.exclude_response_status_codes.is_some(),
+                    on_response_headers: on_response_headers.clone(),
+                    rule_id: Some(rule.id.clone()),
+                    unit_id: compression.id.clone(),
+                    applied: None,
+                })
+            }),
             variables,
             agent_protocol_version_major: 0,
             agent_protocol_version_minor: 0,
+            log_sampler: None,
+            #[cfg(feature = "router")]
+            link_router: None,
+            log_weight: None,
         };
 
         (
@@ -312,6 +543,41 @@ impl Action {
         )
     }
 
+    /// Opt-in "did you mean" mode: when no rule matched, redirect to the closest rule target
+    /// if its score reaches the threshold. Only applied when the backend answers a 404.
+    #[cfg(feature = "router")]
+    pub fn from_did_you_mean(router: &Router<Rule>, request: &Request, threshold: f64, status_code: u16) -> Option<Action> {
+        let suggestion = RuleSuggestion::did_you_mean(router, request, threshold)?;
+
+        Some(Action {
+            status_code_update: Some(StatusCodeUpdate {
+                status_code,
+                on_response_status_codes: vec![404].into(),
+                exclude_response_status_codes: false,
+                on_response_headers: Vec::new(),
+                fallback_status_code: 0,
+                rule_id: None,
+                fallback_rule_id: None,
+                unit_id: None,
+                target_hash: Some("status_code".to_string()),
+            }),
+            header_filters: vec![HeaderFilterAction {
+                filter: HeaderFilter {
+                    action: "override".to_string(),
+                    value: suggestion.url,
+                    header: "Location".to_string(),
+                    id: None,
+                    target_hash: None,
+                },
+                on_response_status_codes: vec![404].into(),
+                exclude_response_status_codes: false,
+                on_response_headers: Vec::new(),
+                rule_id: None,
+            }],
+            ..Default::default()
+        })
+    }
+
     #[cfg(feature = "router")]
     pub fn merge(&mut self, other: Self) {
         self.status_code_update = match other.status_code_update {
@@ -319,15 +585,14 @@ impl Action {
             Some(new_status_code_update) => match &self.status_code_update {
                 None => Some(new_status_code_update),
                 Some(old_status_code_update) => {
-                    if !old_status_code_update.on_response_status_codes.is_empty()
-                        || new_status_code_update.on_response_status_codes.is_empty()
-                    {
+                    if old_status_code_update.is_conditional() || !new_status_code_update.is_conditional() {
                         Some(new_status_code_update)
                     } else {
                         Some(StatusCodeUpdate {
                             status_code: new_status_code_update.status_code,
                             on_response_status_codes: new_status_code_update.on_response_status_codes,
                             exclude_response_status_codes: new_status_code_update.exclude_response_status_codes,
+                            on_response_headers: new_status_code_update.on_response_headers,
                             fallback_status_code: old_status_code_update.status_code,
                             rule_id: new_status_code_update.rule_id,
                             target_hash: new_status_code_update.target_hash,
@@ -347,6 +612,10 @@ impl Action {
             self.body_filters.push(filter);
         }
 
+        for filter in other.request_header_filters {
+            self.request_header_filters.push(filter);
+        }
+
         for rule_id in other.rule_ids {
             self.rule_ids.insert(rule_id);
         }
@@ -359,14 +628,16 @@ impl Action {
             self.log_override = match &self.log_override {
                 None => Some(other_log_override),
                 Some(self_log_override) => {
-                    if !self_log_override.on_response_status_codes.is_empty() || other_log_override.on_response_status_codes.is_empty() {
+                    if self_log_override.is_conditional() || !other_log_override.is_conditional() {
                         Some(other_log_override)
                     } else {
                         Some(LogOverride {
                             log_override: other_log_override.log_override,
+                            sampling: other_log_override.sampling,
                             rule_id: other_log_override.rule_id,
                             on_response_status_codes: other_log_override.on_response_status_codes,
                             exclude_response_status_codes: other_log_override.exclude_response_status_codes,
+                            on_response_headers: other_log_override.on_response_headers,
                             fallback_log_override: Some(self_log_override.log_override),
                             fallback_rule_id: self_log_override.rule_id.clone(),
                             unit_id: self_log_override.unit_id.clone(),
@@ -376,16 +647,130 @@ impl Action {
             }
         }
 
+        // The last rule with a peer override or a peer pool chooses the peer
         if let Some(other_peer_override) = other.peer_override {
             self.peer_override = Some(other_peer_override);
+            self.peer_pool = None;
+        }
+
+        if let Some(other_peer_pool) = other.peer_pool {
+            self.peer_pool = Some(other_peer_pool);
+            self.peer_override = None;
+        }
+
+        if let Some(other_peer_fallback) = other.peer_fallback {
+            self.peer_fallback = Some(other_peer_fallback);
+        }
+
+        if let Some(other_rewrite_override) = other.rewrite_override {
+            self.rewrite_override = Some(other_rewrite_override);
+        }
+
+        if let Some(other_synthetic_response) = other.synthetic_response {
+            self.synthetic_response = Some(other_synthetic_response);
+        }
+
+        if let Some(other_response_compression) = other.response_compression {
+            self.response_compression = Some(other_response_compression);
         }
 
         self.variables.extend(other.variables);
     }
 
+    /// Build the action of the routes matched by the router for this request. When
+    /// `max_rewrite_passes` is set in the router config, the request rewritten by a rule is
+    /// matched again against the router so rules can be layered. Actions of every pass are merged
+    /// with the usual reset and stop semantics, and matching ends on the first rule stopping,
+    /// when no rule rewrites the request anymore, or when a rewritten url was already matched.
     #[cfg(feature = "router")]
-    pub fn from_routes_rule(mut routes: Vec<Arc<Route<Rule>>>, request: &Request, unit_trace: Option<Rc<RefCell<UnitTrace>>>) -> Action {
+    pub fn from_routes_rule(
+        router: &Router<Rule>,
+        routes: Vec<Arc<Route<Rule>>>,
+        request: &Request,
+        unit_trace: Option<Rc<RefCell<UnitTrace>>>,
+    ) -> Action {
+        Action::from_routes_rule_passes(router, routes, request, unit_trace).0
+    }
+
+    /// Match the request against the router and build its action like `from_routes_rule`, also
+    /// returns the rewritten requests that were matched, in order.
+    #[cfg(feature = "router")]
+    pub fn from_router(router: &Router<Rule>, request: &Request, unit_trace: Option<Rc<RefCell<UnitTrace>>>) -> (Action, Vec<Request>) {
+        let routes = router.match_request(request);
+
+        Action::from_routes_rule_passes(router, routes, request, unit_trace)
+    }
+
+    #[cfg(feature = "router")]
+    fn from_routes_rule_passes(
+        router: &Router<Rule>,
+        mut routes: Vec<Arc<Route<Rule>>>,
+        request: &Request,
+        unit_trace: Option<Rc<RefCell<UnitTrace>>>,
+    ) -> (Action, Vec<Request>) {
+        let max_passes = router.config.max_rewrite_passes;
         let mut action = Action::default();
+        let mut rewritten_requests = Vec::new();
+        let mut seen = HashSet::new();
+        let mut current = request.clone();
+        let mut pass = 0;
+
+        seen.insert(current.path_and_query());
+
+        loop {
+            if max_passes > 0
+                && let Some(trace) = &unit_trace
+            {
+                let rule_ids = routes.iter().map(|route| route.handler().id.clone()).collect();
+                trace.borrow_mut().add_match_pass(current.path_and_query().as_str(), rule_ids);
+            }
+
+            let previous_rewrite = action.rewrite_override.clone();
+            let stopped = action.merge_routes_rule(routes, &current, &unit_trace);
+
+            // A reset in a later pass cannot undo the rewrite which led to this pass
+            if action.rewrite_override.is_none() {
+                action.rewrite_override = previous_rewrite.clone();
+            }
+
+            if stopped || pass >= max_passes {
+                break;
+            }
+
+            let rewrite = match action.rewrite_override.as_ref() {
+                Some(rewrite) if previous_rewrite.as_ref().map(|previous| &previous.path_and_query) != Some(&rewrite.path_and_query) => {
+                    rewrite.path_and_query.clone()
+                }
+                _ => break,
+            };
+
+            let rewritten_request = current.with_path_and_query(&router.config, rewrite.as_str());
+
+            if !seen.insert(rewritten_request.path_and_query()) {
+                if let Some(trace) = &unit_trace {
+                    trace.borrow_mut().set_rewrite_loop(rewrite.as_str());
+                }
+
+                break;
+            }
+
+            routes = router.match_request(&rewritten_request);
+            rewritten_requests.push(rewritten_request.clone());
+            current = rewritten_request;
+            pass += 1;
+        }
+
+        (action, rewritten_requests)
+    }
+
+    /// Merge the actions of the matched routes, returns whether a rule stopped the matching
+    #[cfg(feature = "router")]
+    fn merge_routes_rule(
+        &mut self,
+        mut routes: Vec<Arc<Route<Rule>>>,
+        request: &Request,
+        unit_trace: &Option<Rc<RefCell<UnitTrace>>>,
+    ) -> bool {
         routes.sort();
 
         for route in routes {
@@ -393,24 +778,24 @@ impl Action {
 
             if let Some(action_rule) = action_rule_opt {
                 if reset {
-                    if let (Some(trace), Some(unit_id)) = (&unit_trace, &configuration_unit_id) {
+                    if let (Some(trace), Some(unit_id)) = (unit_trace, &configuration_unit_id) {
                         trace.borrow_mut().add_unit_id_with_target("configuration::reset", unit_id.as_str());
                     }
-                    action = action_rule;
+                    *self = action_rule;
                 } else {
-                    action.merge(action_rule);
+                    self.merge(action_rule);
                 }
 
                 if stop {
-                    if let (Some(trace), Some(unit_id)) = (&unit_trace, &configuration_unit_id) {
+                    if let (Some(trace), Some(unit_id)) = (unit_trace, &configuration_unit_id) {
                         trace.borrow_mut().add_unit_id_with_target("configuration::stop", unit_id.as_str());
                     }
-                    return action;
+                    return true;
                 }
             }
         }
 
-        action
+        false
     }
 
     pub fn get_final_status_code_with_fallback(
@@ -449,11 +834,217 @@ impl Action {
         }
     }
 
+    /// Path and query to send to the upstream when a rule rewrites it, the client url is unchanged
+    pub fn get_rewrite(&mut self, unit_trace: Option<Rc<RefCell<UnitTrace>>>) -> Option<&str> {
+        let rewrite_override = self.rewrite_override.as_ref()?;
+
+        if let Some(rule_id) = rewrite_override.rule_id.as_ref() {
+            self.rules_applied.insert(rule_id.clone());
+
+            if let Some(trace) = &unit_trace {
+                trace.borrow_mut().rule_ids_applied.insert(rule_id.to_string());
+
+                if let Some(unit_id) = &rewrite_override.unit_id {
+                    trace.borrow_mut().add_unit_id_with_target("rewrite", unit_id);
+                }
+            }
+        }
+
+        Some(rewrite_override.path_and_query.as_str())
+    }
+
+    /// Response to serve instead of calling the backend, `need_proxification` is false when there
+    /// is one. Status code update, header and body filters still apply on it, like on a backend
+    /// response.
+    pub fn get_synthetic_response(&mut self, unit_trace: Option<Rc<RefCell<UnitTrace>>>) -> Option<&SyntheticResponse> {
+        let synthetic_response = self.synthetic_response.as_ref()?;
+
+        if let Some(rule_id) = synthetic_response.rule_id.as_ref() {
+            self.rules_applied.insert(rule_id.clone());
+
+            if let Some(trace) = &unit_trace {
+                trace.borrow_mut().rule_ids_applied.insert(rule_id.to_string());
+
+                if let Some(unit_id) = &synthetic_response.response.id {
+                    trace.borrow_mut().add_unit_id_with_target("synthetic_response", unit_id);
+                }
+            }
+        }
+
+        Some(&synthetic_response.response)
+    }
+
+    #[cfg(feature = "router")]
+    fn rewrite_path_and_query(rewrite: &str, variables: &[(String, VariableValue)], request: &Request) -> String {
+        let mut value = StaticOrDynamic::replace(rewrite.to_string(), variables, true);
+
+        // Marketing query parameters skipped for matching are still expected by the upstream
+        if let Some(skipped_query_params) = request.path_and_query_skipped.skipped_query_params.as_ref() {
+            if value.contains('?') {
+                value.push('&');
+            } else {
+                value.push('?');
+            }
+
+            value.push_str(skipped_query_params.as_str());
+        }
+
+        value
+    }
+
+    /// Peer to send the request to, chosen in the peer pool of the rule when there is one,
+    /// skipping peers with a failure reported in `health` during the pool cooldown
+    pub fn select_peer(&mut self, health: Option<&PeerHealth>, unit_trace: Option<Rc<RefCell<UnitTrace>>>) -> Option<&Peer> {
+        if self.peer_pool.is_none() {
+            return self.get_peer(unit_trace);
+        }
+
+        let peer_pool = self.peer_pool.as_ref()?;
+        let peer = peer_pool.select(health)?;
+
+        if let Some(rule_id) = peer_pool.rule_id.as_ref() {
+            self.rules_applied.insert(rule_id.clone());
+        }
+
+        if let Some(trace) = &unit_trace {
+            let mut trace = trace.borrow_mut();
+
+            trace.set_peer_selected(peer.address.as_str(), peer_pool.key.as_str());
+
+            if let Some(rule_id) = peer_pool.rule_id.as_ref() {
+                trace.rule_ids_applied.insert(rule_id.to_string());
+            }
+
+            if let Some(unit_id) = &peer_pool.unit_id {
+                trace.add_unit_id_with_target("peer_override", unit_id);
+            }
+        }
+
+        Some(peer)
+    }
+
+    /// Fallback peers of a rule, which also include its peer override when the rule depends on
+    /// the response status code, as it can only apply once the backend responded
+    #[cfg(feature = "router")]
+    fn peer_fallback(rule: &Rule, on_response_status_codes: &StatusCodes, on_response_headers: &[HeaderCondition]) -> Option<PeerFallback> {
+        let mut peers = Vec::new();
+
+        if let Some(peer) = rule.peer_override.as_ref()
+            && (!on_response_status_codes.is_empty() || !on_response_headers.is_empty())
+        {
+            peers.push(peer.clone());
+        }
+
+        if let Some(fallback) = rule.peer_fallback.as_ref() {
+            peers.extend(fallback.peers.iter().cloned());
+        }
+
+        if peers.is_empty() {
+            return None;
+        }
+
+        let (on_response_status_codes, exclude_response_status_codes) =
+            if on_response_status_codes.is_empty() && on_response_headers.is_empty() {
+                (PeerFallback::default_status_codes(), false)
+            } else {
+                (
+                    on_response_status_codes.clone(),
+                    rule.source.exclude_response_status_codes.is_some(),
+                )
+            };
+
+        Some(PeerFallback {
+            peers,
+            on_response_status_codes,
+            exclude_response_status_codes,
+            on_response_headers: on_response_headers.to_vec(),
+            methods: rule
+                .peer_fallback
+                .as_ref()
+                .and_then(|fallback| fallback.methods.clone())
+                .unwrap_or_else(PeerFallback::default_methods),
+            rule_id: Some(rule.id.clone()),
+            unit_id: rule
+                .peer_fallback
+                .as_ref()
+                .and_then(|fallback| fallback.id.clone())
+                .or(rule.peer_unit_id.clone()),
+        })
+    }
+
+    /// Peer to retry the request on after the backend responded, `attempt` being the number of
+    /// fallback peers already tried. Only methods allowed by the rule are retried, GET and HEAD
+    /// by default, as other methods may not be safe to send twice. Rules conditioned on response
+    /// headers never apply here, see `get_retry_peer_with_response_headers`.
+    pub fn get_retry_peer(
+        &mut self,
+        method: &str,
+        response_status_code: u16,
+        attempt: usize,
+        unit_trace: Option<Rc<RefCell<UnitTrace>>>,
+    ) -> Option<&Peer> {
+        self.get_retry_peer_with_response_headers(method, response_status_code, None, attempt, unit_trace)
+    }
+
+    /// Same as `get_retry_peer`, with the headers of the backend response needed by rules
+    /// conditioned on them
+    pub fn get_retry_peer_with_response_headers(
+        &mut self,
+        method: &str,
+        response_status_code: u16,
+        response_headers: Option<&[Header]>,
+        attempt: usize,
+        unit_trace: Option<Rc<RefCell<UnitTrace>>>,
+    ) -> Option<&Peer> {
+        let peer_fallback = self.peer_fallback.as_ref()?;
+        let response_headers_matched = match_response_headers(
+            &peer_fallback.on_response_headers,
+            peer_fallback.rule_id.as_deref(),
+            response_headers,
+            &unit_trace,
+        );
+        let peer = peer_fallback.get_peer(method, response_status_code, response_headers_matched, attempt)?;
+
+        if let Some(rule_id) = peer_fallback.rule_id.as_ref() {
+            self.rules_applied.insert(rule_id.clone());
+
+            if let Some(trace) = &unit_trace {
+                trace.borrow_mut().rule_ids_applied.insert(rule_id.to_string());
+
+                if let Some(unit_id) = &peer_fallback.unit_id {
+                    trace.borrow_mut().add_unit_id_with_target("peer_fallback", unit_id);
+                }
+            }
+        }
+
+        Some(peer)
+    }
+
+    /// Status code to answer with, 0 to keep the one of the backend. Rules conditioned on
+    /// response headers never apply here, see `get_status_code_with_response_headers`.
     pub fn get_status_code(&mut self, response_status_code: u16, unit_trace: Option<Rc<RefCell<UnitTrace>>>) -> u16 {
+        self.get_status_code_with_response_headers(response_status_code, None, unit_trace)
+    }
+
+    /// Same as `get_status_code`, with the headers of the backend response to check the
+    /// conditions of rules on them
+    pub fn get_status_code_with_response_headers(
+        &mut self,
+        response_status_code: u16,
+        response_headers: Option<&[Header]>,
+        unit_trace: Option<Rc<RefCell<UnitTrace>>>,
+    ) -> u16 {
         match self.status_code_update.as_ref() {
             None => 0,
             Some(status_code_update) => {
-                let (status, rule_applied) = status_code_update.get_status_code(response_status_code);
+                let response_headers_matched = match_response_headers(
+                    &status_code_update.on_response_headers,
+                    status_code_update.rule_id.as_deref(),
+                    response_headers,
+                    &unit_trace,
+                );
+                let (status, rule_applied) =
+                    status_code_update.get_status_code_with_headers_matched(response_status_code, response_headers_matched);
 
                 if let Some(rule_id) = rule_applied {
                     if let Some(trace) = unit_trace {
@@ -482,30 +1073,30 @@ impl Action {
         let mut filters = Vec::new();
 
         for trace in &self.rule_traces {
-            if trace.on_response_status_codes.is_empty() {
-                self.rules_applied.insert(trace.id.clone());
-                continue;
-            }
-
-            if !trace.exclude_response_status_codes && trace.on_response_status_codes.contains(&response_status_code) {
-                self.rules_applied.insert(trace.id.clone());
+            if !match_response_headers(&trace.on_response_headers, Some(trace.id.as_str()), Some(&headers), &unit_trace) {
                 continue;
             }
 
-            if trace.exclude_response_status_codes && !trace.on_response_status_codes.contains(&response_status_code) {
+            if trace.on_response_status_codes.is_empty()
+                || trace
+                    .on_response_status_codes
+                    .matches(trace.exclude_response_status_codes, response_status_code)
+            {
                 self.rules_applied.insert(trace.id.clone());
             }
         }
 
         for filter in self.header_filters.as_slice() {
-            if !filter.on_response_status_codes.is_empty() {
-                if !filter.exclude_response_status_codes && !filter.on_response_status_codes.contains(&response_status_code) {
-                    continue;
-                }
+            if !filter.on_response_status_codes.is_empty()
+                && !filter
+                    .on_response_status_codes
+                    .matches(filter.exclude_response_status_codes, response_status_code)
+            {
+                continue;
+            }
 
-                if filter.exclude_response_status_codes && filter.on_response_status_codes.contains(&response_status_code) {
-                    continue;
-                }
+            if !match_response_headers(&filter.on_response_headers, filter.rule_id.as_deref(), Some(&headers), &unit_trace) {
+                continue;
             }
 
             filters.push(filter.filter.clone());
@@ -515,11 +1106,46 @@ impl Action {
             }
         }
 
+        // Like header filters, the compression is conditioned on the headers of the backend response
+        let compression_matched = self.response_compression.as_ref().is_some_and(|compression| {
+            compression.matches_status_code(response_status_code)
+                && match_response_headers(
+                    &compression.on_response_headers,
+                    compression.rule_id.as_deref(),
+                    Some(&headers),
+                    &unit_trace,
+                )
+        });
+
         let mut new_headers = match FilterHeaderAction::new(filters) {
             None => headers,
             Some(filter_action) => filter_action.filter(headers, unit_trace.clone()),
         };
 
+        let mut body_changed = self.will_filter_body(response_status_code, &new_headers);
+
+        if let Some(compression) = self.response_compression.as_mut() {
+            let applied = compression_matched && compression.should_compress(response_status_code, &new_headers);
+            compression.applied = Some(applied);
+            body_changed |= applied;
+
+            if applied {
+                new_headers = compression.compress_headers(new_headers);
+
+                if let Some(rule_id) = compression.rule_id.clone() {
+                    if let (Some(trace), Some(unit_id)) = (&unit_trace, &compression.unit_id) {
+                        trace.borrow_mut().add_unit_id_with_target("response_compression", unit_id);
+                    }
+
+                    self.rules_applied.insert(rule_id);
+                }
+            }
+        }
+
+        if body_changed {
+            new_headers = headers_for_changed_body(new_headers);
+        }
+
         if let Some(trace) = unit_trace {
             trace.borrow_mut().rule_ids_applied.extend(self.get_applied_rule_ids().clone());
         }
@@ -534,6 +1160,33 @@ impl Action {
         new_headers
     }
 
+    /// Filter the headers of the request before it is sent to the upstream, proxies must call
+    /// this before contacting the upstream, even when there is no response header to filter.
+    pub fn filter_request_headers(&mut self, headers: Vec<Header>, unit_trace: Option<Rc<RefCell<UnitTrace>>>) -> Vec<Header> {
+        let mut filters = Vec::new();
+
+        for filter in self.request_header_filters.as_slice() {
+            filters.push(filter.filter.clone());
+
+            if let Some(rule_id) = filter.rule_id.as_ref() {
+                self.rules_applied.insert(rule_id.clone());
+
+                if let Some(trace) = &unit_trace {
+                    trace.borrow_mut().rule_ids_applied.insert(rule_id.clone());
+                }
+            }
+        }
+
+        match FilterHeaderAction::new(filters) {
+            None => headers,
+            Some(filter_action) => filter_action.filter(headers, unit_trace),
+        }
+    }
+
+    pub fn has_request_header_filters(&self) -> bool {
+        !self.request_header_filters.is_empty()
+    }
+
     /// Body filters deliberately apply whatever `response_status_code` is - 206 and 304
     /// included, unless the rule's own status-code trigger says otherwise. Several rules can
     /// combine on one request (e.g. one filters the body while another rewrites the status
@@ -542,6 +1195,9 @@ impl Action {
     /// content-replacement rules by sending a `Range` header. Do not add status-based guards
     /// here - the only built-in restriction is content-type based, in
     /// `FilterBodyActionItem::new` (no text splicing into binary content types).
+    ///
+    /// Whether the response is compressed is decided by `filter_headers`, which must be called
+    /// on this action before creating its body filter.
     pub fn create_filter_body(
         &mut self,
         response_status_code: u16,
@@ -550,14 +1206,8 @@ impl Action {
     ) -> Option<FilterBodyAction> {
         let mut filters = Vec::new();
         for filter in self.body_filters.as_slice() {
-            if !filter.on_response_status_codes.is_empty() {
-                if !filter.exclude_response_status_codes && !filter.on_response_status_codes.contains(&response_status_code) {
-                    continue;
-                }
-
-                if filter.exclude_response_status_codes && filter.on_response_status_codes.contains(&response_status_code) {
-                    continue;
-                }
+            if !filter.matches(response_status_code, headers, &unit_trace) {
+                continue;
             }
 
             if let Some(rule_id) = filter.rule_id.as_ref() {
@@ -567,44 +1217,187 @@ impl Action {
             filters.push(filter.filter.clone());
         }
 
-        let body_filter = FilterBodyAction::new(filters, headers, unit_trace, self.variables.clone());
+        #[cfg(feature = "router")]
+        let links = self
+            .link_router
+            .as_ref()
+            .map(|link_router| Rc::new(RefCell::new(LinkRedirector::new(link_router.clone(), unit_trace.clone()))));
+        #[cfg(not(feature = "router"))]
+        let links = None;
+
+        // The body is compressed after being filtered, the content encoding set for the
+        // compression must not be decoded. The compression is decided when filtering the headers
+        // of the response, which must be done before on this same action.
+        let compression = match self.response_compression.as_ref() {
+            Some(compression) if compression.applied.is_none() => {
+                tracing::error!("response is not compressed, its headers must be filtered before creating the body filter");
+
+                None
+            }
+            Some(compression) if compression.applied == Some(true) => Some(compression.encoding.clone()),
+            _ => None,
+        };
+        let uncompressed_headers: Vec<Header>;
+        let headers = match compression {
+            Some(_) => {
+                uncompressed_headers = headers
+                    .iter()
+                    .filter(|header| !header.name.eq_ignore_ascii_case("content-encoding"))
+                    .cloned()
+                    .collect();
+
+                uncompressed_headers.as_slice()
+            }
+            None => headers,
+        };
+
+        let body_filter = FilterBodyAction::new_with_link_redirector(filters, headers, unit_trace, self.variables.clone(), links);
+
+        #[cfg(feature = "compress")]
+        let body_filter = match compression {
+            Some(encoding) => body_filter.with_compression(encoding.as_str()),
+            None => body_filter,
+        };
 
         if body_filter.is_empty() { None } else { Some(body_filter) }
     }
 
+    /// Whether a body filter will change the body of a response with this status code and these
+    /// headers, so `filter_headers` removes the headers describing the original body
+    fn will_filter_body(&self, response_status_code: u16, headers: &[Header]) -> bool {
+        let filters = self
+            .body_filters
+            .iter()
+            .filter(|filter| filter.matches(response_status_code, headers, &None))
+            .map(|filter| filter.filter.clone())
+            .collect::<Vec<_>>();
+
+        FilterBodyAction::will_filter(&filters, headers)
+    }
+
+    /// Look up the links found by body filters in the router, to rewrite the ones that are
+    /// redirected to their final target. Relative links are resolved against `request_url`,
+    /// at most `max_link_lookups` distinct links are looked up by response.
+    #[cfg(feature = "router")]
+    pub fn set_link_router(&mut self, router: Arc<Router<Rule>>, request_url: Option<&str>, max_link_lookups: Option<usize>) {
+        self.link_router = Some(LinkRouter::new(router, request_url, max_link_lookups));
+    }
+
+    /// Share the counters of sampled logs between the actions of a proxy, needed to enforce
+    /// the limit of logs per interval of a rule
+    pub fn set_log_sampler(&mut self, log_sampler: Arc<LogSampler>) {
+        self.log_sampler = Some(log_sampler);
+    }
+
+    /// Whether the request should be logged, its weight is then read with `get_log_weight`.
+    /// Rules conditioned on response headers never apply here, see
+    /// `should_log_request_with_response_headers`.
     pub fn should_log_request(
         &mut self,
         allow_log_config: bool,
         response_status_code: u16,
         unit_trace: Option<Rc<RefCell<UnitTrace>>>,
     ) -> bool {
-        match self.log_override.as_ref() {
-            None => allow_log_config,
+        self.should_log_request_with_response_headers(allow_log_config, response_status_code, None, unit_trace)
+    }
+
+    /// Same as `should_log_request`, with the headers of the backend response to check the
+    /// conditions of rules on them
+    pub fn should_log_request_with_response_headers(
+        &mut self,
+        allow_log_config: bool,
+        response_status_code: u16,
+        response_headers: Option<&[Header]>,
+        unit_trace: Option<Rc<RefCell<UnitTrace>>>,
+    ) -> bool {
+        let (should_log, weight) = match self.log_override.as_ref() {
+            None => (allow_log_config, None),
             Some(log_override) => {
-                let (allow_log, rule_applied_id, handled) = log_override.get_log_override(response_status_code);
+                let response_headers_matched = match_response_headers(
+                    &log_override.on_response_headers,
+                    log_override.rule_id.as_deref(),
+                    response_headers,
+                    &unit_trace,
+                );
+                let (allow_log, rule_applied_id, handled) = log_override.get_log_override(response_status_code, response_headers_matched);
 
                 if handled && let (Some(trace), Some(unit_id)) = (unit_trace, &log_override.unit_id) {
                     trace.borrow_mut().add_unit_id_with_target("configuration::log", unit_id);
                 }
 
+                let should_log = allow_log.unwrap_or(allow_log_config);
+
+                let (should_log, weight) = match log_override.sampling.as_ref() {
+                    Some(sampling) if handled && should_log => {
+                        let sampled_requests = if !sampling.sampled {
+                            None
+                        } else {
+                            match (sampling.limit, self.log_sampler.as_ref(), rule_applied_id.as_ref()) {
+                                (Some(limit), Some(log_sampler), Some(rule_id)) => log_sampler.acquire(rule_id, limit, sampling.interval),
+                                _ => Some(1),
+                            }
+                        };
+
+                        (
+                            sampled_requests.is_some(),
+                            sampled_requests.map(|sampled_requests| sampling.weight(sampled_requests)),
+                        )
+                    }
+                    _ => (should_log, None),
+                };
+
                 if let Some(rule_id) = rule_applied_id {
                     self.rules_applied.insert(rule_id);
                 }
 
-                allow_log.unwrap_or(allow_log_config)
+                (should_log, weight)
             }
+        };
+
+        self.log_weight = if should_log { weight } else { None };
+
+        should_log
+    }
+
+    /// Weight of the request decided by `should_log_request` when the logs of its rule are
+    /// sampled: the number of requests the log stands for, including the sampled requests
+    /// dropped by the limit of the rule since its last log. None when it is not sampled.
+    pub fn get_log_weight(&self) -> Option<f64> {
+        self.log_weight
+    }
+
+    #[cfg(feature = "router")]
+    fn log_sampling(rule: &Rule, sampling: &RuleLogSampling, variables: &[(String, VariableValue)], request: &Request) -> LogSampling {
+        let key = match sampling.key.as_ref().filter(|key| !key.is_empty()) {
+            Some(key) => StaticOrDynamic::replace(key.clone(), variables, true),
+            None => format!(
+                "{} {}",
+                request.remote_addr.map(|addr| addr.to_string()).unwrap_or_default(),
+                request.path_and_query_skipped.original
+            ),
+        };
+
+        LogSampling {
+            rate: sampling.rate,
+            sampled: stable_unit_hash(format!("{}\n{}", rule.id, key).as_bytes()) <= sampling.rate,
+            limit: sampling.limit,
+            interval: sampling.interval,
         }
     }
 
     pub fn need_proxification(&self) -> bool {
+        if self.synthetic_response.is_some() {
+            return false;
+        }
+
         if let Some(update) = &self.status_code_update
-            && !update.on_response_status_codes.is_empty()
+            && update.is_conditional()
         {
             return true;
         }
 
         if let Some(log_override) = &self.log_override
-            && !log_override.on_response_status_codes.is_empty()
+            && log_override.is_conditional()
         {
             return true;
         }
@@ -617,10 +1410,60 @@ impl Action {
             return true;
         }
 
+        if self.peer_fallback.is_some() {
+            return true;
+        }
+
+        if self.response_compression.is_some() {
+            return true;
+        }
+
         false
     }
 }
 
+/// Headers of a response whose body is changed by the proxy: its length and checksum are removed,
+/// as are byte ranges which would not match the new body, and its entity tag is weakened as the
+/// new body is only semantically equivalent to the original one
+fn headers_for_changed_body(headers: Vec<Header>) -> Vec<Header> {
+    headers
+        .into_iter()
+        .filter(|header| {
+            !header.name.eq_ignore_ascii_case("content-length")
+                && !header.name.eq_ignore_ascii_case("content-md5")
+                && !header.name.eq_ignore_ascii_case("accept-ranges")
+        })
+        .map(|mut header| {
+            if header.name.eq_ignore_ascii_case("etag") && !header.value.trim_start().starts_with("W/") {
+                header.value = format!("W/{}", header.value.trim_start());
+            }
+
+            header
+        })
+        .collect()
+}
+
+/// Evaluate the response header conditions of an action item, an item without conditions
+/// always matches. Results are traced by rule, as all items of a rule share its conditions.
+fn match_response_headers(
+    conditions: &[HeaderCondition],
+    rule_id: Option<&str>,
+    headers: Option<&[Header]>,
+    unit_trace: &Option<Rc<RefCell<UnitTrace>>>,
+) -> bool {
+    if conditions.is_empty() {
+        return true;
+    }
+
+    let matched = headers.is_some_and(|headers| HeaderCondition::match_all(conditions, headers));
+
+    if let (Some(trace), Some(rule_id)) = (unit_trace, rule_id) {
+        trace.borrow_mut().add_response_headers_condition(rule_id, matched);
+    }
+
+    matched
+}
+
 #[cfg(test)]
 mod agent_protocol_tests {
     use super::Action;
@@ -655,4 +1498,397 @@ mod agent_protocol_tests {
 
         assert!(!action.agent_supports_rule_count());
     }
+
+    #[test]
+    fn version_1_2_supports_binary_action() {
+        let mut action = Action::default();
+        action.set_agent_protocol_version(1, 1);
+
+        assert!(!action.agent_supports_binary_action());
+
+        action.set_agent_protocol_version(1, 2);
+
+        assert!(action.agent_supports_binary_action());
+        assert!(action.agent_supports_rule_count());
+    }
+}
+
+#[cfg(all(test, feature = "router"))]
+mod test_rules {
+    use super::Action;
+    use crate::{
+        api::Rule,
+        http::{Header, Request},
+        router::Router,
+    };
+
+    pub(super) fn router(rules: &[&str]) -> Router<Rule> {
+        let mut router = Router::<Rule>::default();
+
+        for rule in rules {
+            router.insert(serde_json::from_str::<Rule>(rule).unwrap());
+        }
+
+        router
+    }
+
+    /// Action of the rules matching a request to `path` with the given headers
+    pub(super) fn action(rules: &[&str], path: &str, request_headers: &[(&str, &str)]) -> Action {
+        let router = router(rules);
+        let mut request = Request::from_config(&router.config, path.to_string(), None, None, None, None, None);
+
+        for (name, value) in request_headers {
+            request.add_header(name.to_string(), value.to_string(), false);
+        }
+
+        Action::from_router(&router, &request, None).0
+    }
+
+    pub(super) fn headers(headers: &[(&str, &str)]) -> Vec<Header> {
+        headers
+            .iter()
+            .map(|(name, value)| Header {
+                name: name.to_string(),
+                value: value.to_string(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(all(test, feature = "router"))]
+mod peer_fallback_tests {
+    use super::{Action, test_rules};
+
+    fn action(rule: &str) -> Action {
+        test_rules::action(&[rule], "/", &[])
+    }
+
+    const PEER: &str =
+        r#"{"address": "legacy:80", "sni_host": null, "request_host": null, "allow_invalid_certificates": false, "tls": false}"#;
+
+    #[test]
+    fn peer_override_on_status_codes_becomes_a_fallback() {
+        let rule = format!(
+            r#"{{"id": "a", "rank": 0, "source": {{"path": "/", "response_status_codes": [404, "5xx"]}}, "peer_override": {PEER}}}"#
+        );
+        let mut action = action(rule.as_str());
+
+        assert!(action.get_peer(None).is_none());
+        assert!(action.get_retry_peer("GET", 200, 0, None).is_none());
+        assert_eq!(
+            action.get_retry_peer("GET", 404, 0, None).map(|peer| peer.address.as_str()),
+            Some("legacy:80")
+        );
+        assert!(action.get_retry_peer("GET", 503, 1, None).is_none());
+        assert!(action.get_retry_peer("POST", 503, 0, None).is_none());
+    }
+
+    #[test]
+    fn peer_override_on_response_headers_becomes_a_fallback() {
+        let rule = format!(
+            r#"{{"id": "a", "rank": 0, "source": {{"path": "/", "response_headers": [{{"name": "X-Legacy", "type": "is_defined", "value": null}}]}}, "peer_override": {PEER}}}"#
+        );
+        let mut action = action(rule.as_str());
+        let headers = test_rules::headers(&[("x-legacy", "1")]);
+
+        assert!(action.need_proxification());
+        assert!(action.get_peer(None).is_none());
+        assert!(action.get_retry_peer("GET", 200, 0, None).is_none());
+        assert!(
+            action
+                .get_retry_peer_with_response_headers("GET", 200, Some(&[]), 0, None)
+                .is_none()
+        );
+        assert_eq!(
+            action
+                .get_retry_peer_with_response_headers("GET", 200, Some(&headers), 0, None)
+                .map(|peer| peer.address.as_str()),
+            Some("legacy:80")
+        );
+    }
+
+    #[test]
+    fn fallback_defaults_to_server_errors_and_allows_methods() {
+        let rule = format!(
+            r#"{{"id": "a", "rank": 0, "source": {{"path": "/"}}, "peer_fallback": {{"peers": [{PEER}], "methods": ["GET", "POST"], "id": null}}}}"#
+        );
+        let mut action = action(rule.as_str());
+
+        assert!(action.get_retry_peer("post", 404, 0, None).is_none());
+        assert!(action.get_retry_peer("post", 502, 0, None).is_some());
+        assert!(action.get_retry_peer("HEAD", 502, 0, None).is_none());
+        assert!(action.get_applied_rule_ids().contains("a"));
+    }
+}
+
+#[cfg(all(test, feature = "router"))]
+mod log_sampling_tests {
+    use std::sync::Arc;
+
+    use super::{Action, LogSampler, test_rules};
+
+    fn action(log_override: &str, path: &str) -> Action {
+        let rule = format!(
+            r#"{{"id": "a", "rank": 0, "source": {{"path": "/@path"}}, "markers": [{{"name": "path", "regex": ".*"}}], "log_override": {log_override}}}"#
+        );
+
+        test_rules::action(&[rule.as_str()], path, &[])
+    }
+
+    #[test]
+    fn boolean_log_override_has_no_weight() {
+        let mut logged = action("true", "/");
+
+        assert!(logged.should_log_request(false, 200, None));
+        assert_eq!(logged.get_log_weight(), None);
+        assert!(!action("false", "/").should_log_request(true, 200, None));
+    }
+
+    #[test]
+    fn sampled_log_override_is_deterministic_and_weighted() {
+        let mut logged = 0;
+
+        for i in 0..400 {
+            let path = format!("/page-{i}");
+            let mut sampled = action(r#"{"rate": 0.25}"#, path.as_str());
+            let decision = sampled.should_log_request(true, 200, None);
+
+            assert_eq!(
+                action(r#"{"rate": 0.25}"#, path.as_str()).should_log_request(true, 200, None),
+                decision
+            );
+
+            if decision {
+                assert_eq!(sampled.get_log_weight(), Some(4.0));
+                logged += 1;
+            }
+        }
+
+        assert!((60..140).contains(&logged), "{logged}");
+    }
+
+    #[test]
+    fn sampled_log_override_limits_logs_per_interval() {
+        let sampler = Arc::new(LogSampler::default());
+        let mut logged = 0;
+
+        for i in 0..10 {
+            let mut action = action(r#"{"rate": 1.0, "limit": 3, "interval": 3600}"#, format!("/page-{i}").as_str());
+            action.set_log_sampler(sampler.clone());
+
+            if action.should_log_request(true, 200, None) {
+                assert_eq!(action.get_log_weight(), Some(1.0));
+                logged += 1;
+            } else {
+                assert_eq!(action.get_log_weight(), None);
+            }
+        }
+
+        assert_eq!(logged, 3);
+    }
+}
+
+#[cfg(all(test, feature = "router"))]
+mod link_router_tests {
+    use std::{cell::RefCell, rc::Rc, sync::Arc};
+
+    use super::{Action, UnitTrace, test_rules};
+    use crate::http::Request;
+
+    fn filter(action: &mut Action, body: &str, unit_trace: Option<Rc<RefCell<UnitTrace>>>) -> Option<String> {
+        let headers = test_rules::headers(&[("Content-Type", "text/html")]);
+        let mut filter = action.create_filter_body(200, &headers, unit_trace.clone())?;
+        let mut filtered = filter.filter(body.as_bytes().to_vec(), unit_trace.clone());
+        filtered.extend(filter.end(unit_trace));
+
+        Some(String::from_utf8(filtered).unwrap())
+    }
+
+    #[test]
+    fn rewrite_links_uses_the_router_set_on_the_action() {
+        let router = Arc::new(test_rules::router(&[
+            r#"{"id": "links", "rank": 0, "source": {"path": "/"}, "body_filters": [{"action": "rewrite_links", "link": true, "id": "links:1"}]}"#,
+            r#"{"id": "a", "rank": 0, "source": {"path": "/old"}, "target": "/new", "status_code": 301}"#,
+            r#"{"id": "b", "rank": 0, "source": {"path": "/blog/old?page=2"}, "target": "https://blog.example.com/", "status_code": 302}"#,
+        ]));
+        let request = Request::from_config(&router.config, "/".to_string(), None, None, None, None, None);
+        let unit_trace = Rc::new(RefCell::new(UnitTrace::default()));
+        let mut action = Action::from_router(&router, &request, None).0;
+
+        assert!(filter(&mut action, "<a href=\"/old\"></a>", None).is_none());
+
+        action.set_link_router(router.clone(), Some("https://www.example.com/blog/"), None);
+
+        let body = filter(
+            &mut action,
+            "<link rel=\"next\" href=\"old?page=2\"><a href=\"../old#top\">Old</a><a href=\"https://www.example.com/old\">Old</a><img src=\"/old\">",
+            Some(unit_trace.clone()),
+        );
+
+        assert_eq!(
+            body.as_deref(),
+            Some(
+                "<link rel=\"next\" href=\"https://blog.example.com/\"><a href=\"/new#top\">Old</a><a href=\"https://www.example.com/new\">Old</a><img src=\"/old\">"
+            )
+        );
+
+        let trace = serde_json::to_value(&*unit_trace.borrow()).unwrap();
+
+        assert_eq!(trace["link_redirects"][0]["target"], "https://blog.example.com/");
+        assert_eq!(trace["link_redirects"][0]["rule_ids"], serde_json::json!(["b"]));
+        assert_eq!(trace["link_redirects"].as_array().unwrap().len(), 3);
+        assert_eq!(trace["value_computed_by_units"]["links:1"], "https://www.example.com/new");
+    }
+}
+
+#[cfg(all(test, feature = "router", feature = "compress"))]
+mod response_compression_tests {
+    use std::io::Read;
+
+    use super::{
+        Action,
+        test_rules::{self, headers},
+    };
+    use crate::http::Header;
+
+    fn action(accept_encoding: &str) -> Action {
+        action_with_source(r#"{"path": "/"}"#, accept_encoding)
+    }
+
+    fn action_with_source(source: &str, accept_encoding: &str) -> Action {
+        let rule = format!(
+            r#"{{"id": "compress", "rank": 0, "source": {source}, "compress_response": {{"min_size": 16, "id": "compress:1"}}, "body_filters": [{{"action": "append_text", "content": "</html>", "id": "compress:2"}}]}}"#
+        );
+
+        test_rules::action(&[rule.as_str()], "/", &[("Accept-Encoding", accept_encoding)])
+    }
+
+    fn pairs(headers: &[Header]) -> Vec<(&str, &str)> {
+        headers.iter().map(|header| (header.name.as_str(), header.value.as_str())).collect()
+    }
+
+    #[test]
+    fn compresses_the_filtered_body() {
+        let mut action = action("gzip;q=0.5, br");
+        let response_headers = action.filter_headers(
+            headers(&[("Content-Type", "text/html"), ("Content-Length", "32"), ("Vary", "Cookie")]),
+            200,
+            false,
+            None,
+        );
+
+        assert_eq!(
+            pairs(&response_headers),
+            [
+                ("Content-Type", "text/html"),
+                ("Vary", "Cookie, Accept-Encoding"),
+                ("Content-Encoding", "br")
+            ]
+        );
+
+        let mut filter = action.create_filter_body(200, &response_headers, None).unwrap();
+        let mut compressed = filter.filter(b"<html><body>Compressed</body>".to_vec(), None);
+        compressed.extend(filter.end(None));
+
+        let mut body = String::new();
+        brotli::Decompressor::new(compressed.as_slice(), 4096)
+            .read_to_string(&mut body)
+            .unwrap();
+
+        assert_eq!(body, "<html><body>Compressed</body></html>");
+        assert!(action.get_applied_rule_ids().contains("compress"));
+    }
+
+    #[test]
+    fn skips_small_encoded_or_unaccepted_responses() {
+        let small = headers(&[("Content-Type", "text/html"), ("Content-Length", "8")]);
+        let encoded = headers(&[("Content-Type", "text/html"), ("Content-Encoding", "zstd")]);
+        let image = headers(&[("Content-Type", "image/png")]);
+
+        // The length of the small response is removed as its body is filtered
+        for (accept_encoding, response_headers, expected) in [
+            ("br", small, vec![("Content-Type", "text/html")]),
+            ("gzip", encoded.clone(), pairs(&encoded)),
+            ("gzip", image.clone(), pairs(&image)),
+            ("deflate", image.clone(), pairs(&image)),
+        ] {
+            let mut action = action(accept_encoding);
+            let filtered_headers = action.filter_headers(response_headers, 200, false, None);
+
+            assert_eq!(pairs(&filtered_headers), expected);
+        }
+
+        let mut action = action("identity");
+        let response_headers = headers(&[("Content-Type", "text/html")]);
+
+        assert_eq!(
+            pairs(&action.filter_headers(response_headers.clone(), 200, false, None)),
+            pairs(&response_headers)
+        );
+    }
+
+    #[test]
+    fn compresses_only_responses_matching_the_rule_conditions() {
+        let source = r#"{"path": "/", "response_status_codes": [200], "response_headers": [{"name": "X-Compress", "type": "is_defined", "value": null}]}"#;
+        let response_headers = headers(&[("Content-Type", "text/html"), ("X-Compress", "1")]);
+
+        let mut action = action_with_source(source, "gzip");
+        assert!(action.need_proxification());
+
+        let filtered_headers = action.filter_headers(response_headers.clone(), 200, false, None);
+        assert!(pairs(&filtered_headers).contains(&("Content-Encoding", "gzip")));
+
+        for (status, response_headers) in [(404, response_headers), (200, headers(&[("Content-Type", "text/html")]))] {
+            let mut action = action_with_source(source, "gzip");
+            let filtered_headers = action.filter_headers(response_headers, status, false, None);
+
+            assert!(filtered_headers.iter().all(|header| header.name != "Content-Encoding"));
+        }
+    }
+}
+
+#[cfg(all(test, feature = "router"))]
+mod changed_body_headers_tests {
+    use super::test_rules;
+
+    fn filter_headers(response_status_code: u16, content_type: &str) -> Vec<(String, String)> {
+        let mut action = test_rules::action(
+            &[
+                r#"{"id": "body", "rank": 0, "source": {"path": "/", "response_status_codes": [200]}, "body_filters": [{"action": "append_html", "css_selector": "body", "value": "<p>Added</p>", "id": "body:1"}]}"#,
+            ],
+            "/",
+            &[],
+        );
+
+        let headers = test_rules::headers(&[
+            ("Content-Type", content_type),
+            ("Content-Length", "120"),
+            ("ETag", "\"33a64df5\""),
+            ("Accept-Ranges", "bytes"),
+            ("Content-MD5", "Q2hlY2sgSW50ZWdyaXR5IQ=="),
+        ]);
+
+        action
+            .filter_headers(headers, response_status_code, false, None)
+            .into_iter()
+            .map(|header| (header.name, header.value))
+            .collect()
+    }
+
+    #[test]
+    fn headers_of_the_original_body_are_removed_when_it_is_filtered() {
+        assert_eq!(
+            filter_headers(200, "text/html; charset=utf-8"),
+            [
+                ("Content-Type".to_string(), "text/html; charset=utf-8".to_string()),
+                ("ETag".to_string(), "W/\"33a64df5\"".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn headers_are_kept_when_the_body_is_not_filtered() {
+        assert_eq!(filter_headers(200, "image/png").len(), 5);
+        assert_eq!(filter_headers(404, "text/html").len(), 5);
+    }
 }
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "router")]
+use crate::api::StatusCodeRange;
+use crate::{
+    action::ResponseHeaderCondition,
+    api::{Peer, StatusCodes},
+};
+
+#[cfg(feature = "router")]
+const DEFAULT_RETRY_METHODS: [&str; 2] = ["GET", "HEAD"];
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PeerFallback {
+    pub peers: Vec<Peer>,
+    pub on_response_status_codes: StatusCodes,
+    pub exclude_response_status_codes: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub on_response_headers: Vec<ResponseHeaderCondition>,
+    pub methods: Vec<String>,
+    pub rule_id: Option<String>,
+    pub unit_id: Option<String>,
+}
+
+impl PeerFallback {
+    /// Server errors, used when the rule has no response condition
+    #[cfg(feature = "router")]
+    pub fn default_status_codes() -> StatusCodes {
+        StatusCodes::new(vec![StatusCodeRange { start: 500, end: 599 }])
+    }
+
+    #[cfg(feature = "router")]
+    pub fn default_methods() -> Vec<String> {
+        DEFAULT_RETRY_METHODS.iter().map(|method| method.to_string()).collect()
+    }
+
+    /// Peer to retry the request on, `attempt` being the number of fallback peers already tried,
+    /// `response_headers_matched` telling whether the response headers satisfy `on_response_headers`
+    pub fn get_peer(&self, method: &str, response_status_code: u16, response_headers_matched: bool, attempt: usize) -> Option<&Peer> {
+        if !self.methods.iter().any(|allowed| allowed.eq_ignore_ascii_case(method)) {
+            return None;
+        }
+
+        if !self.on_response_headers.is_empty() && !response_headers_matched {
+            return None;
+        }
+
+        if !self.on_response_status_codes.is_empty()
+            && !self
+                .on_response_status_codes
+                .matches(self.exclude_response_status_codes, response_status_code)
+        {
+            return None;
+        }
+
+        self.peers.get(attempt)
+    }
+}
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use crate::api::Peer;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct PeerOverride {
     pub peer: Peer,
     pub rule_id: Option<String>,
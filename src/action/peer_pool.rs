@@ -0,0 +1,136 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{Peer, WeightedPeer},
+    hash::stable_unit_hash,
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PeerPoolOverride {
+    pub peers: Vec<WeightedPeer>,
+    pub key: String,
+    pub failure_cooldown: u64,
+    pub rule_id: Option<String>,
+    pub unit_id: Option<String>,
+}
+
+/// Failures reported by the proxy for each peer address, shared between requests and threads
+#[derive(Debug, Default)]
+pub struct PeerHealth {
+    failures: Mutex<HashMap<String, i64>>,
+}
+
+impl PeerHealth {
+    pub fn report_failure(&self, address: &str) {
+        if let Ok(mut failures) = self.failures.lock() {
+            failures.insert(address.to_string(), Utc::now().timestamp_millis());
+        }
+    }
+
+    pub fn report_success(&self, address: &str) {
+        if let Ok(mut failures) = self.failures.lock() {
+            failures.remove(address);
+        }
+    }
+
+    pub fn is_available(&self, address: &str, cooldown: u64) -> bool {
+        let failures = match self.failures.lock() {
+            Ok(failures) => failures,
+            Err(_) => return true,
+        };
+
+        match failures.get(address) {
+            None => true,
+            Some(failed_at) => Utc::now().timestamp_millis() - failed_at >= (cooldown as i64).saturating_mul(1000),
+        }
+    }
+}
+
+impl PeerPoolOverride {
+    /// Weighted rendezvous hashing on the key: a key keeps its peer as long as it is available,
+    /// and only keys of an unavailable peer move. When every peer is in cooldown, health is ignored.
+    pub fn select(&self, health: Option<&PeerHealth>) -> Option<&Peer> {
+        let available = |peer: &&WeightedPeer| health.is_none_or(|health| health.is_available(&peer.peer.address, self.failure_cooldown));
+
+        self.best(self.peers.iter().filter(available))
+            .or_else(|| self.best(self.peers.iter()))
+    }
+
+    fn best<'a>(&self, peers: impl Iterator<Item = &'a WeightedPeer>) -> Option<&'a Peer> {
+        peers
+            .filter(|peer| peer.weight > 0)
+            .map(|peer| (self.score(peer), peer))
+            .max_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(_, peer)| &peer.peer)
+    }
+
+    fn score(&self, peer: &WeightedPeer) -> f64 {
+        let hash = stable_unit_hash(format!("{}\n{}", self.key, peer.peer.address).as_bytes());
+
+        // -w / ln(u) is maximal for the peer to pick, ln(1) being 0 the hash is kept below 1
+        -(peer.weight as f64) / hash.min(1.0 - f64::EPSILON).ln()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool(weights: &[u32], key: &str) -> PeerPoolOverride {
+        PeerPoolOverride {
+            peers: weights
+                .iter()
+                .enumerate()
+                .map(|(index, weight)| WeightedPeer {
+                    peer: Peer {
+                        address: format!("peer-{index}:80"),
+                        sni_host: None,
+                        request_host: None,
+                        allow_invalid_certificates: false,
+                        tls: false,
+                    },
+                    weight: *weight,
+                })
+                .collect(),
+            key: key.to_string(),
+            failure_cooldown: 3600,
+            rule_id: None,
+            unit_id: None,
+        }
+    }
+
+    #[test]
+    fn select_is_sticky_and_weighted() {
+        let mut counts = [0; 2];
+
+        for i in 0..1000 {
+            let pool = pool(&[1, 3], format!("client-{i}").as_str());
+            let address = pool.select(None).unwrap().address.clone();
+
+            assert_eq!(pool.select(None).unwrap().address, address);
+            counts[if address == "peer-0:80" { 0 } else { 1 }] += 1;
+        }
+
+        assert!(counts[1] > counts[0] * 2, "{counts:?}");
+    }
+
+    #[test]
+    fn select_skips_failed_peers_during_cooldown() {
+        let health = PeerHealth::default();
+        let pool = pool(&[1, 1], "client");
+        let selected = pool.select(Some(&health)).unwrap().address.clone();
+
+        health.report_failure(selected.as_str());
+        assert_ne!(pool.select(Some(&health)).unwrap().address, selected);
+
+        health.report_failure(pool.select(Some(&health)).unwrap().address.as_str());
+        assert_eq!(pool.select(Some(&health)).unwrap().address, selected);
+
+        health.report_success(selected.as_str());
+        assert_eq!(pool.select(Some(&health)).unwrap().address, selected);
+    }
+}
@@ -0,0 +1,180 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{action::ResponseHeaderCondition, api::StatusCodes, filter::may_accept_text_splicing, http::Header};
+
+/// Responses smaller than this are not compressed when the rule gives no minimum size
+pub const DEFAULT_MIN_COMPRESSION_SIZE: u64 = 1024;
+
+// Encodings the response can be compressed with, by order of preference
+#[cfg(feature = "router")]
+const COMPRESSION_ENCODINGS: [&str; 2] = ["br", "gzip"];
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ResponseCompressionOverride {
+    /// Encoding negotiated with the `Accept-Encoding` of the request
+    pub encoding: String,
+    pub min_size: u64,
+    #[serde(default)]
+    pub on_response_status_codes: StatusCodes,
+    #[serde(default)]
+    pub exclude_response_status_codes: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub on_response_headers: Vec<ResponseHeaderCondition>,
+    pub rule_id: Option<String>,
+    pub unit_id: Option<String>,
+    // Whether the headers of the response were updated for the compression, the body is then
+    // compressed by the body filter. None until the headers of the response are filtered.
+    #[serde(skip)]
+    pub applied: Option<bool>,
+}
+
+impl ResponseCompressionOverride {
+    /// Encoding preferred by the client among the supported ones, from the q-values of its
+    /// `Accept-Encoding` headers, brotli being chosen over gzip on a tie
+    #[cfg(feature = "router")]
+    pub fn negotiate_encoding(request_headers: &[Header]) -> Option<&'static str> {
+        let mut qualities = [None; COMPRESSION_ENCODINGS.len()];
+        let mut wildcard = None;
+
+        for header in request_headers {
+            if !header.name.eq_ignore_ascii_case("accept-encoding") {
+                continue;
+            }
+
+            for coding in header.value.split(',') {
+                let mut parameters = coding.split(';');
+                let name = parameters.next().unwrap_or_default().trim().to_ascii_lowercase();
+                let quality = parameters
+                    .filter_map(|parameter| parameter.trim().strip_prefix("q="))
+                    .find_map(|quality| quality.trim().parse::<f32>().ok())
+                    .unwrap_or(1.0);
+
+                let name = if name == "x-gzip" { "gzip" } else { name.as_str() };
+
+                match COMPRESSION_ENCODINGS.iter().position(|encoding| *encoding == name) {
+                    Some(index) => qualities[index] = Some(quality),
+                    None if name == "*" => wildcard = Some(quality),
+                    None => (),
+                }
+            }
+        }
+
+        let mut negotiated: Option<(&'static str, f32)> = None;
+
+        for (encoding, quality) in COMPRESSION_ENCODINGS.iter().zip(qualities) {
+            let Some(quality) = quality.or(wildcard) else {
+                continue;
+            };
+
+            if quality > 0.0 && negotiated.is_none_or(|(_, best)| quality > best) {
+                negotiated = Some((encoding, quality));
+            }
+        }
+
+        negotiated.map(|(encoding, _)| encoding)
+    }
+
+    /// Whether the response status code satisfies the conditions of the rule, response header
+    /// conditions being checked by the caller
+    pub fn matches_status_code(&self, response_status_code: u16) -> bool {
+        self.on_response_status_codes.is_empty()
+            || self
+                .on_response_status_codes
+                .matches(self.exclude_response_status_codes, response_status_code)
+    }
+
+    /// Whether a response with these headers is to be compressed: not already encoded, not
+    /// empty or partial, of a known content type which is not already compressed, and not
+    /// smaller than the minimum size
+    pub fn should_compress(&self, response_status_code: u16, headers: &[Header]) -> bool {
+        if !cfg!(feature = "compress") || matches!(response_status_code, 204 | 206 | 304) {
+            return false;
+        }
+
+        let mut has_content_type = false;
+
+        for header in headers {
+            let value = header.value.trim().to_ascii_lowercase();
+
+            match header.name.to_ascii_lowercase().as_str() {
+                "content-encoding" if !value.is_empty() && value != "identity" => return false,
+                "content-type" if !may_accept_text_splicing(value.as_str()) => return false,
+                "content-type" => has_content_type = true,
+                "content-length" if value.parse::<u64>().is_ok_and(|length| length < self.min_size) => return false,
+                "content-range" => return false,
+                "cache-control" if value.split(',').any(|directive| directive.trim() == "no-transform") => return false,
+                _ => (),
+            }
+        }
+
+        has_content_type
+    }
+
+    /// Update the headers of the response for its compressed body: `Content-Encoding` is set and
+    /// `Accept-Encoding` is added to `Vary`
+    pub fn compress_headers(&self, headers: Vec<Header>) -> Vec<Header> {
+        let mut new_headers = Vec::with_capacity(headers.len() + 2);
+        let mut has_vary = false;
+
+        for mut header in headers {
+            match header.name.to_ascii_lowercase().as_str() {
+                "content-encoding" => continue,
+                "vary" => {
+                    has_vary = true;
+
+                    if !header
+                        .value
+                        .split(',')
+                        .any(|value| value.trim() == "*" || value.trim().eq_ignore_ascii_case("accept-encoding"))
+                    {
+                        header.value = format!("{}, Accept-Encoding", header.value);
+                    }
+                }
+                _ => (),
+            }
+
+            new_headers.push(header);
+        }
+
+        if !has_vary {
+            new_headers.push(Header {
+                name: "Vary".to_string(),
+                value: "Accept-Encoding".to_string(),
+            });
+        }
+
+        new_headers.push(Header {
+            name: "Content-Encoding".to_string(),
+            value: self.encoding.clone(),
+        });
+
+        new_headers
+    }
+}
+
+#[cfg(all(test, feature = "router"))]
+mod tests {
+    use super::*;
+
+    fn accept_encoding(value: &str) -> Vec<Header> {
+        vec![Header {
+            name: "Accept-Encoding".to_string(),
+            value: value.to_string(),
+        }]
+    }
+
+    #[test]
+    fn negotiates_the_preferred_encoding() {
+        let negotiate = |value: &str| ResponseCompressionOverride::negotiate_encoding(&accept_encoding(value));
+
+        assert_eq!(negotiate("gzip, deflate, br"), Some("br"));
+        assert_eq!(negotiate("gzip;q=1.0, br;q=0.8"), Some("gzip"));
+        assert_eq!(negotiate("br;q=0, gzip"), Some("gzip"));
+        assert_eq!(negotiate("*;q=0.5, br;q=0"), Some("gzip"));
+        assert_eq!(negotiate("x-gzip"), Some("gzip"));
+        assert_eq!(negotiate("identity, deflate"), None);
+        assert_eq!(negotiate("gzip;q=0"), None);
+        assert_eq!(ResponseCompressionOverride::negotiate_encoding(&[]), None);
+    }
+}
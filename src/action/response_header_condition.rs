@@ -0,0 +1,122 @@
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::Header,
+    http::Header as HttpHeader,
+    marker::{Marker, MarkerString},
+};
+
+/// Condition of a rule on a response header, encoded as the header constraint of the rule.
+/// The regex of a `match_regex` condition is compiled once, when the action is built or
+/// decoded, not for every response.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(from = "ResponseHeaderConditionData", into = "ResponseHeaderConditionData")]
+pub struct ResponseHeaderCondition {
+    header: Header,
+    regex: Option<Regex>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+struct ResponseHeaderConditionData {
+    #[serde(flatten)]
+    header: Header,
+    /// Regex of a `match_regex` condition, built from its value and the markers of the rule
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    regex: Option<String>,
+}
+
+impl From<ResponseHeaderConditionData> for ResponseHeaderCondition {
+    fn from(data: ResponseHeaderConditionData) -> Self {
+        ResponseHeaderCondition {
+            regex: data.regex.as_deref().and_then(compile_regex),
+            header: data.header,
+        }
+    }
+}
+
+impl From<ResponseHeaderCondition> for ResponseHeaderConditionData {
+    fn from(condition: ResponseHeaderCondition) -> Self {
+        ResponseHeaderConditionData {
+            regex: condition.regex.map(|regex| regex.as_str().to_string()),
+            header: condition.header,
+        }
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for ResponseHeaderCondition {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        ResponseHeaderConditionData::schema_name()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        ResponseHeaderConditionData::json_schema(generator)
+    }
+}
+
+fn compile_regex(regex: &str) -> Option<Regex> {
+    match RegexBuilder::new(regex).case_insensitive(true).build() {
+        Ok(regex) => Some(regex),
+        Err(err) => {
+            tracing::error!("cannot compile response header regex {regex}: {err}");
+
+            None
+        }
+    }
+}
+
+impl ResponseHeaderCondition {
+    /// Like for a request header, the value of a `match_regex` condition is matched literally
+    /// except for the markers of the rule, and a value without markers does not constrain the
+    /// header: there is no condition then.
+    pub fn new(header: Header, markers: Vec<Marker>) -> Option<ResponseHeaderCondition> {
+        if header.kind != "match_regex" {
+            return Some(ResponseHeaderCondition { header, regex: None });
+        }
+
+        let marker_string = MarkerString::new(header.value.as_deref()?, markers, true)?;
+
+        Some(ResponseHeaderCondition {
+            regex: compile_regex(marker_string.regex.as_str()),
+            header,
+        })
+    }
+
+    /// Whether the response headers satisfy all the conditions, a response header condition
+    /// uses the same types as a request header one.
+    pub fn match_all(conditions: &[ResponseHeaderCondition], headers: &[HttpHeader]) -> bool {
+        conditions.iter().all(|condition| condition.matches(headers))
+    }
+
+    /// Response header names and values are compared case insensitively, values like
+    /// `Content-Type` or `Cache-Control` directives do not depend on case.
+    pub fn matches(&self, headers: &[HttpHeader]) -> bool {
+        let mut values = headers
+            .iter()
+            .filter(|header| header.name.eq_ignore_ascii_case(self.header.name.as_str()))
+            .map(|header| header.value.to_lowercase());
+        let expected = self.header.value.as_deref().map(str::to_lowercase);
+
+        match (self.header.kind.as_str(), expected) {
+            ("is_defined", _) => values.next().is_some(),
+            ("is_not_defined", _) => values.next().is_none(),
+            ("is_equals", Some(expected)) => values.any(|value| value == expected),
+            ("is_not_equal_to", Some(expected)) => values.all(|value| value != expected),
+            ("contains", Some(expected)) => values.any(|value| value.contains(expected.as_str())),
+            ("does_not_contain", Some(expected)) => values.all(|value| !value.contains(expected.as_str())),
+            ("starts_with", Some(expected)) => values.any(|value| value.starts_with(expected.as_str())),
+            ("ends_with", Some(expected)) => values.any(|value| value.ends_with(expected.as_str())),
+            ("match_regex", Some(_)) => match self.regex.as_ref() {
+                Some(regex) => values.any(|value| regex.is_match(value.as_str())),
+                None => false,
+            },
+            (kind, _) => {
+                tracing::error!("unsupported response header constraint type {kind}");
+
+                false
+            }
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RewriteOverride {
+    /// Path and query sent to the upstream instead of the ones requested by the client
+    pub path_and_query: String,
+    pub rule_id: Option<String>,
+    pub unit_id: Option<String>,
+}
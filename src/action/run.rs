@@ -10,17 +10,25 @@ use crate::{
 };
 
 #[derive(Serialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RunExample {
     pub request: Request,
     pub(crate) unit_trace: UnitTrace,
     pub(crate) backend_status_code: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) upstream_request_headers: Option<Vec<Header>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) upstream_url: Option<String>,
     pub(crate) response: RunResponse,
     pub(crate) should_log_request: bool,
     pub(crate) redirection_loop: Option<RedirectionLoop>,
     pub(crate) match_traces: Vec<Trace<Rule>>,
+    #[serde(skip)]
+    rewritten_requests: Vec<Request>,
 }
 
 #[derive(Serialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RunResponse {
     pub(crate) status_code: u16,
     pub(crate) headers: Vec<Header>,
@@ -30,43 +38,74 @@ pub struct RunResponse {
 impl RunExample {
     pub fn new(router: &Router<Rule>, example: &Example) -> Result<Self, http::Error> {
         let request = Request::from_example(&router.config, example)?;
-        let routes = router.match_request(&request);
         let unit_trace = Rc::new(RefCell::new(UnitTrace::default()));
 
-        let mut action = Action::from_routes_rule(routes, &request, Some(unit_trace.clone()));
+        let (mut action, rewritten_requests) = Action::from_router(router, &request, Some(unit_trace.clone()));
 
+        let upstream_request_headers = if action.has_request_header_filters() {
+            Some(action.filter_request_headers(request.headers.clone(), Some(unit_trace.clone())))
+        } else {
+            None
+        };
+
+        let upstream_url = action.get_rewrite(Some(unit_trace.clone())).map(str::to_string);
+        action.select_peer(None, Some(unit_trace.clone()));
+        let synthetic_response = action.get_synthetic_response(Some(unit_trace.clone())).cloned();
         let action_status_code = action.get_status_code(0, Some(unit_trace.clone()));
-        let (final_status_code, backend_status_code) = if action_status_code != 0 {
-            (action_status_code, action_status_code)
+        let (final_status_code, backend_status_code, response_headers, headers) = if action_status_code != 0 && synthetic_response.is_none()
+        {
+            let headers = action.filter_headers(Vec::new(), action_status_code, false, Some(unit_trace.clone()));
+
+            (action_status_code, action_status_code, None, headers)
         } else {
-            // We call the backend and get a response code
-            let backend_status_code = example.response_status_code.unwrap_or(200);
-            let final_status_code = action.get_status_code(backend_status_code, Some(unit_trace.clone()));
+            // We call the backend, or serve the synthetic response, and get a response code and
+            // headers, which may condition the status code update
+            let (backend_status_code, response_headers): (u16, Vec<Header>) = match &synthetic_response {
+                Some(synthetic_response) => (synthetic_response.status_code, synthetic_response.headers.clone()),
+                None => (
+                    example.response_status_code.unwrap_or(200),
+                    example
+                        .response_headers
+                        .iter()
+                        .map(|header| Header {
+                            name: header.name.clone(),
+                            value: header.value.clone(),
+                        })
+                        .collect(),
+                ),
+            };
+            let final_status_code =
+                action.get_status_code_with_response_headers(backend_status_code, Some(&response_headers), Some(unit_trace.clone()));
+            let headers = action.filter_headers(response_headers.clone(), backend_status_code, false, Some(unit_trace.clone()));
 
-            (final_status_code, backend_status_code)
+            (final_status_code, backend_status_code, Some(response_headers), headers)
         };
 
-        let headers = action.filter_headers(Vec::new(), backend_status_code, false, Some(unit_trace.clone()));
-
-        let mut body = example.response_body.as_deref().unwrap_or(
-            "<!DOCTYPE html>
+        let mut body = match &synthetic_response {
+            Some(synthetic_response) => synthetic_response.body.as_str(),
+            None => example.response_body.as_deref().unwrap_or(
+                "<!DOCTYPE html>
 <html>
     <head>
     </head>
     <body>
     </body>
 </html>",
-        );
+            ),
+        };
 
-        let mut b1;
-        if let Some(mut body_filter) = action.create_filter_body(backend_status_code, &[], Some(unit_trace.clone())) {
-            b1 = body_filter.filter(body.into(), Some(unit_trace.clone()));
+        let filtered_body;
+        if let Some(mut body_filter) = action.create_filter_body(backend_status_code, &headers, Some(unit_trace.clone())) {
+            let mut b1 = body_filter.filter(body.into(), Some(unit_trace.clone()));
             let b2 = body_filter.end(Some(unit_trace.clone()));
             b1.extend(b2);
-            body = std::str::from_utf8(&b1).unwrap();
+            // Compressed bodies are not valid UTF-8
+            filtered_body = String::from_utf8_lossy(&b1).into_owned();
+            body = filtered_body.as_str();
         }
 
-        let should_log_request = action.should_log_request(true, final_status_code, Some(unit_trace.clone()));
+        let should_log_request =
+            action.should_log_request_with_response_headers(true, final_status_code, response_headers.as_deref(), Some(unit_trace.clone()));
         let mut unit_trace = unit_trace.take();
         unit_trace.squash_with_target_unit_traces();
 
@@ -74,6 +113,8 @@ impl RunExample {
             request,
             unit_trace,
             backend_status_code,
+            upstream_request_headers,
+            upstream_url,
             response: RunResponse {
                 status_code: final_status_code,
                 headers,
@@ -82,6 +123,7 @@ impl RunExample {
             should_log_request,
             redirection_loop: None,
             match_traces: vec![],
+            rewritten_requests,
         })
     }
 
@@ -91,5 +133,10 @@ impl RunExample {
 
     pub fn with_match_traces(&mut self, router: &Router<Rule>) {
         self.match_traces = router.trace_request(&self.request);
+
+        // Traces of the rewritten requests matched in the following passes
+        for request in &self.rewritten_requests {
+            self.match_traces.extend(router.trace_request(request));
+        }
     }
 }
@@ -1,10 +1,15 @@
 use serde::{Deserialize, Serialize};
 
+use crate::{action::ResponseHeaderCondition, api::StatusCodes};
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct StatusCodeUpdate {
     pub status_code: u16,
-    pub on_response_status_codes: Vec<u16>,
+    pub on_response_status_codes: StatusCodes,
     pub exclude_response_status_codes: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub on_response_headers: Vec<ResponseHeaderCondition>,
     pub fallback_status_code: u16,
     pub rule_id: Option<String>,
     pub fallback_rule_id: Option<String>,
@@ -13,16 +18,33 @@ pub struct StatusCodeUpdate {
 }
 
 impl StatusCodeUpdate {
+    /// Whether this update depends on the backend response
+    pub fn is_conditional(&self) -> bool {
+        !self.on_response_status_codes.is_empty() || !self.on_response_headers.is_empty()
+    }
+
+    /// Status code for this response, updates conditioned on response headers do not apply
     pub fn get_status_code(&self, response_status_code: u16) -> (u16, Option<&String>) {
-        if response_status_code == 0 && self.on_response_status_codes.is_empty() {
-            return (self.status_code, self.rule_id.as_ref());
-        }
+        self.get_status_code_with_headers_matched(response_status_code, false)
+    }
 
-        if self.exclude_response_status_codes && !self.on_response_status_codes.contains(&response_status_code) {
-            return (self.status_code, self.rule_id.as_ref());
-        }
+    /// Status code for this response, `response_headers_matched` telling whether the response
+    /// headers satisfy `on_response_headers`
+    pub fn get_status_code_with_headers_matched(
+        &self,
+        response_status_code: u16,
+        response_headers_matched: bool,
+    ) -> (u16, Option<&String>) {
+        let headers_matched = self.on_response_headers.is_empty() || response_headers_matched;
+        let status_code_matched = if self.on_response_status_codes.is_empty() {
+            // Without status code conditions, updates conditioned by response headers apply once the backend responded
+            response_status_code == 0 || !self.on_response_headers.is_empty()
+        } else {
+            self.on_response_status_codes
+                .matches(self.exclude_response_status_codes, response_status_code)
+        };
 
-        if !self.exclude_response_status_codes && self.on_response_status_codes.contains(&response_status_code) {
+        if headers_matched && status_code_matched {
             return (self.status_code, self.rule_id.as_ref());
         }
 
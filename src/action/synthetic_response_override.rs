@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::SyntheticResponse;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SyntheticResponseOverride {
+    pub response: SyntheticResponse,
+    pub rule_id: Option<String>,
+}
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use crate::{action::Action, api::Rule, http::Request, router::Trace};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TraceAction {
     action: Action,
     rule: Rule,
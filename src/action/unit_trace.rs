@@ -12,7 +12,9 @@ use crate::{
 };
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct WithTargetUnitTrace {
+    #[cfg_attr(feature = "schema", schemars(with = "HashMap<String, Vec<String>>"))]
     unit_ids_applied_by_key: LinkedHashMap<String, LinkedHashSet<String>>,
 }
 
@@ -29,11 +31,74 @@ impl WithTargetUnitTrace {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MatchPass {
+    pub path_and_query: String,
+    pub rule_ids: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PeerSelection {
+    pub address: String,
+    pub key: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct JsonOperationTrace {
+    pub unit_id: Option<String>,
+    pub op: String,
+    pub path: String,
+    /// Number of locations changed by the operation
+    pub count: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LinkRedirectTrace {
+    pub link: String,
+    pub target: String,
+    /// Rules of the redirects followed from the link to the target
+    pub rule_ids: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UnitTrace {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<String>"))]
     pub(crate) rule_ids_applied: LinkedHashSet<String>,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<String>"))]
     unit_ids_applied: LinkedHashSet<String>,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<String>"))]
     unit_ids_seen: LinkedHashSet<String>,
     value_computed_by_units: HashMap<String, String>,
+    // Result of the response header conditions, by rule id
+    #[serde(default, skip_serializing_if = "LinkedHashMap::is_empty")]
+    #[cfg_attr(feature = "schema", schemars(with = "HashMap<String, bool>"))]
+    response_headers_conditions: LinkedHashMap<String, bool>,
+    // Requests matched when rewritten requests are matched again, the first one being the original request
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    match_passes: Vec<MatchPass>,
+    // Peer chosen in a peer pool
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    peer_selected: Option<PeerSelection>,
+    // Number of replacements done by regex body filters, by unit id (or pattern without unit)
+    #[serde(default, skip_serializing_if = "LinkedHashMap::is_empty")]
+    #[cfg_attr(feature = "schema", schemars(with = "HashMap<String, usize>"))]
+    body_replacements: LinkedHashMap<String, usize>,
+    // Operations of json body filters
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    json_operations: Vec<JsonOperationTrace>,
+    // Links of the body rewritten to the target of their redirect
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    link_redirects: Vec<LinkRedirectTrace>,
+    // Whether links of the body were left unchanged because too many were looked up
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    link_lookup_limit_reached: bool,
+    // Rewritten path and query that was already matched in a previous pass
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rewrite_loop: Option<String>,
     #[serde(skip_serializing)]
     with_target_unit_trace: WithTargetUnitTrace,
 }
@@ -84,6 +149,57 @@ impl UnitTrace {
         self.value_computed_by_units.insert(key.to_string(), value.to_string());
     }
 
+    pub fn add_response_headers_condition(&mut self, rule_id: &str, matched: bool) {
+        self.response_headers_conditions.insert(rule_id.to_string(), matched);
+    }
+
+    pub fn add_match_pass(&mut self, path_and_query: &str, rule_ids: Vec<String>) {
+        self.match_passes.push(MatchPass {
+            path_and_query: path_and_query.to_string(),
+            rule_ids,
+        });
+    }
+
+    pub fn set_peer_selected(&mut self, address: &str, key: &str) {
+        self.peer_selected = Some(PeerSelection {
+            address: address.to_string(),
+            key: key.to_string(),
+        });
+    }
+
+    pub fn add_body_replacements(&mut self, key: &str, count: usize) {
+        *self.body_replacements.entry(key.to_string()).or_insert(0) += count;
+    }
+
+    pub fn get_body_replacements(&self, key: &str) -> usize {
+        self.body_replacements.get(key).copied().unwrap_or(0)
+    }
+
+    pub fn add_json_operation(&mut self, unit_id: Option<&str>, op: &str, path: &str, count: usize) {
+        self.json_operations.push(JsonOperationTrace {
+            unit_id: unit_id.map(str::to_string),
+            op: op.to_string(),
+            path: path.to_string(),
+            count,
+        });
+    }
+
+    pub fn add_link_redirect(&mut self, link: &str, target: &str, rule_ids: Vec<String>) {
+        self.link_redirects.push(LinkRedirectTrace {
+            link: link.to_string(),
+            target: target.to_string(),
+            rule_ids,
+        });
+    }
+
+    pub fn set_link_lookup_limit_reached(&mut self) {
+        self.link_lookup_limit_reached = true;
+    }
+
+    pub fn set_rewrite_loop(&mut self, path_and_query: &str) {
+        self.rewrite_loop = Some(path_and_query.to_string());
+    }
+
     pub fn diff(&self, other: Vec<String>) -> LinkedHashSet<String> {
         let mut diff = LinkedHashSet::new();
 
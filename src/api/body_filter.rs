@@ -1,9 +1,13 @@
 use html_to_markdown_rs::ConversionOptions;
 use serde::{Deserialize, Serialize};
 
-use crate::{api::VariableValue, marker::StaticOrDynamic};
+use crate::{
+    api::{VariableValue, escape_variables},
+    marker::StaticOrDynamic,
+};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct HTMLBodyFilterInnerLegacy {
     pub value: String,
     pub inner_value: Option<String>,
@@ -31,6 +35,7 @@ impl HTMLBodyFilterInnerLegacy {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct HTMLBodyFilterInner {
     pub value: String,
     pub inner_value: Option<String>,
@@ -56,6 +61,7 @@ impl HTMLBodyFilterInner {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct HTMLBodyFilterAppend {
     pub value: String,
     pub inner_value: Option<String>,
@@ -83,6 +89,7 @@ impl HTMLBodyFilterAppend {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct HTMLBodyFilterRemove {
     pub css_selector: String,
     pub id: Option<String>,
@@ -90,6 +97,125 @@ pub struct HTMLBodyFilterRemove {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HTMLBodyFilterAttribute {
+    pub css_selector: String,
+    pub attribute: String,
+    /// Value set by `set_attribute`, or replacement of the matches of `pattern` by `replace_attribute`
+    /// where `$1` or `${name}` are capture groups
+    #[serde(default)]
+    pub value: String,
+    /// Regex searched in the value of the attribute by `replace_attribute`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    pub id: Option<String>,
+    pub target_hash: Option<String>,
+}
+
+impl HTMLBodyFilterAttribute {
+    pub fn clone_with_variables_replaced(&self, variables: &[(String, VariableValue)]) -> HTMLBodyFilterAttribute {
+        HTMLBodyFilterAttribute {
+            css_selector: self.css_selector.clone(),
+            attribute: self.attribute.clone(),
+            value: StaticOrDynamic::replace(self.value.clone(), variables, false),
+            pattern: self.pattern.clone(),
+            id: self.id.clone(),
+            target_hash: self.target_hash.clone(),
+        }
+    }
+}
+
+/// Rewrite the `href` of `a` elements, and of the other elements enabled, to the final target
+/// of their redirect in the router set on the action
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HTMLBodyFilterRewriteLinks {
+    /// Also rewrite the `href` of `link` elements
+    #[serde(default)]
+    pub link: bool,
+    /// Also rewrite the `src` of `img` elements
+    #[serde(default)]
+    pub img: bool,
+    /// Also rewrite the `action` of `form` elements
+    #[serde(default)]
+    pub form: bool,
+    pub id: Option<String>,
+    pub target_hash: Option<String>,
+}
+
+/// Set the metadata tags of the `head`: an existing tag is updated, and a missing one is inserted
+/// at the end of the `head`, so applying the filter twice gives the same document
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HTMLBodyFilterHeadMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Content of the `description` meta tag
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Href of the `canonical` link
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canonical: Option<String>,
+    /// Content of the `robots` meta tag
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub robots: Option<String>,
+    /// Alternate links of the page in other languages
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hreflang: Vec<HTMLHeadHreflang>,
+    /// Open Graph meta tags, the `og:` prefix of the property may be omitted
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub open_graph: Vec<HTMLHeadOpenGraph>,
+    pub id: Option<String>,
+    pub target_hash: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HTMLHeadHreflang {
+    pub hreflang: String,
+    pub href: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HTMLHeadOpenGraph {
+    pub property: String,
+    pub content: String,
+}
+
+impl HTMLBodyFilterHeadMetadata {
+    pub fn clone_with_variables_replaced(&self, variables: &[(String, VariableValue)]) -> HTMLBodyFilterHeadMetadata {
+        let replace = |value: &String| StaticOrDynamic::replace(value.clone(), variables, false);
+
+        HTMLBodyFilterHeadMetadata {
+            title: self.title.as_ref().map(replace),
+            description: self.description.as_ref().map(replace),
+            canonical: self.canonical.as_ref().map(replace),
+            robots: self.robots.as_ref().map(replace),
+            hreflang: self
+                .hreflang
+                .iter()
+                .map(|alternate| HTMLHeadHreflang {
+                    hreflang: alternate.hreflang.clone(),
+                    href: replace(&alternate.href),
+                })
+                .collect(),
+            open_graph: self
+                .open_graph
+                .iter()
+                .map(|property| HTMLHeadOpenGraph {
+                    property: property.property.clone(),
+                    content: replace(&property.content),
+                })
+                .collect(),
+            id: self.id.clone(),
+            target_hash: self.target_hash.clone(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "action")]
 pub enum HTMLBodyFilter {
     #[serde(rename = "append_html")]
@@ -104,6 +230,16 @@ pub enum HTMLBodyFilter {
     After(HTMLBodyFilterInner),
     #[serde(rename = "before_html")]
     Before(HTMLBodyFilterInner),
+    #[serde(rename = "set_attribute")]
+    SetAttribute(HTMLBodyFilterAttribute),
+    #[serde(rename = "remove_attribute")]
+    RemoveAttribute(HTMLBodyFilterAttribute),
+    #[serde(rename = "replace_attribute")]
+    ReplaceAttribute(HTMLBodyFilterAttribute),
+    #[serde(rename = "rewrite_links")]
+    RewriteLinks(HTMLBodyFilterRewriteLinks),
+    #[serde(rename = "head_metadata")]
+    HeadMetadata(HTMLBodyFilterHeadMetadata),
     #[serde(rename = "append_child")]
     AppendLegacy(HTMLBodyFilterInnerLegacy),
     #[serde(rename = "prepend_child")]
@@ -124,19 +260,32 @@ impl HTMLBodyFilter {
             HTMLBodyFilter::Remove(inner) => HTMLBodyFilter::Remove(inner.clone()),
             HTMLBodyFilter::After(inner) => HTMLBodyFilter::After(inner.clone_with_variables_replaced(variables)),
             HTMLBodyFilter::Before(inner) => HTMLBodyFilter::Before(inner.clone_with_variables_replaced(variables)),
+            HTMLBodyFilter::SetAttribute(inner) => HTMLBodyFilter::SetAttribute(inner.clone_with_variables_replaced(variables)),
+            HTMLBodyFilter::RemoveAttribute(inner) => HTMLBodyFilter::RemoveAttribute(inner.clone()),
+            HTMLBodyFilter::ReplaceAttribute(inner) => HTMLBodyFilter::ReplaceAttribute(inner.clone_with_variables_replaced(variables)),
+            HTMLBodyFilter::RewriteLinks(inner) => HTMLBodyFilter::RewriteLinks(inner.clone()),
+            HTMLBodyFilter::HeadMetadata(inner) => HTMLBodyFilter::HeadMetadata(inner.clone_with_variables_replaced(variables)),
         }
     }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TextBodyFilter {
     pub action: TextAction,
     pub content: String,
+    /// Regex searched by `replace_regex`, `content` being the replacement where `$1` or `${name}` are capture groups
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    /// Maximum bytes held back between two chunks by `replace_regex` to find a match split between them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lookbehind: Option<usize>,
     pub id: Option<String>,
     pub target_hash: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum TextAction {
     #[serde(rename = "append_text")]
     Append,
@@ -144,27 +293,167 @@ pub enum TextAction {
     Prepend,
     #[serde(rename = "replace_text")]
     Replace,
+    #[serde(rename = "replace_regex")]
+    ReplaceRegex,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct HTMLToMarkdownFilter {
     pub action: HTMLToMarkdownAction,
+    #[cfg_attr(feature = "schema", schemars(with = "Option<serde_json::Map<String, serde_json::Value>>"))]
     pub options: Option<ConversionOptions>,
     pub id: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum HTMLToMarkdownAction {
     #[serde(rename = "html_to_markdown")]
     Filter,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct JSONBodyFilter {
+    pub action: JSONAction,
+    pub operations: Vec<JSONOperation>,
+    /// Maximum size of the body buffered to be edited, larger bodies are sent unchanged
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_size: Option<usize>,
+    pub id: Option<String>,
+    pub target_hash: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum JSONAction {
+    #[serde(rename = "edit_json")]
+    Edit,
+}
+
+/// Edit at a location of the document, `path` being a JSON Pointer (`/links/self`) or a
+/// JSONPath (`$.items[*].href`)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum JSONOperation {
+    Set { path: String, value: serde_json::Value },
+    Remove { path: String },
+    Append { path: String, value: serde_json::Value },
+}
+
+impl JSONBodyFilter {
+    pub fn clone_with_variables_replaced(&self, variables: &[(String, VariableValue)]) -> JSONBodyFilter {
+        JSONBodyFilter {
+            action: self.action.clone(),
+            operations: self
+                .operations
+                .iter()
+                .map(|operation| match operation {
+                    JSONOperation::Set { path, value } => JSONOperation::Set {
+                        path: path.clone(),
+                        value: replace_json_variables(value.clone(), variables),
+                    },
+                    JSONOperation::Remove { path } => JSONOperation::Remove { path: path.clone() },
+                    JSONOperation::Append { path, value } => JSONOperation::Append {
+                        path: path.clone(),
+                        value: replace_json_variables(value.clone(), variables),
+                    },
+                })
+                .collect(),
+            max_size: self.max_size,
+            id: self.id.clone(),
+            target_hash: self.target_hash.clone(),
+        }
+    }
+}
+
+fn replace_json_variables(value: serde_json::Value, variables: &[(String, VariableValue)]) -> serde_json::Value {
+    match value {
+        serde_json::Value::String(str) => serde_json::Value::String(StaticOrDynamic::replace(str, variables, true)),
+        serde_json::Value::Array(values) => values.into_iter().map(|value| replace_json_variables(value, variables)).collect(),
+        serde_json::Value::Object(values) => values
+            .into_iter()
+            .map(|(key, value)| (key, replace_json_variables(value, variables)))
+            .collect(),
+        value => value,
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct XMLBodyFilter {
+    pub action: XMLAction,
+    pub edits: Vec<XMLEdit>,
+    pub id: Option<String>,
+    pub target_hash: Option<String>,
+}
+
+impl XMLBodyFilter {
+    pub fn clone_with_variables_replaced(&self, variables: &[(String, VariableValue)]) -> XMLBodyFilter {
+        let pattern_variables = escape_variables(variables, regex::escape);
+        let replacement_variables = escape_variables(variables, |value| value.replace('$', "$$"));
+
+        XMLBodyFilter {
+            action: self.action.clone(),
+            edits: self
+                .edits
+                .iter()
+                .map(|edit| XMLEdit {
+                    path: edit.path.clone(),
+                    pattern: edit
+                        .pattern
+                        .as_ref()
+                        .map(|pattern| StaticOrDynamic::replace(pattern.clone(), &pattern_variables, true)),
+                    // `$` is only a capture group when there is a pattern
+                    replacement: edit.replacement.as_ref().map(|replacement| match edit.pattern {
+                        Some(_) => StaticOrDynamic::replace(replacement.clone(), &replacement_variables, true),
+                        None => StaticOrDynamic::replace(replacement.clone(), variables, true),
+                    }),
+                    redirect: edit.redirect,
+                })
+                .collect(),
+            id: self.id.clone(),
+            target_hash: self.target_hash.clone(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum XMLAction {
+    #[serde(rename = "edit_xml")]
+    Edit,
+}
+
+/// Edit of the text of elements or of an attribute, selected by `path`: `url/loc` selects the
+/// `loc` elements of `url` elements, a leading `/` anchors the path to the root element, `*`
+/// matches any element and a last `@href` step selects the `href` attribute
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct XMLEdit {
+    pub path: String,
+    /// Regex searched in the value, replaced by `replacement` where `$1` or `${name}` are capture groups
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    /// Replacement of the matches of `pattern`, or of the whole value when there is no pattern
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<String>,
+    /// Replace the value, an url, by the target of the redirect matching it in the router
+    #[serde(default)]
+    pub redirect: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum BodyFilter {
     Text(TextBodyFilter),
     HTML(HTMLBodyFilter),
     HTMLToMarkdown(HTMLToMarkdownFilter),
+    JSON(JSONBodyFilter),
+    XML(XMLBodyFilter),
     #[serde(untagged)]
     Other(serde_json::Value),
 }
@@ -0,0 +1,354 @@
+use std::net::IpAddr;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDateTime, NaiveTime, Utc, Weekday};
+use cidr::AnyIpCidr;
+
+use crate::api::{DateTimeConstraint, Example, IpConstraint, Rule, examples::ExampleHeader};
+
+const METHODS: [&str; 7] = ["GET", "POST", "PUT", "PATCH", "DELETE", "HEAD", "OPTIONS"];
+const FALLBACK_IPS: [&str; 3] = ["203.0.113.10", "198.51.100.10", "2001:db8::10"];
+const NO_MATCH_SEGMENT: &str = "redirectionio-no-match";
+
+/// Build examples for a rule from its source, for rule sets imported without any.
+///
+/// Generated examples are only candidates: a constraint this generator cannot satisfy, or
+/// another rule of the router, may prevent the rule from applying. They must be run through
+/// `RunExample` before being kept, see `GenerateExamplesOutput`.
+pub struct ExampleGenerator<'a> {
+    rule: &'a Rule,
+}
+
+impl<'a> ExampleGenerator<'a> {
+    pub fn new(rule: &'a Rule) -> Self {
+        Self { rule }
+    }
+
+    /// Returns an example that should match the rule followed by one that should not, or
+    /// nothing when a marker regex cannot be sampled.
+    pub fn generate(&self) -> Vec<Example> {
+        let Some(samples) = self.marker_samples() else {
+            return Vec::new();
+        };
+
+        let source = &self.rule.source;
+        let path = replace_markers(source.path.as_str(), &samples);
+        let query = source
+            .query
+            .as_deref()
+            .filter(|query| !query.is_empty())
+            .map(|query| replace_markers(query, &samples));
+
+        let example = Example {
+            url: self.url(path.as_str(), query.as_deref(), &samples),
+            method: self.method(),
+            headers: self.headers(&samples),
+            datetime: self.datetime(),
+            ip_address: self.ip_address(),
+            response_status_code: self.response_status_code(),
+            must_match: true,
+            unit_ids_applied: None,
+            response_headers: Vec::new(),
+            response_body: None,
+            sampling_override: source.sampling.map(|_| true),
+        };
+
+        let no_match_path = format!("/{NO_MATCH_SEGMENT}/{}", path.trim_start_matches('/'));
+
+        let mut no_match_example = example.with_url(self.url(no_match_path.as_str(), query.as_deref(), &samples));
+        no_match_example.must_match = false;
+
+        vec![example, no_match_example]
+    }
+
+    fn marker_samples(&self) -> Option<Vec<(String, String)>> {
+        let mut samples = Vec::new();
+
+        for marker in &self.rule.markers {
+            let Some(sample) = marker.sample() else {
+                tracing::debug!("cannot generate a value for marker {} of rule {}", marker.name, self.rule.id);
+
+                return None;
+            };
+
+            samples.push((marker.name.clone(), sample));
+        }
+
+        // Replace longer names first, so @marker_long is not replaced by the value of @marker
+        samples.sort_by_key(|(name, _)| std::cmp::Reverse(name.len()));
+
+        Some(samples)
+    }
+
+    fn url(&self, path: &str, query: Option<&str>, samples: &[(String, String)]) -> String {
+        let source = &self.rule.source;
+        let mut url = String::new();
+
+        if let Some(host) = source.host.as_deref().filter(|host| !host.is_empty()) {
+            let scheme = source.scheme.as_deref().filter(|scheme| !scheme.is_empty()).unwrap_or("https");
+
+            url.push_str(format!("{scheme}://{}", replace_markers(host, samples)).as_str());
+        }
+
+        url.push_str(path);
+
+        if let Some(query) = query {
+            url.push('?');
+            url.push_str(query);
+        }
+
+        url
+    }
+
+    fn method(&self) -> Option<String> {
+        let source = &self.rule.source;
+        let methods = source.methods.as_deref().unwrap_or_default();
+
+        if methods.is_empty() {
+            return Some("GET".to_string());
+        }
+
+        if source.exclude_methods.unwrap_or(false) {
+            return METHODS
+                .iter()
+                .find(|method| !methods.iter().any(|excluded| excluded.eq_ignore_ascii_case(method)))
+                .map(|method| method.to_string());
+        }
+
+        methods.first().cloned()
+    }
+
+    fn headers(&self, samples: &[(String, String)]) -> Vec<ExampleHeader> {
+        let mut headers = Vec::new();
+
+        for header in self.rule.source.headers.as_deref().unwrap_or_default() {
+            // Negative constraints are satisfied when the header is missing
+            let value = match (header.kind.as_str(), header.value.as_deref()) {
+                ("is_defined", _) => "example".to_string(),
+                ("is_equals" | "contains" | "starts_with" | "ends_with", Some(value)) => value.to_string(),
+                ("match_regex", Some(value)) => replace_markers(value, samples),
+                _ => continue,
+            };
+
+            headers.push(ExampleHeader {
+                name: header.name.clone(),
+                value,
+            });
+        }
+
+        headers
+    }
+
+    fn datetime(&self) -> Option<String> {
+        let source = &self.rule.source;
+        let datetime_range = source.datetime.as_deref().and_then(|ranges| ranges.first());
+        let time_range = source.time.as_deref().and_then(|ranges| ranges.first());
+        let weekdays = source
+            .weekdays
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|weekday| weekday.parse::<Weekday>().ok())
+            .collect::<Vec<Weekday>>();
+
+        if datetime_range.is_none() && time_range.is_none() && weekdays.is_empty() {
+            return None;
+        }
+
+        let mut datetime = match datetime_range {
+            Some(DateTimeConstraint(Some(start), _)) => start.parse::<DateTime<Utc>>().ok()?.naive_utc(),
+            Some(DateTimeConstraint(None, Some(end))) => end.parse::<DateTime<Utc>>().ok()?.naive_utc() - Duration::days(1),
+            _ => Utc::now().naive_utc(),
+        };
+
+        if let Some(DateTimeConstraint(start, end)) = time_range {
+            let time = match (start, end) {
+                (Some(start), _) => start.parse::<NaiveTime>().ok()?,
+                (None, Some(end)) => end.parse::<NaiveTime>().ok()? - Duration::minutes(1),
+                (None, None) => datetime.time(),
+            };
+
+            datetime = NaiveDateTime::new(datetime.date(), time);
+        }
+
+        if !weekdays.is_empty() {
+            while !weekdays.contains(&datetime.weekday()) {
+                datetime += Duration::days(1);
+            }
+        }
+
+        Some(datetime.and_utc().to_rfc3339())
+    }
+
+    fn ip_address(&self) -> Option<String> {
+        let constraints = self.rule.source.ips.as_deref().unwrap_or_default();
+
+        if constraints.is_empty() {
+            return None;
+        }
+
+        // Ip constraints are alternatives, satisfying the first one is enough
+        for constraint in constraints {
+            match constraint {
+                IpConstraint::InRange(range) => {
+                    if let Ok(cidr) = range.parse::<AnyIpCidr>()
+                        && let Some(address) = cidr.first_address()
+                    {
+                        return Some(address.to_string());
+                    }
+                }
+                IpConstraint::NotInRange(range) => {
+                    let Ok(cidr) = range.parse::<AnyIpCidr>() else {
+                        continue;
+                    };
+
+                    if let Some(address) = FALLBACK_IPS
+                        .iter()
+                        .find(|ip| ip.parse::<IpAddr>().is_ok_and(|ip| !cidr.contains(&ip)))
+                    {
+                        return Some(address.to_string());
+                    }
+                }
+                IpConstraint::NotOneOf(list) => {
+                    if let Some(address) = FALLBACK_IPS.iter().find(|ip| !list.iter().any(|excluded| excluded == *ip)) {
+                        return Some(address.to_string());
+                    }
+                }
+                IpConstraint::Other(_) => (),
+            }
+        }
+
+        None
+    }
+
+    fn response_status_code(&self) -> Option<u16> {
+        let source = &self.rule.source;
+        let codes = source.response_status_codes.as_ref()?;
+
+        if codes.is_empty() {
+            return None;
+        }
+
+        if source.exclude_response_status_codes.unwrap_or(false) {
+            return [200, 404, 500, 301].into_iter().find(|code| !codes.contains(*code));
+        }
+
+        codes.iter().next().map(|range| range.start)
+    }
+}
+
+fn replace_markers(value: &str, samples: &[(String, String)]) -> String {
+    let mut value = value.to_string();
+
+    for (name, sample) in samples {
+        value = value.replace(format!("@{name}").as_str(), sample.as_str());
+    }
+
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::regex::sample;
+
+    fn rule(json: &str) -> Rule {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn sample_matches_regex() {
+        for regex in [
+            "[0-9]+",
+            "[a-z]{2,4}",
+            "(foo|bar)-[A-Z]",
+            ".*",
+            "[^/]+",
+            r"\\d{4}-\\d{2}",
+            "(?i)abc",
+        ] {
+            let value = sample(regex).unwrap();
+            let compiled = regex::Regex::new(format!("^{regex}$").as_str()).unwrap();
+
+            assert!(compiled.is_match(value.as_str()), "{value} does not match {regex}");
+        }
+    }
+
+    #[test]
+    fn generate_replaces_markers_and_constraints() {
+        let rule = rule(
+            r#"{
+                "id": "rule",
+                "rank": 0,
+                "source": {
+                    "host": "@sub.example.com",
+                    "path": "/product/@id",
+                    "query": "page=@id",
+                    "methods": ["POST"],
+                    "headers": [{"type": "is_equals", "name": "X-Test", "value": "yes"}, {"type": "is_not_defined", "name": "X-Other"}],
+                    "ips": [{"in_range": "10.0.0.0/8"}],
+                    "response_status_codes": [404]
+                },
+                "markers": [{"name": "id", "regex": "[0-9]+"}, {"name": "sub", "regex": "(www|shop)"}],
+                "target": "/"
+            }"#,
+        );
+
+        let examples = ExampleGenerator::new(&rule).generate();
+
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].url, "https://www.example.com/product/0?page=0");
+        assert_eq!(examples[0].method.as_deref(), Some("POST"));
+        assert_eq!(
+            examples[0].headers,
+            vec![ExampleHeader {
+                name: "X-Test".to_string(),
+                value: "yes".to_string()
+            }]
+        );
+        assert_eq!(examples[0].ip_address.as_deref(), Some("10.0.0.0"));
+        assert_eq!(examples[0].response_status_code, Some(404));
+        assert!(examples[0].must_match);
+        assert_eq!(examples[1].url, "https://www.example.com/redirectionio-no-match/product/0?page=0");
+        assert!(!examples[1].must_match);
+    }
+
+    #[test]
+    fn generate_uses_typed_markers() {
+        let rule = rule(
+            r#"{
+                "id": "rule",
+                "rank": 0,
+                "source": {"path": "/@lang/product/@id/@uuid"},
+                "markers": [
+                    {"name": "id", "type": {"integer": {"min": 5, "max": 10}}},
+                    {"name": "lang", "type": {"enum": ["fr", "en"]}},
+                    {"name": "uuid", "type": "uuid"}
+                ]
+            }"#,
+        );
+
+        let examples = ExampleGenerator::new(&rule).generate();
+
+        assert_eq!(examples[0].url, "/fr/product/5/aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa");
+    }
+
+    #[test]
+    fn generate_satisfies_time_constraints() {
+        let rule = rule(
+            r#"{
+                "id": "rule",
+                "rank": 0,
+                "source": {
+                    "path": "/",
+                    "datetime": [["2026-03-02T00:00:00Z", "2026-04-01T00:00:00Z"]],
+                    "time": [["10:00:00", "12:00:00"]],
+                    "weekdays": ["Sat"]
+                }
+            }"#,
+        );
+
+        let examples = ExampleGenerator::new(&rule).generate();
+
+        assert_eq!(examples[0].datetime.as_deref(), Some("2026-03-07T10:00:00+00:00"));
+    }
+}
@@ -13,6 +13,7 @@ use crate::{
 // Input
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ExplainRequestInput {
     pub router_config: RouterConfig,
     pub example: Example,
@@ -23,6 +24,7 @@ pub struct ExplainRequestInput {
 }
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ExplainRequestProjectInput {
     pub example: Example,
     pub change_set: RuleChangeSet,
@@ -34,10 +36,13 @@ pub struct ExplainRequestProjectInput {
 // Output
 
 #[derive(Serialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ExplainRequestOutput {
     example: Example,
     unit_trace: UnitTrace,
     backend_status_code: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    upstream_url: Option<String>,
     response: Response,
     match_traces: Vec<Trace<Rule>>,
     redirection_loop: Option<RedirectionLoop>,
@@ -45,6 +50,7 @@ pub struct ExplainRequestOutput {
 }
 
 #[derive(Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Response {
     pub status_code: u16,
     pub headers: Vec<Header>,
@@ -52,6 +58,7 @@ pub struct Response {
 }
 
 #[derive(Serialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ExplainRequestOutputError {
     pub message: String,
 }
@@ -100,22 +107,25 @@ impl ExplainRequestOutput {
         max_hops: u8,
         project_domains: Vec<String>,
     ) -> Result<ExplainRequestOutput, ExplainRequestOutputError> {
-        let run = RunExample::new(router.as_ref(), example).map_err(|e| ExplainRequestOutputError {
+        let mut run = RunExample::new(router.as_ref(), example).map_err(|e| ExplainRequestOutputError {
             message: format!("invalid example: {e}"),
         })?;
 
+        run.with_match_traces(router.as_ref());
+
         let redirection_loop = Some(RedirectionLoop::from_example(router.as_ref(), max_hops, example, project_domains));
 
         Ok(ExplainRequestOutput {
             example: example.to_owned(),
             unit_trace: run.unit_trace,
             backend_status_code: run.backend_status_code,
+            upstream_url: run.upstream_url,
             response: Response {
                 status_code: run.response.status_code,
                 headers: run.response.headers,
                 body: run.response.body,
             },
-            match_traces: router.trace_request(&run.request),
+            match_traces: run.match_traces,
             redirection_loop,
             should_log_request: run.should_log_request,
         })
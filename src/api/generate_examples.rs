@@ -0,0 +1,101 @@
+use std::{collections::HashMap, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    action::RunExample,
+    api::{Example, Rule, example_generator::ExampleGenerator, rules_message::RuleChangeSet},
+    router::Router,
+    router_config::RouterConfig,
+};
+
+// Input
+
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GenerateExamplesInput {
+    pub router_config: RouterConfig,
+    pub rules: Vec<Rule>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GenerateExamplesProjectInput {
+    pub change_set: RuleChangeSet,
+}
+
+// Output
+
+#[derive(Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GenerateExamplesOutput {
+    pub rules: HashMap<String, RuleOutput>,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RuleOutput {
+    pub examples: Vec<Example>,
+}
+
+// Implementation
+
+impl GenerateExamplesOutput {
+    pub fn create_result_from_project(
+        generate_examples_input: GenerateExamplesProjectInput,
+        existing_router: Arc<Router<Rule>>,
+    ) -> GenerateExamplesOutput {
+        let router = generate_examples_input.change_set.update_existing_router(existing_router);
+
+        Self::create_result(&router)
+    }
+
+    pub fn create_result_without_project(generate_examples_input: GenerateExamplesInput) -> GenerateExamplesOutput {
+        let mut router = Router::<Rule>::from_config(generate_examples_input.router_config.clone());
+
+        for rule in generate_examples_input.rules.iter() {
+            router.insert(rule.clone());
+        }
+
+        router.cache(None);
+
+        Self::create_result(&router)
+    }
+
+    fn create_result(router: &Router<Rule>) -> GenerateExamplesOutput {
+        let mut rules = HashMap::new();
+
+        for (id, route) in router.routes() {
+            let rule = route.handler();
+
+            if rule.examples.as_ref().is_some_and(|examples| !examples.is_empty()) {
+                continue;
+            }
+
+            let mut examples_output = Vec::new();
+
+            for example in ExampleGenerator::new(rule).generate() {
+                let run_example = match RunExample::new(router, &example) {
+                    Ok(run_example) => run_example,
+                    Err(err) => {
+                        tracing::debug!("cannot run generated example {} for rule {}: {}", example.url, id, err);
+                        continue;
+                    }
+                };
+
+                // Only keep examples that behave as expected, another rule may shadow this one
+                if run_example.unit_trace.rule_ids_contains(id) != example.must_match {
+                    continue;
+                }
+
+                let mut final_example = example;
+                final_example.unit_ids_applied = Some(run_example.unit_trace.get_unit_ids_applied().into_iter().collect());
+                examples_output.push(final_example);
+            }
+
+            rules.insert(id.clone(), RuleOutput { examples: examples_output });
+        }
+
+        GenerateExamplesOutput { rules }
+    }
+}
@@ -12,6 +12,7 @@ use crate::{
 // Input
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ImpactInput {
     pub router_config: RouterConfig,
     pub max_hops: u8,
@@ -24,6 +25,7 @@ pub struct ImpactInput {
 }
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ImpactProjectInput {
     pub max_hops: u8,
     pub with_redirection_loop: bool,
@@ -37,11 +39,13 @@ pub struct ImpactProjectInput {
 // Output
 
 #[derive(Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ImpactOutput {
     pub impacts: Vec<Impact>,
 }
 
 #[derive(Serialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Impact {
     example: Example,
     unit_trace: UnitTrace,
@@ -54,6 +58,7 @@ pub struct Impact {
 }
 
 #[derive(Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Response {
     pub status_code: u16,
     pub headers: Vec<Header>,
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use crate::http::{Addr, Header, Request};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Log {
     code: u16,
     to: String,
@@ -13,9 +14,13 @@ pub struct Log {
     duration: Option<u128>,
     match_duration: Option<u128>,
     proxy_duration: Option<u128>,
+    // Number of requests this log stands for when logs are sampled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    weight: Option<f64>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 struct FromLog {
     #[serde(rename = "ruleIds")]
     rule_ids: Option<Vec<String>>,
@@ -31,6 +36,7 @@ struct FromLog {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct LegacyLog {
     status_code: u16,
     host: Option<String>,
@@ -67,9 +73,16 @@ impl Log {
             duration: None,
             match_duration: None,
             proxy_duration: None,
+            weight: None,
         }
     }
 
+    /// Record the sampling weight decided by `Action::should_log_request`
+    pub fn with_weight(mut self, weight: Option<f64>) -> Self {
+        self.weight = weight;
+        self
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn from_proxy(
         request: &Request,
@@ -162,6 +175,7 @@ impl Log {
             duration,
             match_duration,
             proxy_duration,
+            weight: None,
         }
     }
 }
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+/// Log override of a rule: logs enabled or disabled, or enabled for a sample of the requests
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum LogOverrideValue {
+    Enabled(bool),
+    Sampled(LogSampling),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LogSampling {
+    /// Share of the requests logged, between 0 and 1
+    #[serde(default = "default_rate")]
+    pub rate: f64,
+    /// Value hashed to decide whether a request is logged, markers are replaced, the client ip
+    /// and url are used when empty
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// Maximum number of requests logged for the rule per interval
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    /// Interval of the limit, in seconds
+    #[serde(default = "default_interval")]
+    pub interval: u64,
+}
+
+impl LogOverrideValue {
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            LogOverrideValue::Enabled(enabled) => *enabled,
+            LogOverrideValue::Sampled(_) => true,
+        }
+    }
+
+    pub fn sampling(&self) -> Option<&LogSampling> {
+        match self {
+            LogOverrideValue::Enabled(_) => None,
+            LogOverrideValue::Sampled(sampling) => Some(sampling),
+        }
+    }
+}
+
+impl From<bool> for LogOverrideValue {
+    fn from(enabled: bool) -> Self {
+        LogOverrideValue::Enabled(enabled)
+    }
+}
+
+fn default_rate() -> f64 {
+    1.0
+}
+
+fn default_interval() -> u64 {
+    60
+}
@@ -1,15 +1,105 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{api::Transformer, marker::Transform};
+use crate::{
+    api::Transformer,
+    marker::{MarkerValidator, Transform},
+    regex::sample,
+};
+
+const UUID_REGEX: &str = "[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}";
+const DATE_REGEX: &str = "[0-9]{4}-(?:0[1-9]|1[0-2])-(?:0[1-9]|[12][0-9]|3[01])";
+const SLUG_REGEX: &str = "[a-z0-9]+(?:-[a-z0-9]+)*";
+const ANY_SEGMENT_REGEX: &str = "[^/]+";
+const ANY_PATH_REGEX: &str = ".*";
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Marker {
     pub name: String,
+    /// Ignored when `kind` is set
+    #[serde(default)]
     pub regex: String,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<MarkerKind>,
     #[serde(default)]
     pub transformers: Vec<Transformer>,
 }
 
+/// Built-in marker patterns, to avoid writing a regex for common values
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum MarkerKind {
+    Integer {
+        #[serde(default)]
+        min: Option<i64>,
+        #[serde(default)]
+        max: Option<i64>,
+    },
+    Uuid,
+    Date,
+    Slug,
+    Enum(Vec<String>),
+    AnySegment,
+    AnyPath,
+}
+
+impl Marker {
+    pub fn regex(&self) -> String {
+        match &self.kind {
+            None => self.regex.clone(),
+            Some(kind) => kind.regex(),
+        }
+    }
+
+    pub fn validator(&self) -> Option<MarkerValidator> {
+        self.kind.as_ref().and_then(MarkerKind::validator)
+    }
+
+    /// A value this marker would capture, used to build examples for rules that have none.
+    pub fn sample(&self) -> Option<String> {
+        match &self.kind {
+            Some(MarkerKind::Integer { min, max }) => {
+                let value = match (min, max) {
+                    (Some(min), _) => *min,
+                    (None, Some(max)) => (*max).min(0),
+                    (None, None) => 0,
+                };
+
+                Some(value.to_string())
+            }
+            Some(MarkerKind::Date) => Some("2024-01-01".to_string()),
+            Some(MarkerKind::Enum(values)) => values.first().cloned(),
+            _ => sample(self.regex().as_str()),
+        }
+    }
+}
+
+impl MarkerKind {
+    pub fn regex(&self) -> String {
+        match self {
+            MarkerKind::Integer { min, .. } if min.is_some_and(|min| min >= 0) => "[0-9]+".to_string(),
+            MarkerKind::Integer { .. } => "-?[0-9]+".to_string(),
+            MarkerKind::Uuid => UUID_REGEX.to_string(),
+            MarkerKind::Date => DATE_REGEX.to_string(),
+            MarkerKind::Slug => SLUG_REGEX.to_string(),
+            MarkerKind::Enum(values) => values.iter().map(|value| regex::escape(value)).collect::<Vec<_>>().join("|"),
+            MarkerKind::AnySegment => ANY_SEGMENT_REGEX.to_string(),
+            MarkerKind::AnyPath => ANY_PATH_REGEX.to_string(),
+        }
+    }
+
+    pub fn validator(&self) -> Option<MarkerValidator> {
+        match self {
+            MarkerKind::Integer { min: None, max: None } => None,
+            MarkerKind::Integer { min, max } => Some(MarkerValidator::IntegerRange { min: *min, max: *max }),
+            // The regex accepts impossible days like 2023-02-30
+            MarkerKind::Date => Some(MarkerValidator::Date),
+            _ => None,
+        }
+    }
+}
+
 impl Transform for Marker {
     fn transform(&self, mut value: String) -> String {
         for transformer in &self.transformers {
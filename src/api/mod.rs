@@ -1,25 +1,37 @@
 mod body_filter;
 mod date_time;
+#[cfg(feature = "router")]
+mod example_generator;
 mod examples;
 #[cfg(feature = "router")]
 mod explain_request;
 #[cfg(not(target_arch = "wasm32"))]
 mod ffi;
+#[cfg(feature = "router")]
+mod generate_examples;
 mod header;
 mod header_filter;
 #[cfg(feature = "router")]
 mod impact;
 mod ip;
 mod log;
+mod log_sampling;
 mod marker;
 mod peer;
+mod peer_fallback;
+mod peer_pool;
 #[cfg(feature = "router")]
 mod redirection_loop;
+mod response_compression;
 #[cfg(feature = "router")]
 mod rule;
 #[cfg(feature = "router")]
 mod rules_message;
 mod source;
+mod status_code;
+#[cfg(feature = "router")]
+mod suggestion;
+mod synthetic_response;
 #[cfg(feature = "router")]
 mod test_examples;
 mod transformer;
@@ -27,30 +39,47 @@ mod transformer;
 mod unit_ids;
 mod variable;
 
-pub use body_filter::{BodyFilter, HTMLBodyFilter, HTMLBodyFilterInnerLegacy, TextAction, TextBodyFilter};
+pub use body_filter::{
+    BodyFilter, HTMLBodyFilter, HTMLBodyFilterAttribute, HTMLBodyFilterHeadMetadata, HTMLBodyFilterInnerLegacy, HTMLBodyFilterRewriteLinks,
+    HTMLHeadHreflang, HTMLHeadOpenGraph, JSONAction, JSONBodyFilter, JSONOperation, TextAction, TextBodyFilter, XMLAction, XMLBodyFilter,
+    XMLEdit,
+};
 pub use date_time::DateTimeConstraint;
+#[cfg(feature = "router")]
+pub use example_generator::ExampleGenerator;
 pub use examples::Example;
 #[cfg(feature = "router")]
 pub use explain_request::{ExplainRequestInput, ExplainRequestOutput, ExplainRequestOutputError, ExplainRequestProjectInput};
+#[cfg(feature = "router")]
+pub use generate_examples::{GenerateExamplesInput, GenerateExamplesOutput, GenerateExamplesProjectInput};
 pub use header::Header;
 pub use header_filter::HeaderFilter;
 #[cfg(feature = "router")]
 pub use impact::{ImpactInput, ImpactOutput, ImpactProjectInput};
 pub use ip::IpConstraint;
-pub use marker::Marker;
+pub use log_sampling::{LogOverrideValue, LogSampling};
+pub use marker::{Marker, MarkerKind};
 pub use peer::Peer;
+pub use peer_fallback::PeerFallback;
+pub use peer_pool::{PeerPool, WeightedPeer};
 #[cfg(feature = "router")]
 pub use redirection_loop::RedirectionLoop;
+pub use response_compression::ResponseCompression;
 #[cfg(feature = "router")]
 pub use rule::Rule;
 #[cfg(feature = "router")]
 pub use rules_message::{RuleChangeSet, RulesMessage};
 pub use source::Source;
+pub use status_code::{StatusCodeRange, StatusCodes};
+#[cfg(feature = "router")]
+pub use suggestion::{RuleSuggestion, RuleSuggestionKind, SuggestRulesInput, SuggestRulesOutput, SuggestRulesProjectInput};
+pub use synthetic_response::SyntheticResponse;
 #[cfg(feature = "router")]
 pub use test_examples::{TestExamplesInput, TestExamplesOutput, TestExamplesProjectInput};
 pub use transformer::Transformer;
 #[cfg(feature = "router")]
 pub use unit_ids::{UnitIdsInput, UnitIdsOutput, UnitIdsProjectInput};
+pub(crate) use variable::escape_variables;
 pub use variable::{Variable, VariableKind, VariableValue};
 
 pub use self::log::{LegacyLog, Log};
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::Peer;
+
+/// Peers tried in order when the backend response matches the response status codes of the
+/// rule, or is a server error when the rule has none
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PeerFallback {
+    pub peers: Vec<Peer>,
+    /// Methods which can be sent again to another peer, only idempotent methods by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub methods: Option<Vec<String>>,
+    pub id: Option<String>,
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::Peer;
+
+/// Weighted peers a peer override is chosen from
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PeerPool {
+    pub peers: Vec<WeightedPeer>,
+    /// Value requests stick to a peer on, markers are replaced, the client ip is used when empty
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sticky_key: Option<String>,
+    /// Seconds a peer is skipped after a failure was reported
+    #[serde(default = "default_failure_cooldown")]
+    pub failure_cooldown: u64,
+    pub id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct WeightedPeer {
+    pub peer: Peer,
+    #[serde(default = "default_weight")]
+    pub weight: u32,
+}
+
+fn default_failure_cooldown() -> u64 {
+    30
+}
+
+fn default_weight() -> u32 {
+    1
+}
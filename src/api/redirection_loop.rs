@@ -7,12 +7,14 @@ use crate::{action::Action, http::Request, router::Router};
 const REDIRECTION_CODES: [u16; 4] = [301, 302, 307, 308];
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RedirectionLoop {
     hops: Vec<RedirectionHop>,
     error: Option<RedirectionError>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RedirectionHop {
     pub url: String,
     pub status_code: u16,
@@ -20,6 +22,7 @@ pub struct RedirectionHop {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 enum RedirectionError {
     AtLeastOneHop,
     TooManyHops,
@@ -65,8 +68,7 @@ impl RedirectionLoop {
                 }
             };
 
-            let routes = router.match_request(&request);
-            let mut action = Action::from_routes_rule(routes, &request, None);
+            let (mut action, _) = Action::from_router(router, &request, None);
 
             let action_status_code = action.get_status_code(0, None);
             let (final_status_code, backend_status_code) = if action_status_code != 0 {
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// Compress the response with brotli or gzip, as preferred by the `Accept-Encoding` of the
+/// request, when the backend sends it uncompressed
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ResponseCompression {
+    /// Responses with a smaller `Content-Length` are not compressed, 1024 bytes by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_size: Option<u64>,
+    pub id: Option<String>,
+}
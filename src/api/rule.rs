@@ -6,7 +6,11 @@ use serde::{Deserialize, Serialize};
 use serde_json::from_str as json_decode;
 
 use crate::{
-    api::{BodyFilter, DateTimeConstraint, Example, HeaderFilter, IpConstraint, Marker, Peer, Source, Variable, variable::VariableValue},
+    action::ResponseHeaderCondition,
+    api::{
+        BodyFilter, DateTimeConstraint, Example, HeaderFilter, IpConstraint, LogOverrideValue, Marker, Peer, PeerFallback, PeerPool,
+        ResponseCompression, Source, SyntheticResponse, Variable, variable::VariableValue,
+    },
     http::Request,
     marker::{Marker as RouteMarker, MarkerString, StaticOrDynamic, Transform},
     router::{IntoRoute, Route, RouteDateTime, RouteHeader, RouteHeaderKind, RouteIp, RouteTime, RouteWeekday},
@@ -18,6 +22,7 @@ const URL_ENCODE_SET: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'#').add(b'
 const QUERY_ENCODE_SET: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'#').add(b'<').add(b'>').add(b'+');
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Rule {
     pub id: String,
     pub source: Source,
@@ -31,8 +36,26 @@ pub struct Rule {
     pub variables: Vec<Variable>,
     pub body_filters: Option<Vec<BodyFilter>>,
     pub header_filters: Option<Vec<HeaderFilter>>,
-    pub log_override: Option<bool>,
+    /// Filters applied on the request headers, before the request is sent to the upstream
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub request_header_filters: Option<Vec<HeaderFilter>>,
+    pub log_override: Option<LogOverrideValue>,
     pub peer_override: Option<Peer>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub peer_fallback: Option<PeerFallback>,
+    /// Peers the peer override is chosen from, used instead of `peer_override`
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub peer_pool: Option<PeerPool>,
+    /// Path and query sent to the upstream instead of the requested ones, markers are replaced
+    /// by their captured values, the client url is kept
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub rewrite: Option<String>,
+    /// Response served without calling the backend, its header values and body may contain markers
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub synthetic_response: Option<SyntheticResponse>,
+    /// Compress the response when the client accepts it and the backend sent it uncompressed
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub compress_response: Option<ResponseCompression>,
     pub reset: Option<bool>,
     pub stop: Option<bool>,
     pub examples: Option<Vec<Example>>,
@@ -40,6 +63,8 @@ pub struct Rule {
     pub configuration_log_unit_id: Option<String>,
     pub configuration_reset_unit_id: Option<String>,
     pub peer_unit_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub rewrite_unit_id: Option<String>,
     pub target_hash: Option<String>,
 }
 
@@ -121,9 +146,9 @@ impl Rule {
         let mut markers = Vec::new();
 
         for marker in &self.markers {
-            let regex = utf8_percent_encode(marker.regex.as_str(), SIMPLE_ENCODE_SET).to_string();
+            let regex = utf8_percent_encode(marker.regex().as_str(), SIMPLE_ENCODE_SET).to_string();
 
-            markers.push(RouteMarker::new(marker.name.clone(), regex));
+            markers.push(RouteMarker::new(marker.name.clone(), regex).with_validator(marker.validator()));
         }
 
         markers
@@ -241,6 +266,17 @@ impl Rule {
         ))
     }
 
+    /// Conditions of the rule on the response headers, the markers of the rule apply to them
+    /// like to the request headers
+    pub(crate) fn response_header_conditions(&self) -> Vec<ResponseHeaderCondition> {
+        self.source
+            .response_headers
+            .iter()
+            .flatten()
+            .filter_map(|header| ResponseHeaderCondition::new(header.clone(), self.markers()))
+            .collect()
+    }
+
     fn headers(&self, ignore_case: bool) -> Vec<RouteHeader> {
         let mut headers = Vec::new();
 
@@ -297,17 +333,33 @@ impl Rule {
 }
 
 impl IntoRoute<Rule> for Rule {
-    fn into_route(self, config: &RouterConfig) -> Route<Rule> {
+    fn into_route(mut self, config: &RouterConfig) -> Route<Rule> {
+        if let Some(rewrite) = self.rewrite.as_ref()
+            && !rewrite.is_empty()
+            && !rewrite.starts_with('/')
+        {
+            tracing::error!("rule {} rewrite {rewrite} is ignored, it must start with /", self.id);
+
+            self.rewrite = None;
+        }
+
+        let ignore_path_and_query_case = self.source.ignore_path_and_query_case.unwrap_or(config.ignore_path_and_query_case);
+        let target_path = self.target.as_deref().and_then(|target| match target.starts_with('/') {
+            true => Some(target.to_string()),
+            false => url::Url::parse(target).ok().map(|url| url.path().to_string()),
+        });
+
         Route::new(
             self.source.methods.clone(),
             self.source.exclude_methods,
             self.source.scheme.clone(),
             self.host(config.ignore_host_case),
             self.path_and_query(
-                config.ignore_path_and_query_case,
+                ignore_path_and_query_case,
                 config.ignore_all_query_parameters,
                 config.ignore_query_param_order,
             ),
+            ignore_path_and_query_case,
             self.headers(config.ignore_header_case),
             self.route_ips(),
             self.route_datetimes(),
@@ -317,5 +369,6 @@ impl IntoRoute<Rule> for Rule {
             0 - self.rank as i64,
             self,
         )
+        .with_target_path(target_path)
     }
 }
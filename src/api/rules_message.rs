@@ -5,12 +5,14 @@ use serde::{Deserialize, Serialize};
 use crate::{api::Rule, router::Router};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RulesMessage {
     #[serde(rename = "hydra:member")]
     pub rules: Vec<Rule>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RuleChangeSet {
     pub added: Vec<Rule>,
     pub updated: Vec<Rule>,
@@ -1,8 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-use crate::api::{DateTimeConstraint, Header, IpConstraint};
+use crate::api::{DateTimeConstraint, Header, IpConstraint, StatusCodes};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Source {
     pub scheme: Option<String>,
     pub host: Option<String>,
@@ -13,10 +14,19 @@ pub struct Source {
     pub time: Option<Vec<DateTimeConstraint>>,
     pub path: String,
     pub query: Option<String>,
+    /// Overrides `RouterConfig::ignore_path_and_query_case` for this rule
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub ignore_path_and_query_case: Option<bool>,
     pub headers: Option<Vec<Header>>,
+    /// Conditions on the backend response headers, gating the actions of the rule
+    ///
+    /// A status code update conditioned by response headers is only applied when
+    /// `Action::filter_headers` is called before `Action::get_status_code` for the backend response
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub response_headers: Option<Vec<Header>>,
     pub methods: Option<Vec<String>>,
     pub exclude_methods: Option<bool>,
-    pub response_status_codes: Option<Vec<u16>>,
+    pub response_status_codes: Option<StatusCodes>,
     pub exclude_response_status_codes: Option<bool>,
     pub sampling: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
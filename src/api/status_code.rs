@@ -0,0 +1,184 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Response status codes an action is restricted to.
+///
+/// Single codes are serialized as numbers, like before ranges were supported, so actions
+/// without ranges stay readable by older proxy modules. Ranges are serialized as `5xx` for a
+/// whole class or `500-504` otherwise.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct StatusCodes(Vec<StatusCodeRange>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatusCodeRange {
+    pub start: u16,
+    pub end: u16,
+}
+
+impl StatusCodes {
+    pub fn new(ranges: Vec<StatusCodeRange>) -> Self {
+        Self(ranges)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &StatusCodeRange> {
+        self.0.iter()
+    }
+
+    pub fn contains(&self, status_code: u16) -> bool {
+        self.0.iter().any(|range| range.contains(status_code))
+    }
+
+    /// Whether an action restricted to these codes applies for the response status code:
+    /// the code is listed, or it is not when the list is an exclusion list.
+    ///
+    /// Callers decide what an empty list means, as it differs between actions.
+    pub fn matches(&self, exclude: bool, status_code: u16) -> bool {
+        self.contains(status_code) != exclude
+    }
+}
+
+impl From<Vec<u16>> for StatusCodes {
+    fn from(codes: Vec<u16>) -> Self {
+        Self(codes.into_iter().map(StatusCodeRange::from).collect())
+    }
+}
+
+impl StatusCodeRange {
+    pub fn contains(&self, status_code: u16) -> bool {
+        self.start <= status_code && status_code <= self.end
+    }
+}
+
+impl From<u16> for StatusCodeRange {
+    fn from(status_code: u16) -> Self {
+        Self {
+            start: status_code,
+            end: status_code,
+        }
+    }
+}
+
+impl Display for StatusCodeRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.start == self.end {
+            write!(f, "{}", self.start)
+        } else if self.start.is_multiple_of(100) && self.end == self.start + 99 {
+            write!(f, "{}xx", self.start / 100)
+        } else {
+            write!(f, "{}-{}", self.start, self.end)
+        }
+    }
+}
+
+impl FromStr for StatusCodeRange {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        let invalid = || format!("invalid status code range {value}");
+
+        if let Some(class) = value.strip_suffix("xx").or_else(|| value.strip_suffix("XX")) {
+            let class = class.parse::<u16>().map_err(|_| invalid())?;
+
+            if !(1..=9).contains(&class) {
+                return Err(invalid());
+            }
+
+            return Ok(Self {
+                start: class * 100,
+                end: class * 100 + 99,
+            });
+        }
+
+        let (start, end) = match value.split_once('-') {
+            Some((start, end)) => (start.trim(), end.trim()),
+            None => (value, value),
+        };
+
+        let start = start.parse::<u16>().map_err(|_| invalid())?;
+        let end = end.parse::<u16>().map_err(|_| invalid())?;
+
+        if start > end {
+            return Err(invalid());
+        }
+
+        Ok(Self { start, end })
+    }
+}
+
+impl Serialize for StatusCodeRange {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.start == self.end {
+            serializer.serialize_u16(self.start)
+        } else {
+            serializer.serialize_str(self.to_string().as_str())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for StatusCodeRange {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum RawStatusCodeRange {
+            Code(u16),
+            Range(String),
+        }
+
+        match RawStatusCodeRange::deserialize(deserializer)? {
+            RawStatusCodeRange::Code(status_code) => Ok(status_code.into()),
+            RawStatusCodeRange::Range(range) => range.parse().map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+// A single code is a number, a range is a string like `5xx` or `500-504`
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for StatusCodeRange {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "StatusCodeRange".into()
+    }
+
+    fn json_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "anyOf": [
+                { "type": "integer", "minimum": 0, "maximum": 65535 },
+                { "type": "string", "pattern": "^\\s*([1-9](xx|XX)|\\d+(\\s*-\\s*\\d+)?)\\s*$" }
+            ]
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_codes_round_trip() {
+        let json = r#"[404,"5xx","500-504"]"#;
+        let codes: StatusCodes = serde_json::from_str(json).unwrap();
+
+        assert!(codes.contains(404));
+        assert!(codes.contains(599));
+        assert!(!codes.contains(405));
+        assert_eq!(serde_json::to_string(&codes).unwrap(), json);
+    }
+
+    #[test]
+    fn status_codes_matches_with_exclusion() {
+        let codes: StatusCodes = serde_json::from_str(r#"["4xx"]"#).unwrap();
+
+        assert!(codes.matches(false, 410));
+        assert!(!codes.matches(true, 410));
+        assert!(codes.matches(true, 200));
+        assert!(serde_json::from_str::<StatusCodes>(r#"["504-500"]"#).is_err());
+        assert!(serde_json::from_str::<StatusCodes>(r#"["0xx"]"#).is_err());
+    }
+}
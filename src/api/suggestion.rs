@@ -0,0 +1,346 @@
+use std::{cmp::Ordering, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    api::{Example, Rule, rules_message::RuleChangeSet},
+    http::Request,
+    router::Router,
+    router_config::RouterConfig,
+};
+
+/// Cost of replacing a request segment by a segment containing a marker, which may or may
+/// not capture it: cheaper than a different static segment, but never free.
+const MARKER_SEGMENT_COST: f64 = 0.25;
+
+// Input
+
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SuggestRulesInput {
+    pub router_config: RouterConfig,
+    pub rules: Vec<Rule>,
+    pub url: String,
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SuggestRulesProjectInput {
+    pub change_set: RuleChangeSet,
+    pub url: String,
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+}
+
+// Output
+
+#[derive(Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SuggestRulesOutput {
+    pub suggestions: Vec<RuleSuggestion>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RuleSuggestion {
+    pub rule_id: String,
+    pub kind: RuleSuggestionKind,
+    /// Source path or target of the rule, as written in the rule
+    pub url: String,
+    /// Path similarity between 0 and 1, 1 meaning all segments are equal
+    pub score: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum RuleSuggestionKind {
+    Source,
+    Target,
+}
+
+// Implementation
+
+fn default_limit() -> usize {
+    5
+}
+
+impl SuggestRulesOutput {
+    pub fn create_result_from_project(
+        suggest_rules_input: SuggestRulesProjectInput,
+        existing_router: Arc<Router<Rule>>,
+    ) -> SuggestRulesOutput {
+        let router = if suggest_rules_input.change_set.is_empty() {
+            existing_router
+        } else {
+            Arc::new(suggest_rules_input.change_set.update_existing_router(existing_router))
+        };
+
+        Self::create_result(&router, suggest_rules_input.url, suggest_rules_input.limit)
+    }
+
+    pub fn create_result_without_project(suggest_rules_input: SuggestRulesInput) -> SuggestRulesOutput {
+        let mut router = Router::<Rule>::from_config(suggest_rules_input.router_config.clone());
+
+        for rule in suggest_rules_input.rules.iter() {
+            router.insert(rule.clone());
+        }
+
+        Self::create_result(&router, suggest_rules_input.url, suggest_rules_input.limit)
+    }
+
+    fn create_result(router: &Router<Rule>, url: String, limit: usize) -> SuggestRulesOutput {
+        let example = Example {
+            url,
+            method: None,
+            headers: Vec::new(),
+            datetime: None,
+            ip_address: None,
+            response_status_code: None,
+            must_match: false,
+            unit_ids_applied: None,
+            response_headers: Vec::new(),
+            response_body: None,
+            sampling_override: None,
+        };
+
+        let request = match Request::from_example(&router.config, &example) {
+            Ok(request) => request,
+            Err(err) => {
+                tracing::debug!("cannot create request for url {}: {}", example.url, err);
+
+                return SuggestRulesOutput::default();
+            }
+        };
+
+        SuggestRulesOutput {
+            suggestions: RuleSuggestion::find(router, &request, limit),
+        }
+    }
+}
+
+impl RuleSuggestion {
+    /// Rules whose source path or target is the closest to the request path, best score first.
+    ///
+    /// Only rules sharing the first segment of the request path, or whose source starts with a
+    /// marker, are scored: it is meant for requests that did not match any rule, like a 404
+    /// page, not for every request.
+    pub fn find(router: &Router<Rule>, request: &Request, limit: usize) -> Vec<RuleSuggestion> {
+        let ignore_case = router.config.ignore_path_and_query_case;
+        let request_path = normalize_path(request.path_and_query().as_str(), ignore_case);
+        let request_segments = segments(request_path.as_str());
+        let mut suggestions = Vec::new();
+
+        for route in router.routes_sharing_path_prefix(request_path.as_str()) {
+            let rule = route.handler();
+            let id = route.id();
+
+            if let Some(host) = rule.source.host.as_deref().filter(|host| !host.is_empty())
+                && let Some(request_host) = request.host()
+                && !host.contains('@')
+                && !host.eq_ignore_ascii_case(request_host)
+            {
+                continue;
+            }
+
+            let source_path = normalize_path(rule.source.path.as_str(), ignore_case);
+
+            suggestions.push(RuleSuggestion {
+                rule_id: id.to_string(),
+                kind: RuleSuggestionKind::Source,
+                url: rule.source.path.clone(),
+                score: score(&request_segments, &segments(source_path.as_str())),
+            });
+
+            if let Some(target) = rule.target.as_deref()
+                && let Some(target_path) = target_path(target, request.host())
+            {
+                let target_path = normalize_path(target_path.as_str(), ignore_case);
+
+                suggestions.push(RuleSuggestion {
+                    rule_id: id.to_string(),
+                    kind: RuleSuggestionKind::Target,
+                    url: target.to_string(),
+                    score: score(&request_segments, &segments(target_path.as_str())),
+                });
+            }
+        }
+
+        suggestions.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| a.rule_id.cmp(&b.rule_id))
+        });
+        suggestions.truncate(limit);
+
+        suggestions
+    }
+
+    /// Best target usable as a redirect: its score reaches the threshold and it has no marker
+    /// to fill. Sources are never suggested, as they are urls other rules redirect or remove.
+    pub fn did_you_mean(router: &Router<Rule>, request: &Request, threshold: f64) -> Option<RuleSuggestion> {
+        Self::find(router, request, usize::MAX)
+            .into_iter()
+            .take_while(|suggestion| suggestion.score >= threshold)
+            .find(|suggestion| suggestion.kind == RuleSuggestionKind::Target && !suggestion.url.contains('@'))
+    }
+}
+
+fn normalize_path(path: &str, ignore_case: bool) -> String {
+    let path = path.split_once('?').map(|(path, _)| path).unwrap_or(path);
+
+    if ignore_case { path.to_lowercase() } else { path.to_string() }
+}
+
+fn target_path(target: &str, request_host: Option<&str>) -> Option<String> {
+    if target.starts_with('/') {
+        return Some(target.to_string());
+    }
+
+    let url = Url::parse(target).ok()?;
+
+    match (url.host_str(), request_host) {
+        (Some(host), Some(request_host)) if !host.eq_ignore_ascii_case(request_host) => None,
+        _ => Some(url.path().to_string()),
+    }
+}
+
+fn segments(path: &str) -> Vec<&str> {
+    path.split('/').filter(|segment| !segment.is_empty()).collect()
+}
+
+/// Edit distance on path segments, where replacing a segment costs its normalized
+/// character edit distance, scaled to a similarity between 0 and 1.
+fn score(request: &[&str], candidate: &[&str]) -> f64 {
+    let length = request.len().max(candidate.len());
+
+    if length == 0 {
+        return 1.0;
+    }
+
+    let mut previous = (0..=candidate.len()).map(|i| i as f64).collect::<Vec<f64>>();
+    let mut current = vec![0.0; candidate.len() + 1];
+
+    for (i, request_segment) in request.iter().enumerate() {
+        current[0] = (i + 1) as f64;
+
+        for (j, candidate_segment) in candidate.iter().enumerate() {
+            let replace = previous[j] + segment_distance(request_segment, candidate_segment);
+
+            current[j + 1] = replace.min(previous[j + 1] + 1.0).min(current[j] + 1.0);
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    1.0 - previous[candidate.len()] / length as f64
+}
+
+fn segment_distance(request: &str, candidate: &str) -> f64 {
+    if request == candidate {
+        return 0.0;
+    }
+
+    if candidate.contains('@') {
+        return MARKER_SEGMENT_COST;
+    }
+
+    let request = request.chars().collect::<Vec<char>>();
+    let candidate = candidate.chars().collect::<Vec<char>>();
+    let mut previous = (0..=candidate.len()).collect::<Vec<usize>>();
+    let mut current = vec![0; candidate.len() + 1];
+
+    for (i, request_char) in request.iter().enumerate() {
+        current[0] = i + 1;
+
+        for (j, candidate_char) in candidate.iter().enumerate() {
+            let replace = previous[j] + usize::from(request_char != candidate_char);
+
+            current[j + 1] = replace.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[candidate.len()] as f64 / request.len().max(candidate.len()) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn router(rules: &str) -> Router<Rule> {
+        let mut router = Router::<Rule>::default();
+
+        for rule in serde_json::from_str::<Vec<Rule>>(rules).unwrap() {
+            router.insert(rule);
+        }
+
+        router
+    }
+
+    fn request(router: &Router<Rule>, path: &str) -> Request {
+        Request::from_config(&router.config, path.to_string(), None, None, None, None, None)
+    }
+
+    #[test]
+    fn find_scores_closest_paths_first() {
+        let router = router(
+            r#"[
+                {"id": "a", "rank": 0, "source": {"path": "/blog/my-first-post"}, "target": "/articles/my-first-post", "status_code": 301},
+                {"id": "b", "rank": 0, "source": {"path": "/shop/@product/reviews"}, "status_code": 410},
+                {"id": "c", "rank": 0, "source": {"path": "/contact"}, "status_code": 410}
+            ]"#,
+        );
+
+        let suggestions = RuleSuggestion::find(&router, &request(&router, "/blog/my-frist-post"), 2);
+
+        assert_eq!(suggestions.len(), 2);
+        assert_eq!(suggestions[0].rule_id, "a");
+        assert_eq!(suggestions[0].kind, RuleSuggestionKind::Source);
+        assert!(suggestions[0].score > 0.8 && suggestions[0].score < 1.0);
+        assert_eq!(suggestions[1].kind, RuleSuggestionKind::Target);
+        assert!(suggestions[1].score < suggestions[0].score);
+    }
+
+    #[test]
+    fn find_skips_rules_without_shared_prefix() {
+        let router = router(
+            r#"[
+                {"id": "a", "rank": 0, "source": {"path": "/blog/my-first-post"}, "status_code": 410},
+                {"id": "b", "rank": 0, "source": {"path": "/old/page"}, "target": "https://example.org/blog/my-post", "status_code": 301},
+                {"id": "c", "rank": 0, "source": {"path": "/@lang/my-first-post"}, "markers": [{"name": "lang", "regex": "[a-z]{2}"}], "status_code": 410},
+                {"id": "d", "rank": 0, "source": {"path": "/news/my-first-post"}, "status_code": 410}
+            ]"#,
+        );
+
+        let mut rule_ids = RuleSuggestion::find(&router, &request(&router, "/blog/my-frist-post"), 10)
+            .into_iter()
+            .map(|suggestion| suggestion.rule_id)
+            .collect::<Vec<String>>();
+        rule_ids.sort();
+        rule_ids.dedup();
+
+        assert_eq!(rule_ids, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn did_you_mean_suggests_targets_over_threshold() {
+        let router = router(
+            r#"[
+                {"id": "a", "rank": 0, "source": {"path": "/shop/shoes"}, "status_code": 410},
+                {"id": "b", "rank": 0, "source": {"path": "/old-shoes"}, "target": "/shop/shoes-2024", "status_code": 301}
+            ]"#,
+        );
+
+        let suggestion = RuleSuggestion::did_you_mean(&router, &request(&router, "/shop/shoes-2042"), 0.8);
+
+        assert_eq!(suggestion.map(|suggestion| suggestion.url), Some("/shop/shoes-2024".to_string()));
+        assert!(RuleSuggestion::did_you_mean(&router, &request(&router, "/shop/shoe"), 0.9).is_none());
+        assert!(RuleSuggestion::did_you_mean(&router, &request(&router, "/about"), 0.8).is_none());
+    }
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use crate::http::Header;
+
+/// Complete response served by the proxy instead of calling the backend
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SyntheticResponse {
+    pub status_code: u16,
+    #[serde(default)]
+    pub headers: Vec<Header>,
+    #[serde(default)]
+    pub body: String,
+    pub id: Option<String>,
+}
@@ -14,6 +14,7 @@ use crate::{
 // Input
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TestExamplesInput {
     pub router_config: RouterConfig,
     pub rules: Vec<Rule>,
@@ -23,6 +24,7 @@ pub struct TestExamplesInput {
 }
 
 #[derive(Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TestExamplesProjectInput {
     pub change_set: RuleChangeSet,
     pub max_hops: u8,
@@ -31,6 +33,7 @@ pub struct TestExamplesProjectInput {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TestExamplesOutput {
     pub example_count: u32,
     pub failure_count: u32,
@@ -40,27 +43,34 @@ pub struct TestExamplesOutput {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct FailedRule {
     pub rule: Rule,
     pub failed_examples: Vec<FailedExample>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct FailedExample {
     example: Example,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<String>"))]
     rule_ids_applied: LinkedHashSet<String>,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<String>"))]
     unit_ids_applied: LinkedHashSet<String>,
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<String>"))]
     unit_ids_not_applied_anymore: LinkedHashSet<String>,
     redirection_loop: Option<RedirectionLoop>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ErroredRule {
     pub rule: Rule,
     pub errored_examples: Vec<ErroredExample>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ErroredExample {
     example: Example,
     error: String,
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use crate::marker::{Camelize, Dasherize, Lowercase, Replace, Slice, Transform, Underscorize, Uppercase};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Transformer {
     #[serde(rename = "type")]
     pub kind: Option<String>,
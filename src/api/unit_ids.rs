@@ -12,12 +12,14 @@ use crate::{
 // Input
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UnitIdsInput {
     pub router_config: RouterConfig,
     pub rules: Vec<Rule>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UnitIdsProjectInput {
     pub change_set: RuleChangeSet,
 }
@@ -25,11 +27,13 @@ pub struct UnitIdsProjectInput {
 // Output
 
 #[derive(Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UnitIdsOutput {
     pub rules: HashMap<String, RuleOutput>,
 }
 
 #[derive(Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RuleOutput {
     pub examples: Vec<Example>,
 }
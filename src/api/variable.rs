@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use crate::{api::Transformer, http::Request};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum VariableKind {
     Marker(String),
@@ -27,6 +28,7 @@ pub enum VariableKind {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Variable {
     pub name: String,
     #[serde(rename = "type")]
@@ -36,6 +38,7 @@ pub struct Variable {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum VariableValue {
     Value(String),
     HtmlFilter {
@@ -103,3 +106,26 @@ impl VariableValue {
         }
     }
 }
+
+/// Variables with their values escaped, to insert them literally in a regex or its replacement
+pub(crate) fn escape_variables(variables: &[(String, VariableValue)], escape: impl Fn(&str) -> String) -> Vec<(String, VariableValue)> {
+    variables
+        .iter()
+        .map(|(name, value)| {
+            let value = match value {
+                VariableValue::Value(value) => VariableValue::Value(escape(value)),
+                VariableValue::HtmlFilter {
+                    selector,
+                    default,
+                    transformers,
+                } => VariableValue::HtmlFilter {
+                    selector: selector.clone(),
+                    default: default.as_deref().map(&escape),
+                    transformers: transformers.clone(),
+                },
+            };
+
+            (name.clone(), value)
+        })
+        .collect()
+}
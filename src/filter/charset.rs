@@ -0,0 +1,266 @@
+use std::{cell::Cell, rc::Rc};
+
+use encoding_rs::{CoderResult, Decoder, Encoder, Encoding, UTF_8};
+
+// Bytes of an html document searched for a `<meta charset>`, as browsers do
+const META_SNIFF_SIZE: usize = 1024;
+
+/// Transcode the body from its charset to UTF-8, so filters only handle UTF-8
+#[derive(Debug)]
+pub struct DecodeCharsetFilterBody {
+    decoder: Option<Decoder>,
+    // Start of the document, kept until its `<meta charset>` is found
+    sniffed: Option<Vec<u8>>,
+    encoding: Rc<Cell<Option<&'static Encoding>>>,
+}
+
+/// Transcode the filtered body back to the charset it was decoded from, characters that cannot
+/// be represented in this charset are written as html numeric entities
+#[derive(Debug)]
+pub struct EncodeCharsetFilterBody {
+    encoder: Option<Encoder>,
+    // Trailing bytes of an UTF-8 character split between two chunks
+    pending: Vec<u8>,
+    encoding: Rc<Cell<Option<&'static Encoding>>>,
+}
+
+/// Filters transcoding the body when its charset is not UTF-8.
+///
+/// The charset is read from the `charset` parameter of the content type, or for html documents
+/// without it, from the `<meta charset>` found at the start of the body. Bodies without charset
+/// are assumed to be UTF-8 and are not transcoded, as are UTF-16 ones which cannot be filtered.
+pub fn get_charset_filters(content_type: Option<&str>) -> Option<(DecodeCharsetFilterBody, EncodeCharsetFilterBody)> {
+    let content_type = content_type.unwrap_or_default();
+    let charset = content_type.split(';').skip(1).find_map(|parameter| {
+        let (name, value) = parameter.split_once('=')?;
+
+        (name.trim() == "charset").then(|| value.trim().trim_matches(|c| c == '"' || c == '\''))
+    });
+
+    let (encoding, sniffed) = match charset {
+        Some(charset) => (Some(transcoded_encoding(Encoding::for_label(charset.as_bytes()))?), None),
+        None if content_type.is_empty() || content_type.contains("text/html") => (None, Some(Vec::new())),
+        None => return None,
+    };
+
+    let encoding = Rc::new(Cell::new(encoding));
+
+    Some((
+        DecodeCharsetFilterBody {
+            decoder: encoding.get().map(|encoding| encoding.new_decoder_without_bom_handling()),
+            sniffed,
+            encoding: encoding.clone(),
+        },
+        EncodeCharsetFilterBody {
+            encoder: None,
+            pending: Vec::new(),
+            encoding,
+        },
+    ))
+}
+
+impl DecodeCharsetFilterBody {
+    pub fn filter(&mut self, data: Vec<u8>) -> Vec<u8> {
+        if let Some(sniffed) = self.sniffed.as_mut() {
+            sniffed.extend(data);
+
+            if sniffed.len() < META_SNIFF_SIZE {
+                return Vec::new();
+            }
+
+            let data = self.end_sniffing();
+
+            return self.decode(data, false);
+        }
+
+        self.decode(data, false)
+    }
+
+    pub fn end(mut self) -> Vec<u8> {
+        let data = match self.sniffed {
+            Some(_) => self.end_sniffing(),
+            None => Vec::new(),
+        };
+
+        self.decode(data, true)
+    }
+
+    fn end_sniffing(&mut self) -> Vec<u8> {
+        let sniffed = self.sniffed.take().unwrap_or_default();
+
+        if let Some(encoding) = transcoded_encoding(sniff_meta_charset(&sniffed)) {
+            self.encoding.set(Some(encoding));
+            self.decoder = Some(encoding.new_decoder_without_bom_handling());
+        }
+
+        sniffed
+    }
+
+    fn decode(&mut self, data: Vec<u8>, last: bool) -> Vec<u8> {
+        let Some(decoder) = self.decoder.as_mut() else {
+            return data;
+        };
+
+        let mut output = String::with_capacity(decoder.max_utf8_buffer_length(data.len()).unwrap_or(data.len()));
+        let mut read = 0;
+
+        loop {
+            let (result, consumed, _) = decoder.decode_to_string(&data[read..], &mut output, last);
+            read += consumed;
+
+            match result {
+                CoderResult::InputEmpty => break,
+                CoderResult::OutputFull => output.reserve(decoder.max_utf8_buffer_length(data.len() - read).unwrap_or(data.len())),
+            }
+        }
+
+        output.into_bytes()
+    }
+}
+
+impl EncodeCharsetFilterBody {
+    pub fn filter(&mut self, data: Vec<u8>) -> Vec<u8> {
+        self.encode(data, false)
+    }
+
+    pub fn end(mut self) -> Vec<u8> {
+        self.encode(Vec::new(), true)
+    }
+
+    fn encode(&mut self, data: Vec<u8>, last: bool) -> Vec<u8> {
+        if self.encoder.is_none() {
+            match self.encoding.get() {
+                Some(encoding) => self.encoder = Some(encoding.new_encoder()),
+                None => return data,
+            }
+        }
+
+        let Some(encoder) = self.encoder.as_mut() else {
+            return data;
+        };
+
+        self.pending.extend(data);
+
+        // Keep an incomplete character for the next chunk
+        let complete = match std::str::from_utf8(&self.pending) {
+            Err(error) if !last && error.error_len().is_none() => error.valid_up_to(),
+            _ => self.pending.len(),
+        };
+        let pending = self.pending.split_off(complete);
+        let text = String::from_utf8_lossy(&self.pending).into_owned();
+        self.pending = pending;
+
+        let mut output = Vec::with_capacity(
+            encoder
+                .max_buffer_length_from_utf8_if_no_unmappables(text.len())
+                .unwrap_or(text.len()),
+        );
+        let mut read = 0;
+
+        loop {
+            let (result, consumed, _) = encoder.encode_from_utf8_to_vec(&text[read..], &mut output, last);
+            read += consumed;
+
+            match result {
+                CoderResult::InputEmpty => break,
+                CoderResult::OutputFull => output.reserve(
+                    encoder
+                        .max_buffer_length_from_utf8_if_no_unmappables(text.len() - read)
+                        .unwrap_or(text.len()),
+                ),
+            }
+        }
+
+        output
+    }
+}
+
+// Encoding to transcode from, none for UTF-8 and for encodings that cannot be written back, like
+// UTF-16 ones
+fn transcoded_encoding(encoding: Option<&'static Encoding>) -> Option<&'static Encoding> {
+    encoding.filter(|encoding| *encoding != UTF_8 && encoding.is_ascii_compatible() && encoding.output_encoding() == *encoding)
+}
+
+// Charset of the first `<meta charset>` or `<meta http-equiv="content-type">` of the document
+fn sniff_meta_charset(data: &[u8]) -> Option<&'static Encoding> {
+    let data = data[..data.len().min(META_SNIFF_SIZE)].to_ascii_lowercase();
+    let mut rest = data.as_slice();
+
+    while let Some(start) = find(rest, b"<meta") {
+        rest = &rest[start + 5..];
+        let tag = &rest[..find(rest, b">").unwrap_or(rest.len())];
+
+        if let Some(charset) = find(tag, b"charset=") {
+            let value = &tag[charset + 8..];
+            let value = value.strip_prefix(b"\"").or_else(|| value.strip_prefix(b"'")).unwrap_or(value);
+            let end = value
+                .iter()
+                .position(|byte| matches!(byte, b'"' | b'\'' | b';' | b'/' | b' ' | b'\t' | b'\n' | b'\r'))
+                .unwrap_or(value.len());
+
+            return Encoding::for_label(&value[..end]);
+        }
+    }
+
+    None
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|window| window == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transcode(content_type: Option<&str>, body: &[u8], chunk_size: usize, inserted: &str) -> Vec<u8> {
+        let (mut decode, mut encode) = get_charset_filters(content_type).unwrap();
+        let mut output = Vec::new();
+
+        for chunk in body.chunks(chunk_size) {
+            let decoded = decode.filter(chunk.to_vec());
+
+            output.extend(encode.filter(decoded));
+        }
+
+        let mut decoded = decode.end();
+        decoded.extend(inserted.as_bytes());
+        output.extend(encode.filter(decoded));
+        output.extend(encode.end());
+
+        output
+    }
+
+    #[test]
+    fn transcodes_charset_of_the_content_type() {
+        let (encoded, _, _) = encoding_rs::WINDOWS_1252.encode("<p>Café €</p>");
+        let output = transcode(Some("text/html; charset=iso-8859-1"), &encoded, 3, "<p>Été 東京</p>");
+
+        assert_eq!(
+            encoding_rs::WINDOWS_1252.decode(&output).0,
+            "<p>Café €</p><p>Été &#26481;&#20140;</p>"
+        );
+    }
+
+    #[test]
+    fn transcodes_charset_of_the_meta() {
+        let (encoded, _, _) = encoding_rs::SHIFT_JIS.encode("<html><head><meta charset=\"Shift_JIS\"></head><body>東京</body></html>");
+        let output = transcode(Some("text/html"), &encoded, 5, "é");
+
+        assert_eq!(
+            encoding_rs::SHIFT_JIS.decode(&output).0,
+            "<html><head><meta charset=\"Shift_JIS\"></head><body>東京</body></html>&#233;"
+        );
+    }
+
+    #[test]
+    fn keeps_utf8_bodies() {
+        assert!(get_charset_filters(Some("text/html; charset=utf-8")).is_none());
+        assert!(get_charset_filters(Some("text/css")).is_none());
+        assert!(get_charset_filters(Some("text/plain; charset=utf-16")).is_none());
+
+        let body = "<html><head><meta charset=\"utf-8\"></head><body>東京</body></html>".as_bytes();
+
+        assert_eq!(transcode(Some("text/html"), body, 4, "é"), [body, "é".as_bytes()].concat());
+    }
+}
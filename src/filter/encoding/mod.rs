@@ -6,6 +6,7 @@ pub enum SupportedEncoding {
     Brotli,
     Gzip,
     Deflate,
+    Zstd,
 }
 
 impl SupportedEncoding {
@@ -14,6 +15,7 @@ impl SupportedEncoding {
         set.insert("br".to_string());
         set.insert("gzip".to_string());
         set.insert("deflate".to_string());
+        set.insert("zstd".to_string());
         set
     }
 }
@@ -23,16 +25,54 @@ use std::collections::HashSet;
 pub use decode::DecodeFilterBody;
 pub use encode::EncodeFilterBody;
 
-pub fn get_encoding_filters(encoding: &str) -> Option<(DecodeFilterBody, EncodeFilterBody)> {
-    let supported_encoding = match encoding {
-        "br" => SupportedEncoding::Brotli,
-        "gzip" => SupportedEncoding::Gzip,
-        "deflate" => SupportedEncoding::Deflate,
-        _ => return None,
-    };
-
-    Some((
-        DecodeFilterBody::new(supported_encoding.clone()),
-        EncodeFilterBody::new(supported_encoding),
-    ))
+/// Decoders and encoders of a `Content-Encoding` value, which may list several encodings in the
+/// order they were applied, like `gzip, br`.
+///
+/// Decoders are returned in the order they must be applied, last encoding first, and encoders in
+/// the order of the header, so the filtered body is encoded as the original one. `None` is
+/// returned if any of the encodings is not supported.
+pub fn get_encoding_filters(encoding: &str) -> Option<(Vec<DecodeFilterBody>, Vec<EncodeFilterBody>)> {
+    let mut decoders = Vec::new();
+    let mut encoders = Vec::new();
+
+    for supported_encoding in parse_encodings(encoding)? {
+        match (
+            DecodeFilterBody::new(supported_encoding.clone()),
+            EncodeFilterBody::new(supported_encoding),
+        ) {
+            (Ok(decoder), Ok(encoder)) => {
+                decoders.insert(0, decoder);
+                encoders.push(encoder);
+            }
+            (Err(err), _) | (_, Err(err)) => {
+                tracing::error!("cannot create filters for content-encoding {encoding}: {err}");
+
+                return None;
+            }
+        }
+    }
+
+    Some((decoders, encoders))
+}
+
+/// Whether all the encodings of a `Content-Encoding` value are supported
+pub fn is_supported_encoding(encoding: &str) -> bool {
+    parse_encodings(encoding).is_some()
+}
+
+fn parse_encodings(encoding: &str) -> Option<Vec<SupportedEncoding>> {
+    let mut encodings = Vec::new();
+
+    for encoding in encoding.split(',').map(str::trim) {
+        encodings.push(match encoding {
+            "br" => SupportedEncoding::Brotli,
+            "gzip" => SupportedEncoding::Gzip,
+            "deflate" => SupportedEncoding::Deflate,
+            "zstd" => SupportedEncoding::Zstd,
+            "" | "identity" => continue,
+            _ => return None,
+        });
+    }
+
+    Some(encodings)
 }
@@ -1,20 +1,25 @@
 use std::{cell::RefCell, rc::Rc, sync::Arc};
 
 #[cfg(feature = "compress")]
-use crate::filter::encoding::{DecodeFilterBody, EncodeFilterBody, get_encoding_filters};
+use crate::filter::encoding::{DecodeFilterBody, EncodeFilterBody, get_encoding_filters, is_supported_encoding};
 use crate::{
     action::UnitTrace,
     api::{BodyFilter, TextAction, VariableValue},
     filter::{
         HtmlFilterBodyAction,
         buffer_filter_body::BufferFilterBody,
+        charset::{DecodeCharsetFilterBody, EncodeCharsetFilterBody, get_charset_filters},
         error::Result,
         html_body_action::{
             HtmlBodyVisitor,
             body_capture::{BodyCapture, CaptureRegistry},
         },
         html_to_markdown::HtmlToMarkdownFilter,
+        json_filter_body::JsonFilterBodyAction,
+        link_redirector::LinkRedirector,
         text_filter_body::{TextFilterAction, TextFilterBodyAction},
+        text_regex_filter_body::TextRegexFilterBodyAction,
+        xml_filter_body::XmlFilterBodyAction,
     },
     http::Header,
 };
@@ -30,7 +35,12 @@ pub enum FilterBodyActionItem {
     Buffer(BufferFilterBody),
     HtmlToMarkdown(Box<HtmlToMarkdownFilter>),
     Html(Box<HtmlFilterBodyAction>),
+    Json(Box<JsonFilterBodyAction>),
     Text(TextFilterBodyAction),
+    TextRegex(Box<TextRegexFilterBodyAction>),
+    Xml(Box<XmlFilterBodyAction>),
+    DecodeCharset(Box<DecodeCharsetFilterBody>),
+    EncodeCharset(Box<EncodeCharsetFilterBody>),
     #[cfg(feature = "compress")]
     Encode(Box<EncodeFilterBody>),
     #[cfg(feature = "compress")]
@@ -43,29 +53,32 @@ impl FilterBodyAction {
         headers: &[Header],
         unit_trace: Option<Rc<RefCell<UnitTrace>>>,
         variables: Vec<(String, VariableValue)>,
+    ) -> Self {
+        Self::new_with_link_redirector(filters, headers, unit_trace, variables, None)
+    }
+
+    /// Same as `new`, with the links found by the filters looked up in `links` to rewrite the
+    /// redirected ones
+    pub fn new_with_link_redirector(
+        filters: Vec<BodyFilter>,
+        headers: &[Header],
+        unit_trace: Option<Rc<RefCell<UnitTrace>>>,
+        variables: Vec<(String, VariableValue)>,
+        links: Option<Rc<RefCell<LinkRedirector>>>,
     ) -> Self {
         let mut chain = Vec::new();
-        let mut content_type = None;
+        let content_type = header_value(headers, "content-type");
         #[cfg(feature = "compress")]
-        let mut content_encoding = None;
-
-        for header in headers {
-            #[cfg(feature = "compress")]
-            if header.name.to_lowercase() == "content-encoding" {
-                content_encoding = Some(header.value.to_lowercase());
-            }
-
-            if header.name.to_lowercase() == "content-type" {
-                content_type = Some(header.value.to_lowercase());
-            }
-        }
+        let content_encoding = header_value(headers, "content-encoding");
 
         let capture_registry = CaptureRegistry::from_variables(variables);
         let need_body_capture = capture_registry.need_body_capture();
         let variables = Arc::new(capture_registry);
 
         for filter in filters {
-            if let Some(item) = FilterBodyActionItem::new(filter, content_type.clone(), unit_trace.clone(), variables.clone()) {
+            if let Some(item) =
+                FilterBodyActionItem::new(filter, content_type.clone(), unit_trace.clone(), variables.clone(), links.clone())
+            {
                 chain.push(item);
             }
         }
@@ -83,6 +96,14 @@ impl FilterBodyAction {
             );
         }
 
+        // Filters handle UTF-8, bodies in other charsets are transcoded to UTF-8 and back
+        if !chain.is_empty()
+            && let Some((decode, encode)) = get_charset_filters(content_type.as_deref())
+        {
+            chain.insert(0, FilterBodyActionItem::DecodeCharset(Box::new(decode)));
+            chain.push(FilterBodyActionItem::EncodeCharset(Box::new(encode)));
+        }
+
         #[cfg(not(feature = "compress"))]
         {
             return Self { chain, in_error: false };
@@ -96,9 +117,12 @@ impl FilterBodyAction {
         #[cfg(feature = "compress")]
         match content_encoding {
             Some(encoding) => match get_encoding_filters(encoding.as_str()) {
-                Some((decode, encode)) => {
-                    chain.insert(0, FilterBodyActionItem::Decode(Box::new(decode)));
-                    chain.push(FilterBodyActionItem::Encode(Box::new(encode)));
+                Some((decoders, encoders)) => {
+                    chain.splice(
+                        0..0,
+                        decoders.into_iter().map(|decode| FilterBodyActionItem::Decode(Box::new(decode))),
+                    );
+                    chain.extend(encoders.into_iter().map(|encode| FilterBodyActionItem::Encode(Box::new(encode))));
 
                     Self { chain, in_error: false }
                 }
@@ -117,6 +141,35 @@ impl FilterBodyAction {
         }
     }
 
+    /// Whether the filters change the body of a response with these headers, which is the case
+    /// when `new` creates a filter with them, unless they are misconfigured
+    pub fn will_filter(filters: &[BodyFilter], headers: &[Header]) -> bool {
+        let content_type = header_value(headers, "content-type");
+
+        // Filtering is disabled for bodies which cannot be decoded
+        #[cfg(feature = "compress")]
+        if header_value(headers, "content-encoding").is_some_and(|encoding| !is_supported_encoding(encoding.as_str())) {
+            return false;
+        }
+
+        filters
+            .iter()
+            .any(|filter| FilterBodyActionItem::supports_content_type(filter, content_type.as_deref()))
+    }
+
+    /// Compress the filtered body, for responses compressed by the proxy
+    #[cfg(feature = "compress")]
+    pub fn with_compression(mut self, encoding: &str) -> Self {
+        match get_encoding_filters(encoding) {
+            Some((_, encoders)) => self
+                .chain
+                .extend(encoders.into_iter().map(|encode| FilterBodyActionItem::Encode(Box::new(encode)))),
+            None => tracing::error!("cannot compress the response with {encoding}"),
+        }
+
+        self
+    }
+
     pub fn is_empty(&self) -> bool {
         self.chain.is_empty()
     }
@@ -185,12 +238,21 @@ impl FilterBodyAction {
     }
 }
 
+/// Lowercased value of the last header with this name
+fn header_value(headers: &[Header], name: &str) -> Option<String> {
+    headers
+        .iter()
+        .rev()
+        .find(|header| header.name.eq_ignore_ascii_case(name))
+        .map(|header| header.value.to_lowercase())
+}
+
 /// Whether text spliced into a body of this content type has a chance of not corrupting it.
 ///
 /// This is a deny list of formats known to break, so `true` is no guarantee - only "not known
 /// to be binary". `content_type` is expected lowercased, as read from the response headers;
 /// parameters such as `; charset=utf-8` are ignored.
-fn may_accept_text_splicing(content_type: &str) -> bool {
+pub(crate) fn may_accept_text_splicing(content_type: &str) -> bool {
     let content_type = content_type.split(';').next().unwrap_or_default().trim();
 
     if let Some(subtype) = content_type.strip_prefix("image/") {
@@ -215,90 +277,123 @@ fn may_accept_text_splicing(content_type: &str) -> bool {
         )
 }
 
+/// Whether the content type is `application/json`, `text/json` or a `+json` type. `content_type`
+/// is expected lowercased.
+fn is_json_content_type(content_type: &str) -> bool {
+    let content_type = content_type.split(';').next().unwrap_or_default().trim();
+
+    matches!(content_type, "application/json" | "text/json") || content_type.ends_with("+json")
+}
+
+/// Whether the content type is an XML type, like `application/xml` or `application/rss+xml`.
+/// `content_type` is expected lowercased.
+fn is_xml_content_type(content_type: &str) -> bool {
+    let content_type = content_type.split(';').next().unwrap_or_default().trim();
+
+    matches!(content_type, "application/xml" | "text/xml") || content_type.ends_with("+xml")
+}
+
 impl FilterBodyActionItem {
+    /// Whether the filter applies to a body of this content type, `content_type` is expected
+    /// lowercased
+    pub fn supports_content_type(filter: &BodyFilter, content_type: Option<&str>) -> bool {
+        let Some(content_type) = content_type else {
+            // Assume HTML if no content type, json and xml bodies are edited when they turn out
+            // to be json or xml
+            return !matches!(filter, BodyFilter::Other(_));
+        };
+
+        match filter {
+            BodyFilter::HTML(_) | BodyFilter::HTMLToMarkdown(_) => content_type.contains("text/html"),
+            // Appending or prepending splices text into the bytes the origin produced, which
+            // corrupts media and other binary payloads - and costs them their Content-Length.
+            // Replacing discards the original body, so the type it used to have is irrelevant:
+            // a rule serving a custom body over a binary asset is doing what it was asked.
+            BodyFilter::Text(text_body_filter) => {
+                matches!(text_body_filter.action, TextAction::Replace) || may_accept_text_splicing(content_type)
+            }
+            BodyFilter::JSON(_) => is_json_content_type(content_type),
+            BodyFilter::XML(_) => is_xml_content_type(content_type),
+            BodyFilter::Other(_) => false,
+        }
+    }
+
     pub fn new(
         filter: BodyFilter,
         content_type: Option<String>,
         unit_trace: Option<Rc<RefCell<UnitTrace>>>,
         variables: Arc<CaptureRegistry>,
+        links: Option<Rc<RefCell<LinkRedirector>>>,
     ) -> Option<Self> {
-        match filter {
-            BodyFilter::HTML(html_body_filter) => match content_type {
-                Some(content_type) if content_type.contains("text/html") => {
-                    // @TODO Support charset
-                    HtmlBodyVisitor::new(html_body_filter, unit_trace, variables.clone())
-                        .map(|visitor| Self::Html(Box::new(HtmlFilterBodyAction::new(visitor))))
-                }
-                None => {
-                    // Assume HTML if no content type
-                    HtmlBodyVisitor::new(html_body_filter, unit_trace, variables)
-                        .map(|visitor| Self::Html(Box::new(HtmlFilterBodyAction::new(visitor))))
-                }
-                _ => {
-                    tracing::warn!(
-                        "html filtering is only supported for text/html content type, {} received",
-                        content_type.unwrap_or_default()
-                    );
+        if !Self::supports_content_type(&filter, content_type.as_deref()) {
+            let supported = match &filter {
+                BodyFilter::HTML(_) => "html filtering is only supported for text/html content type",
+                BodyFilter::HTMLToMarkdown(_) => "html to markdown is only supported for text/html content type",
+                BodyFilter::Text(_) => "appending, prepending or replacing text with a regex is not supported for binary content types",
+                BodyFilter::JSON(_) => "json editing is only supported for json content types",
+                BodyFilter::XML(_) => "xml editing is only supported for xml content types",
+                BodyFilter::Other(_) => {
+                    tracing::warn!("unsupported body filter: {filter:?}, you may need to update your agent or module");
 
-                    None
+                    return None;
                 }
-            },
+            };
+
+            tracing::warn!("{supported}, {} received", content_type.unwrap_or_default());
+
+            return None;
+        }
+
+        match filter {
+            BodyFilter::HTML(html_body_filter) => HtmlBodyVisitor::new(html_body_filter, unit_trace, variables, links)
+                .map(|visitor| Self::Html(Box::new(HtmlFilterBodyAction::new(visitor)))),
+            BodyFilter::Text(text_body_filter) if matches!(text_body_filter.action, TextAction::ReplaceRegex) => {
+                let regex = match regex::bytes::Regex::new(text_body_filter.pattern.as_deref().unwrap_or_default()) {
+                    Ok(regex) => regex,
+                    Err(err) => {
+                        tracing::error!("invalid regex in replace_regex body filter: {err}");
+
+                        return None;
+                    }
+                };
+
+                Some(Self::TextRegex(Box::new(TextRegexFilterBodyAction::new(
+                    text_body_filter.id,
+                    regex,
+                    text_body_filter.content,
+                    text_body_filter.lookbehind,
+                    unit_trace,
+                ))))
+            }
             BodyFilter::Text(text_body_filter) => {
                 let action = match text_body_filter.action {
                     TextAction::Append => TextFilterAction::Append,
                     TextAction::Prepend => TextFilterAction::Prepend,
-                    TextAction::Replace => TextFilterAction::Replace,
+                    TextAction::Replace | TextAction::ReplaceRegex => TextFilterAction::Replace,
                 };
 
-                // Appending or prepending splices text into the bytes the origin produced, which
-                // corrupts media and other binary payloads - and costs them their Content-Length.
-                // Replacing discards the original body, so the type it used to have is irrelevant:
-                // a rule serving a custom body over a binary asset is doing what it was asked.
-                if !matches!(action, TextFilterAction::Replace)
-                    && let Some(content_type) = content_type.as_deref()
-                    && !may_accept_text_splicing(content_type)
-                {
-                    tracing::warn!("appending or prepending text is not supported for {content_type} content type");
-
-                    return None;
-                }
-
                 Some(Self::Text(TextFilterBodyAction::new(
                     text_body_filter.id,
                     action,
                     text_body_filter.content,
                 )))
             }
-            BodyFilter::HTMLToMarkdown(html_to_md_filter) => match content_type {
-                Some(content_type) if content_type.contains("text/html") => {
-                    // @TODO Support charset
-                    Some(Self::HtmlToMarkdown(Box::new(HtmlToMarkdownFilter::new(
-                        html_to_md_filter.options,
-                        html_to_md_filter.id,
-                        unit_trace.clone(),
-                    ))))
-                }
-                None => {
-                    // Assume HTML if no content type
-                    Some(Self::HtmlToMarkdown(Box::new(HtmlToMarkdownFilter::new(
-                        html_to_md_filter.options,
-                        html_to_md_filter.id,
-                        unit_trace.clone(),
-                    ))))
-                }
-                _ => {
-                    tracing::warn!(
-                        "html to markdown is only supported for text/html content type, {} received",
-                        content_type.unwrap_or_default()
-                    );
-
-                    None
-                }
-            },
-            BodyFilter::Other(_) => {
-                tracing::warn!("unsupported body filter: {filter:?}, you may need to update your agent or module");
-                None
+            BodyFilter::HTMLToMarkdown(html_to_md_filter) => Some(Self::HtmlToMarkdown(Box::new(HtmlToMarkdownFilter::new(
+                html_to_md_filter.options,
+                html_to_md_filter.id,
+                unit_trace,
+            )))),
+            BodyFilter::JSON(json_body_filter) => JsonFilterBodyAction::new(
+                json_body_filter.id,
+                json_body_filter.operations,
+                json_body_filter.max_size,
+                unit_trace,
+            )
+            .map(|filter| Self::Json(Box::new(filter))),
+            BodyFilter::XML(xml_body_filter) => {
+                XmlFilterBodyAction::new(xml_body_filter, links, unit_trace).map(|filter| Self::Xml(Box::new(filter)))
             }
+            BodyFilter::Other(_) => None,
         }
     }
 
@@ -307,7 +402,12 @@ impl FilterBodyActionItem {
             FilterBodyActionItem::Buffer(buffer) => buffer.filter(data),
             FilterBodyActionItem::Html(html_body_filter) => html_body_filter.filter(data)?,
             FilterBodyActionItem::HtmlToMarkdown(html_to_md_filter) => html_to_md_filter.filter(data),
+            FilterBodyActionItem::Json(json_body_filter) => json_body_filter.filter(data),
             FilterBodyActionItem::Text(text_body_filter) => text_body_filter.filter(data, unit_trace),
+            FilterBodyActionItem::TextRegex(text_regex_filter) => text_regex_filter.filter(data),
+            FilterBodyActionItem::Xml(xml_body_filter) => xml_body_filter.filter(data),
+            FilterBodyActionItem::DecodeCharset(decode_charset_filter) => decode_charset_filter.filter(data),
+            FilterBodyActionItem::EncodeCharset(encode_charset_filter) => encode_charset_filter.filter(data),
             #[cfg(feature = "compress")]
             FilterBodyActionItem::Decode(decode_body_filter) => decode_body_filter.filter(data)?,
             #[cfg(feature = "compress")]
@@ -320,7 +420,12 @@ impl FilterBodyActionItem {
             FilterBodyActionItem::Buffer(buffer) => buffer.end(),
             FilterBodyActionItem::Html(html_body_filter) => html_body_filter.end(),
             FilterBodyActionItem::HtmlToMarkdown(html_to_md_filter) => html_to_md_filter.end(),
+            FilterBodyActionItem::Json(json_body_filter) => json_body_filter.end(),
             FilterBodyActionItem::Text(text_body_filter) => text_body_filter.end(),
+            FilterBodyActionItem::TextRegex(text_regex_filter) => text_regex_filter.end(),
+            FilterBodyActionItem::Xml(xml_body_filter) => xml_body_filter.end(),
+            FilterBodyActionItem::DecodeCharset(decode_charset_filter) => decode_charset_filter.end(),
+            FilterBodyActionItem::EncodeCharset(encode_charset_filter) => encode_charset_filter.end(),
             #[cfg(feature = "compress")]
             FilterBodyActionItem::Decode(decode_body_filter) => decode_body_filter.end()?,
             #[cfg(feature = "compress")]
@@ -339,7 +444,10 @@ mod tests {
     };
 
     use super::*;
-    use crate::api::{HTMLBodyFilter, HTMLBodyFilterInnerLegacy, TextBodyFilter};
+    use crate::api::{
+        HTMLBodyFilter, HTMLBodyFilterInnerLegacy, JSONAction, JSONBodyFilter, JSONOperation, TextBodyFilter, XMLAction, XMLBodyFilter,
+        XMLEdit,
+    };
 
     #[test]
     pub fn test_filter_gzip() {
@@ -395,6 +503,60 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn test_filter_json_gzip() {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(br#"{"links":{"self":"http://old.example.com/1"},"debug":true}"#)
+            .unwrap();
+        let compressed_input = encoder.finish().unwrap();
+
+        let headers = vec![
+            Header {
+                name: "Content-Encoding".to_string(),
+                value: "gzip".to_string(),
+            },
+            Header {
+                name: "Content-Type".to_string(),
+                value: "application/hal+json".to_string(),
+            },
+        ];
+
+        let mut filter = FilterBodyAction::new(
+            vec![BodyFilter::JSON(JSONBodyFilter {
+                action: JSONAction::Edit,
+                operations: vec![
+                    JSONOperation::Set {
+                        path: "$.links.self".to_string(),
+                        value: serde_json::json!("https://new.example.com/1"),
+                    },
+                    JSONOperation::Remove {
+                        path: "/debug".to_string(),
+                    },
+                ],
+                max_size: None,
+                id: None,
+                target_hash: None,
+            })],
+            &headers,
+            None,
+            Vec::new(),
+        );
+
+        let (first, second) = compressed_input.split_at(compressed_input.len() / 2);
+        let mut filtered = filter.filter(first.to_vec(), None);
+        filtered.extend(filter.filter(second.to_vec(), None));
+        filtered.extend(filter.end(None));
+
+        let mut decoder = GzDecoder::new(Vec::new());
+        decoder.write_all(&filtered).unwrap();
+
+        assert_eq!(
+            String::from_utf8(decoder.finish().unwrap()).unwrap(),
+            r#"{"links":{"self":"https://new.example.com/1"}}"#
+        );
+    }
+
     #[test]
     pub fn test_filter_deflate() {
         let decompressed_input = "<html><head></head><body class=\"page\"><div>Yolo</div></body></html>".to_string();
@@ -503,6 +665,170 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn test_filter_zstd() {
+        let decompressed_input = "<html><head></head><body class=\"page\"><div>Yolo</div></body></html>".to_string();
+        let compressed_input = zstd::encode_all(decompressed_input.as_bytes(), 0).unwrap();
+
+        let headers = vec![
+            Header {
+                name: "Content-Encoding".to_string(),
+                value: "zstd".to_string(),
+            },
+            Header {
+                name: "Content-Type".to_string(),
+                value: "text/html;charset=".to_string(),
+            },
+        ];
+
+        let mut filter = FilterBodyAction::new(
+            vec![BodyFilter::HTML(HTMLBodyFilter::PrependLegacy(HTMLBodyFilterInnerLegacy {
+                element_tree: vec!["html".to_string(), "body".to_string()],
+                css_selector: Some("".to_string()),
+                value: "<p>This is as test</p>".to_string(),
+                id: Some("test".to_string()),
+                target_hash: Some("target_hash".to_string()),
+                inner_value: None,
+            }))],
+            &headers,
+            None,
+            Vec::new(),
+        );
+
+        let mut filtered = Vec::new();
+
+        for chunk in compressed_input.chunks(10) {
+            filtered.extend(filter.filter(chunk.to_vec(), None));
+        }
+
+        filtered.extend(filter.end(None));
+
+        assert_eq!(
+            String::from_utf8(zstd::decode_all(filtered.as_slice()).unwrap()).unwrap(),
+            "<html><head></head><body class=\"page\"><p>This is as test</p><div>Yolo</div></body></html>".to_string()
+        );
+    }
+
+    #[test]
+    pub fn test_filter_stacked_encodings() {
+        let decompressed_input = "<html><head></head><body class=\"page\"><div>Yolo</div></body></html>".to_string();
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(decompressed_input.as_bytes()).unwrap();
+        let gzipped_input = encoder.finish().unwrap();
+        let mut compressed_input = Vec::new();
+        let mut reader = brotli::CompressorReader::new(gzipped_input.as_slice(), 4096, 11, 22);
+        reader.read_to_end(&mut compressed_input).expect("Failed to encode");
+
+        let headers = vec![
+            Header {
+                name: "Content-Encoding".to_string(),
+                value: "gzip, br".to_string(),
+            },
+            Header {
+                name: "Content-Type".to_string(),
+                value: "text/html;charset=".to_string(),
+            },
+        ];
+
+        let mut filter = FilterBodyAction::new(
+            vec![BodyFilter::HTML(HTMLBodyFilter::PrependLegacy(HTMLBodyFilterInnerLegacy {
+                element_tree: vec!["html".to_string(), "body".to_string()],
+                css_selector: Some("".to_string()),
+                value: "<p>This is as test</p>".to_string(),
+                id: Some("test".to_string()),
+                target_hash: Some("target_hash".to_string()),
+                inner_value: None,
+            }))],
+            &headers,
+            None,
+            Vec::new(),
+        );
+
+        assert!(!filter.is_empty());
+
+        let mut filtered = Vec::new();
+
+        for chunk in compressed_input.chunks(10) {
+            filtered.extend(filter.filter(chunk.to_vec(), None));
+        }
+
+        filtered.extend(filter.end(None));
+
+        let mut gzipped_output = Vec::new();
+        let mut reader = brotli::Decompressor::new(filtered.as_slice(), 4096);
+        reader.read_to_end(&mut gzipped_output).expect("Failed to decompress");
+
+        let mut decoder = GzDecoder::new(Vec::new());
+        decoder.write_all(&gzipped_output).unwrap();
+        let decompressed_output = decoder.finish().unwrap();
+
+        assert_eq!(
+            String::from_utf8(decompressed_output).unwrap(),
+            "<html><head></head><body class=\"page\"><p>This is as test</p><div>Yolo</div></body></html>".to_string()
+        );
+    }
+
+    #[test]
+    pub fn test_unsupported_encoding_disables_filtering() {
+        let headers = vec![Header {
+            name: "Content-Encoding".to_string(),
+            value: "gzip, compress".to_string(),
+        }];
+
+        let filter = FilterBodyAction::new(
+            vec![BodyFilter::Text(TextBodyFilter {
+                action: TextAction::Append,
+                content: "appended".to_string(),
+                pattern: None,
+                lookbehind: None,
+                id: None,
+                target_hash: None,
+            })],
+            &headers,
+            None,
+            Vec::new(),
+        );
+
+        assert!(filter.is_empty());
+    }
+
+    #[test]
+    pub fn test_filter_html_charset() {
+        let (input, _, _) = encoding_rs::WINDOWS_1252.encode("<html><head></head><body><div>Café</div></body></html>");
+
+        let headers = vec![Header {
+            name: "Content-Type".to_string(),
+            value: "text/html; charset=ISO-8859-1".to_string(),
+        }];
+
+        let mut filter = FilterBodyAction::new(
+            vec![BodyFilter::HTML(HTMLBodyFilter::PrependLegacy(HTMLBodyFilterInnerLegacy {
+                element_tree: vec!["html".to_string(), "body".to_string()],
+                css_selector: Some("".to_string()),
+                value: "<p>Été à Tōkyō</p>".to_string(),
+                id: Some("test".to_string()),
+                target_hash: Some("target_hash".to_string()),
+                inner_value: None,
+            }))],
+            &headers,
+            None,
+            Vec::new(),
+        );
+
+        let mut filtered = Vec::new();
+
+        for chunk in input.chunks(7) {
+            filtered.extend(filter.filter(chunk.to_vec(), None));
+        }
+
+        filtered.extend(filter.end(None));
+
+        assert_eq!(
+            encoding_rs::WINDOWS_1252.decode(&filtered).0,
+            "<html><head></head><body><p>Été à T&#333;ky&#333;</p><div>Café</div></body></html>"
+        );
+    }
+
     #[test]
     pub fn test_filter() {
         let mut filter = FilterBodyAction::new(Vec::new(), &[], None, Vec::new());
@@ -690,6 +1016,8 @@ mod tests {
         BodyFilter::Text(TextBodyFilter {
             action,
             content: "filtered".to_string(),
+            pattern: None,
+            lookbehind: None,
             id: None,
             target_hash: None,
         })
@@ -763,4 +1091,95 @@ mod tests {
             assert!(!filter.is_empty(), "text filters apply when no content type is known");
         }
     }
+
+    #[test]
+    pub fn test_text_replace_regex_across_chunks() {
+        let mut filter = FilterBodyAction::new(
+            vec![BodyFilter::Text(TextBodyFilter {
+                action: TextAction::ReplaceRegex,
+                content: "/new/$1".to_string(),
+                pattern: Some(r"/old/(\w+)".to_string()),
+                lookbehind: None,
+                id: None,
+                target_hash: None,
+            })],
+            &[Header {
+                name: "Content-Type".to_string(),
+                value: "application/json".to_string(),
+            }],
+            None,
+            Vec::new(),
+        );
+
+        let mut filtered = filter.filter(br#"{"a":"/old/pa"#.to_vec(), None);
+        filtered.extend(filter.filter(br#"ge","b":"/old/other"}"#.to_vec(), None));
+        filtered.extend(filter.end(None));
+
+        assert_eq!(r#"{"a":"/new/page","b":"/new/other"}"#, String::from_utf8(filtered).unwrap());
+        assert!(filter_for(TextAction::ReplaceRegex, "image/png").is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "router")]
+    pub fn test_filter_xml_redirects_links() {
+        use crate::{api::Rule, filter::LinkRouter, router::Router};
+
+        let mut router = Router::<Rule>::default();
+        router.insert(
+            serde_json::from_value(serde_json::json!({
+                "id": "redirect",
+                "source": {"path": "/old"},
+                "target": "/new",
+                "status_code": 301,
+                "rank": 0,
+            }))
+            .unwrap(),
+        );
+
+        let xml_filter = BodyFilter::XML(XMLBodyFilter {
+            action: XMLAction::Edit,
+            edits: vec![XMLEdit {
+                path: "url/loc".to_string(),
+                pattern: None,
+                replacement: None,
+                redirect: true,
+            }],
+            id: None,
+            target_hash: None,
+        });
+        let headers = [Header {
+            name: "Content-Type".to_string(),
+            value: "application/xml".to_string(),
+        }];
+        let input = "<urlset><url><loc>https://example.com/old</loc></url><url><loc>https://example.com/page</loc></url></urlset>";
+
+        let links = LinkRedirector::new(LinkRouter::new(Arc::new(router), None, Some(10)), None);
+        let mut filter = FilterBodyAction::new_with_link_redirector(
+            vec![xml_filter.clone()],
+            &headers,
+            None,
+            Vec::new(),
+            Some(Rc::new(RefCell::new(links))),
+        );
+        let mut filtered = filter.filter(input.as_bytes()[..30].to_vec(), None);
+        filtered.extend(filter.filter(input.as_bytes()[30..].to_vec(), None));
+        filtered.extend(filter.end(None));
+
+        assert_eq!(
+            String::from_utf8(filtered).unwrap(),
+            input.replace("example.com/old", "example.com/new")
+        );
+        assert!(
+            FilterBodyAction::new(
+                vec![xml_filter],
+                &[Header {
+                    name: "Content-Type".to_string(),
+                    value: "text/html".to_string()
+                }],
+                None,
+                Vec::new()
+            )
+            .is_empty()
+        );
+    }
 }
@@ -0,0 +1,112 @@
+use std::{cell::RefCell, rc::Rc, sync::Arc};
+
+use lol_html::{ElementHandler, send::IntoHandler};
+use regex::Regex;
+
+use crate::{action::UnitTrace, filter::html_body_action::body_capture::CaptureRegistry};
+
+#[derive(Debug)]
+pub enum AttributeAction {
+    Set(String),
+    Remove,
+    Replace(Regex, String),
+}
+
+#[derive(Debug)]
+pub struct BodyAttribute {
+    css_selector: String,
+    name: String,
+    action: AttributeAction,
+    id: Option<String>,
+    target_hash: Option<String>,
+    unit_trace: Option<Rc<RefCell<UnitTrace>>>,
+    variables: Arc<CaptureRegistry>,
+}
+
+impl<'h> IntoHandler<ElementHandler<'h>> for BodyAttribute {
+    fn into_handler(self) -> ElementHandler<'h> {
+        Box::new(move |element| {
+            // Value set on the attribute, none when it is removed
+            let value = match &self.action {
+                AttributeAction::Set(value) => {
+                    let value = self.variables.replace(value.clone());
+                    element.set_attribute(self.name.as_str(), value.as_str())?;
+
+                    Some(value)
+                }
+                AttributeAction::Remove => {
+                    if !element.has_attribute(self.name.as_str()) {
+                        return Ok(());
+                    }
+
+                    element.remove_attribute(self.name.as_str());
+
+                    None
+                }
+                AttributeAction::Replace(regex, replacement) => {
+                    let Some(value) = element.get_attribute(self.name.as_str()) else {
+                        return Ok(());
+                    };
+                    // Captured variables are inserted literally, `$` is not a capture group in them
+                    let replacement = self
+                        .variables
+                        .replace_escaped(replacement.clone(), |value| value.replace('$', "$$"));
+                    let new_value = regex.replace_all(value.as_str(), replacement.as_str());
+
+                    if new_value == value {
+                        return Ok(());
+                    }
+
+                    let new_value = new_value.into_owned();
+                    element.set_attribute(self.name.as_str(), new_value.as_str())?;
+
+                    Some(new_value)
+                }
+            };
+
+            if let (Some(unit_trace), Some(id)) = (self.unit_trace.clone(), &self.id) {
+                if let Some(value) = value.as_ref() {
+                    unit_trace.borrow_mut().add_value_computed_by_unit(id, value);
+                }
+
+                if let Some(target_hash) = &self.target_hash {
+                    unit_trace
+                        .borrow_mut()
+                        .override_unit_id_with_target(target_hash.as_str(), id.as_str());
+                } else {
+                    unit_trace.borrow_mut().add_unit_id(id.clone());
+                }
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl BodyAttribute {
+    pub fn new(
+        css_selector: String,
+        name: String,
+        action: AttributeAction,
+        id: Option<String>,
+        target_hash: Option<String>,
+        unit_trace: Option<Rc<RefCell<UnitTrace>>>,
+        variables: Arc<CaptureRegistry>,
+    ) -> BodyAttribute {
+        BodyAttribute {
+            css_selector,
+            name,
+            action,
+            id,
+            target_hash,
+            unit_trace,
+            variables,
+        }
+    }
+}
+
+impl BodyAttribute {
+    pub fn css_selector(&self) -> String {
+        self.css_selector.clone()
+    }
+}
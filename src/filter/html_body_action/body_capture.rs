@@ -7,7 +7,10 @@ use std::{
 
 use lol_html::{ElementContentHandlers, Settings, html_content::TextChunk};
 
-use crate::{api::VariableValue, marker::StaticOrDynamic};
+use crate::{
+    api::{VariableValue, escape_variables},
+    marker::StaticOrDynamic,
+};
 
 #[derive(Debug)]
 pub struct CaptureRegistry {
@@ -16,6 +19,15 @@ pub struct CaptureRegistry {
 
 impl CaptureRegistry {
     pub fn replace(&self, value: String) -> String {
+        StaticOrDynamic::replace(value, &self.values(), true)
+    }
+
+    /// Same as `replace`, with the values of the variables escaped
+    pub fn replace_escaped(&self, value: String, escape: impl Fn(&str) -> String) -> String {
+        StaticOrDynamic::replace(value, &escape_variables(&self.values(), escape), true)
+    }
+
+    fn values(&self) -> Vec<(String, VariableValue)> {
         let mut variables = self
             .variables
             .iter()
@@ -33,7 +45,7 @@ impl CaptureRegistry {
         // sort by length descending to replace longer keys first
         variables.sort_by_key(|(key_b, _)| std::cmp::Reverse(key_b.len()));
 
-        StaticOrDynamic::replace(value, &variables, true)
+        variables
     }
 
     pub fn set_variable(&self, name: String, value: String) {
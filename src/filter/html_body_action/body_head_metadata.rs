@@ -0,0 +1,250 @@
+use std::{borrow::Cow, cell::RefCell, rc::Rc, sync::Arc};
+
+use lol_html::{
+    ElementContentHandlers, Settings,
+    html_content::{ContentType, Element},
+};
+
+use crate::{action::UnitTrace, api::HTMLBodyFilterHeadMetadata, filter::html_body_action::body_capture::CaptureRegistry};
+
+/// Upsert metadata tags of the head: the first existing tag is updated, following duplicates
+/// are removed, and missing tags are inserted at the end of the head. Pages without a `head`
+/// element are left unchanged.
+#[derive(Debug)]
+pub struct BodyHeadMetadata {
+    tags: Vec<HeadTag>,
+    id: Option<String>,
+    target_hash: Option<String>,
+    unit_trace: Option<Rc<RefCell<UnitTrace>>>,
+    variables: Arc<CaptureRegistry>,
+}
+
+#[derive(Debug, Clone)]
+struct HeadTag {
+    // Css selector of the existing tag
+    selector: String,
+    kind: HeadTagKind,
+    value: String,
+}
+
+#[derive(Debug, Clone)]
+enum HeadTagKind {
+    Title,
+    // Meta with a name, or a property for Open Graph, and its value in `content`
+    Meta { attribute: &'static str, name: String },
+    // Link with a rel and maybe a hreflang, and its value in `href`
+    Link { rel: &'static str, hreflang: Option<String> },
+}
+
+impl BodyHeadMetadata {
+    pub fn new(
+        filter: HTMLBodyFilterHeadMetadata,
+        unit_trace: Option<Rc<RefCell<UnitTrace>>>,
+        variables: Arc<CaptureRegistry>,
+    ) -> BodyHeadMetadata {
+        let mut tags = Vec::new();
+
+        if let Some(title) = filter.title {
+            tags.push(HeadTag::new(HeadTagKind::Title, title));
+        }
+
+        if let Some(description) = filter.description {
+            tags.push(HeadTag::meta("name", "description", description));
+        }
+
+        if let Some(robots) = filter.robots {
+            tags.push(HeadTag::meta("name", "robots", robots));
+        }
+
+        if let Some(canonical) = filter.canonical {
+            tags.push(HeadTag::new(
+                HeadTagKind::Link {
+                    rel: "canonical",
+                    hreflang: None,
+                },
+                canonical,
+            ));
+        }
+
+        for alternate in filter.hreflang {
+            if !is_selector_value(&alternate.hreflang) {
+                tracing::warn!("invalid hreflang {} in head_metadata body filter", alternate.hreflang);
+                continue;
+            }
+
+            tags.push(HeadTag::new(
+                HeadTagKind::Link {
+                    rel: "alternate",
+                    hreflang: Some(alternate.hreflang),
+                },
+                alternate.href,
+            ));
+        }
+
+        for property in filter.open_graph {
+            let name = match property.property.strip_prefix("og:") {
+                Some(_) => property.property,
+                None => format!("og:{}", property.property),
+            };
+
+            if !is_selector_value(&name) {
+                tracing::warn!("invalid open graph property {name} in head_metadata body filter");
+                continue;
+            }
+
+            tags.push(HeadTag::meta("property", name.as_str(), property.content));
+        }
+
+        BodyHeadMetadata {
+            tags,
+            id: filter.id,
+            target_hash: filter.target_hash,
+            unit_trace,
+            variables,
+        }
+    }
+
+    pub fn into_handlers(self, settings: &mut Settings) {
+        if self.tags.is_empty() {
+            return;
+        }
+
+        // Whether each tag was found in the head
+        let found = Rc::new(RefCell::new(vec![false; self.tags.len()]));
+
+        for (index, tag) in self.tags.iter().enumerate() {
+            let Ok(selector) = tag.selector.parse() else {
+                tracing::error!("failed to parse CSS selector: {}", tag.selector);
+                continue;
+            };
+
+            let tag = tag.clone();
+            let found = found.clone();
+            let variables = self.variables.clone();
+
+            settings.element_content_handlers.push((
+                Cow::Owned(selector),
+                ElementContentHandlers::default().element(move |element: &mut Element| {
+                    if std::mem::replace(&mut found.borrow_mut()[index], true) {
+                        element.remove();
+
+                        return Ok(());
+                    }
+
+                    let value = variables.replace(tag.value.clone());
+
+                    match tag.kind {
+                        HeadTagKind::Title => element.set_inner_content(value.as_str(), ContentType::Text),
+                        HeadTagKind::Meta { .. } => element.set_attribute("content", escape_attribute(&value).as_str())?,
+                        HeadTagKind::Link { .. } => element.set_attribute("href", escape_attribute(&value).as_str())?,
+                    }
+
+                    Ok(())
+                }),
+            ));
+        }
+
+        let Ok(head_selector) = "head".parse() else {
+            return;
+        };
+
+        settings.element_content_handlers.push((
+            Cow::Owned(head_selector),
+            ElementContentHandlers::default().element(move |element: &mut Element| {
+                let tags = self.tags.clone();
+                let found = found.clone();
+                let variables = self.variables.clone();
+                let id = self.id.clone();
+                let target_hash = self.target_hash.clone();
+                let unit_trace = self.unit_trace.clone();
+
+                if let Some(handlers) = element.end_tag_handlers() {
+                    handlers.push(Box::new(move |end| {
+                        let mut metadata = String::new();
+
+                        for (tag, found) in tags.iter().zip(found.borrow().iter()) {
+                            let html = tag.to_html(variables.replace(tag.value.clone()).as_str());
+
+                            if !found {
+                                end.before(html.as_str(), ContentType::Html);
+                            }
+
+                            metadata.push_str(html.as_str());
+                        }
+
+                        if let (Some(unit_trace), Some(id)) = (unit_trace, &id) {
+                            unit_trace.borrow_mut().add_value_computed_by_unit(id, &metadata);
+
+                            if let Some(target_hash) = &target_hash {
+                                unit_trace
+                                    .borrow_mut()
+                                    .override_unit_id_with_target(target_hash.as_str(), id.as_str());
+                            } else {
+                                unit_trace.borrow_mut().add_unit_id(id.clone());
+                            }
+                        }
+
+                        Ok(())
+                    }));
+                }
+
+                Ok(())
+            }),
+        ));
+    }
+}
+
+impl HeadTag {
+    fn new(kind: HeadTagKind, value: String) -> Self {
+        // Values of these attributes are case insensitive, `rel="Canonical"` is the canonical link
+        let selector = match &kind {
+            HeadTagKind::Title => "head title".to_string(),
+            HeadTagKind::Meta { attribute, name } => format!("head meta[{attribute}=\"{name}\" i]"),
+            HeadTagKind::Link { rel, hreflang: None } => format!("head link[rel=\"{rel}\" i]"),
+            HeadTagKind::Link {
+                rel,
+                hreflang: Some(hreflang),
+            } => format!("head link[rel=\"{rel}\" i][hreflang=\"{hreflang}\" i]"),
+        };
+
+        Self { selector, kind, value }
+    }
+
+    fn meta(attribute: &'static str, name: &str, value: String) -> Self {
+        Self::new(
+            HeadTagKind::Meta {
+                attribute,
+                name: name.to_string(),
+            },
+            value,
+        )
+    }
+
+    fn to_html(&self, value: &str) -> String {
+        match &self.kind {
+            HeadTagKind::Title => format!("<title>{}</title>", escape_text(value)),
+            HeadTagKind::Meta { attribute, name } => format!("<meta {attribute}=\"{name}\" content=\"{}\">", escape_attribute(value)),
+            HeadTagKind::Link { rel, hreflang: None } => format!("<link rel=\"{rel}\" href=\"{}\">", escape_attribute(value)),
+            HeadTagKind::Link {
+                rel,
+                hreflang: Some(hreflang),
+            } => format!("<link rel=\"{rel}\" hreflang=\"{hreflang}\" href=\"{}\">", escape_attribute(value)),
+        }
+    }
+}
+
+// Whether the value can be quoted in a css selector and an attribute as is
+fn is_selector_value(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric() || matches!(character, '-' | '_' | ':' | '.'))
+}
+
+fn escape_text(value: &str) -> String {
+    value.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+fn escape_attribute(value: &str) -> String {
+    value.replace('&', "&amp;").replace('"', "&quot;")
+}
@@ -0,0 +1,76 @@
+use std::{borrow::Cow, cell::RefCell, rc::Rc};
+
+use lol_html::{ElementContentHandlers, Settings, html_content::Element};
+
+use crate::{action::UnitTrace, filter::link_redirector::LinkRedirector};
+
+#[derive(Debug)]
+pub struct BodyLinks {
+    // Css selector of the elements, with the attribute holding the link
+    elements: Vec<(&'static str, &'static str)>,
+    links: Rc<RefCell<LinkRedirector>>,
+    id: Option<String>,
+    target_hash: Option<String>,
+    unit_trace: Option<Rc<RefCell<UnitTrace>>>,
+}
+
+impl BodyLinks {
+    pub fn new(
+        elements: Vec<(&'static str, &'static str)>,
+        links: Rc<RefCell<LinkRedirector>>,
+        id: Option<String>,
+        target_hash: Option<String>,
+        unit_trace: Option<Rc<RefCell<UnitTrace>>>,
+    ) -> BodyLinks {
+        BodyLinks {
+            elements,
+            links,
+            id,
+            target_hash,
+            unit_trace,
+        }
+    }
+
+    pub fn into_handlers(self, settings: &mut Settings) {
+        for (selector, attribute) in self.elements {
+            let Ok(css_selector) = selector.parse() else {
+                continue;
+            };
+
+            let links = self.links.clone();
+            let id = self.id.clone();
+            let target_hash = self.target_hash.clone();
+            let unit_trace = self.unit_trace.clone();
+
+            settings.element_content_handlers.push((
+                Cow::Owned(css_selector),
+                ElementContentHandlers::default().element(move |element: &mut Element| {
+                    let Some(link) = element.get_attribute(attribute) else {
+                        return Ok(());
+                    };
+
+                    // Attribute values may have entities, `&` in queries being the only common one
+                    let Some(target) = links.borrow_mut().redirect(&link.replace("&amp;", "&")) else {
+                        return Ok(());
+                    };
+
+                    element.set_attribute(attribute, target.replace('&', "&amp;").as_str())?;
+
+                    if let (Some(unit_trace), Some(id)) = (unit_trace.as_ref(), id.as_ref()) {
+                        unit_trace.borrow_mut().add_value_computed_by_unit(id, &target);
+
+                        if let Some(target_hash) = &target_hash {
+                            unit_trace
+                                .borrow_mut()
+                                .override_unit_id_with_target(target_hash.as_str(), id.as_str());
+                        } else {
+                            unit_trace.borrow_mut().add_unit_id(id.clone());
+                        }
+                    }
+
+                    Ok(())
+                }),
+            ));
+        }
+    }
+}
@@ -1,7 +1,10 @@
 pub mod body_after;
 pub mod body_append;
+pub mod body_attribute;
 pub mod body_before;
 pub mod body_capture;
+pub mod body_head_metadata;
+pub mod body_links;
 pub mod body_prepend;
 pub mod body_remove;
 pub mod body_replace;
@@ -13,14 +16,20 @@ use lol_html::{ElementContentHandlers, Settings};
 use crate::{
     action::UnitTrace,
     api::HTMLBodyFilter,
-    filter::html_body_action::{
-        body_after::BodyAfter,
-        body_append::BodyAppend,
-        body_before::BodyBefore,
-        body_capture::{BodyCapture, CaptureRegistry},
-        body_prepend::BodyPrepend,
-        body_remove::BodyRemove,
-        body_replace::BodyReplace,
+    filter::{
+        html_body_action::{
+            body_after::BodyAfter,
+            body_append::BodyAppend,
+            body_attribute::{AttributeAction, BodyAttribute},
+            body_before::BodyBefore,
+            body_capture::{BodyCapture, CaptureRegistry},
+            body_head_metadata::BodyHeadMetadata,
+            body_links::BodyLinks,
+            body_prepend::BodyPrepend,
+            body_remove::BodyRemove,
+            body_replace::BodyReplace,
+        },
+        link_redirector::LinkRedirector,
     },
 };
 
@@ -33,6 +42,9 @@ pub enum HtmlBodyVisitor {
     Remove(BodyRemove),
     After(BodyAfter),
     Before(BodyBefore),
+    Attribute(BodyAttribute),
+    Links(BodyLinks),
+    HeadMetadata(BodyHeadMetadata),
 }
 
 impl HtmlBodyVisitor {
@@ -40,6 +52,7 @@ impl HtmlBodyVisitor {
         filter: HTMLBodyFilter,
         unit_trace: Option<Rc<RefCell<UnitTrace>>>,
         variables: Arc<CaptureRegistry>,
+        links: Option<Rc<RefCell<LinkRedirector>>>,
     ) -> Option<HtmlBodyVisitor> {
         match filter {
             HTMLBodyFilter::PrependLegacy(filter) => {
@@ -142,6 +155,76 @@ impl HtmlBodyVisitor {
                 filter.target_hash,
                 unit_trace,
             ))),
+            HTMLBodyFilter::SetAttribute(filter) => Some(HtmlBodyVisitor::Attribute(BodyAttribute::new(
+                filter.css_selector,
+                filter.attribute,
+                AttributeAction::Set(filter.value),
+                filter.id,
+                filter.target_hash,
+                unit_trace,
+                variables.clone(),
+            ))),
+            HTMLBodyFilter::RemoveAttribute(filter) => Some(HtmlBodyVisitor::Attribute(BodyAttribute::new(
+                filter.css_selector,
+                filter.attribute,
+                AttributeAction::Remove,
+                filter.id,
+                filter.target_hash,
+                unit_trace,
+                variables.clone(),
+            ))),
+            HTMLBodyFilter::ReplaceAttribute(filter) => {
+                let regex = match regex::Regex::new(filter.pattern.as_deref().unwrap_or_default()) {
+                    Ok(regex) => regex,
+                    Err(err) => {
+                        tracing::error!("invalid regex in replace_attribute body filter: {err}");
+
+                        return None;
+                    }
+                };
+
+                Some(HtmlBodyVisitor::Attribute(BodyAttribute::new(
+                    filter.css_selector,
+                    filter.attribute,
+                    AttributeAction::Replace(regex, filter.value),
+                    filter.id,
+                    filter.target_hash,
+                    unit_trace,
+                    variables.clone(),
+                )))
+            }
+            HTMLBodyFilter::RewriteLinks(filter) => {
+                let Some(links) = links else {
+                    tracing::warn!("rewrite_links body filter ignored, no router is set on the action");
+
+                    return None;
+                };
+
+                let mut elements = vec![("a[href]", "href")];
+
+                if filter.link {
+                    elements.push(("link[href]", "href"));
+                }
+
+                if filter.img {
+                    elements.push(("img[src]", "src"));
+                }
+
+                if filter.form {
+                    elements.push(("form[action]", "action"));
+                }
+
+                Some(HtmlBodyVisitor::Links(BodyLinks::new(
+                    elements,
+                    links,
+                    filter.id,
+                    filter.target_hash,
+                    unit_trace,
+                )))
+            }
+            HTMLBodyFilter::HeadMetadata(filter) => {
+                Some(HtmlBodyVisitor::HeadMetadata(BodyHeadMetadata::new(filter, unit_trace, variables)))
+            }
         }
     }
 
@@ -169,6 +252,12 @@ impl HtmlBodyVisitor {
             HtmlBodyVisitor::Capture(capture) => {
                 capture.into_handlers(settings);
             }
+            HtmlBodyVisitor::Links(links) => {
+                links.into_handlers(settings);
+            }
+            HtmlBodyVisitor::HeadMetadata(head_metadata) => {
+                head_metadata.into_handlers(settings);
+            }
             HtmlBodyVisitor::Remove(remove) => {
                 let Ok(selector) = remove.css_selector().parse() else {
                     return;
@@ -196,6 +285,15 @@ impl HtmlBodyVisitor {
                     .element_content_handlers
                     .push((Cow::Owned(selector), ElementContentHandlers::default().element(before)));
             }
+            HtmlBodyVisitor::Attribute(attribute) => {
+                let Ok(selector) = attribute.css_selector().parse() else {
+                    return;
+                };
+
+                settings
+                    .element_content_handlers
+                    .push((Cow::Owned(selector), ElementContentHandlers::default().element(attribute)));
+            }
         }
     }
 }
@@ -0,0 +1,638 @@
+use std::{cell::RefCell, rc::Rc};
+
+use super::json_value::JsonValue as Value;
+use crate::{action::UnitTrace, api::JSONOperation};
+
+/// Bytes buffered when the rule does not set a maximum size
+pub const DEFAULT_MAX_SIZE: usize = 2 * 1024 * 1024;
+
+/// Edit a JSON body, which is buffered until its end as long as it stays under `max_size`
+///
+/// A body larger than `max_size`, or which is not valid JSON, is sent unchanged.
+#[derive(Debug)]
+pub struct JsonFilterBodyAction {
+    id: Option<String>,
+    operations: Vec<JsonEdit>,
+    max_size: usize,
+    buffer: Vec<u8>,
+    overflowed: bool,
+    unit_trace: Option<Rc<RefCell<UnitTrace>>>,
+}
+
+#[derive(Debug)]
+struct JsonEdit {
+    kind: JsonEditKind,
+    path: String,
+    segments: Vec<Segment>,
+    value: Value,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum JsonEditKind {
+    Set,
+    Remove,
+    Append,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    /// Key of an object, or index of an array when numeric (JSON Pointer tokens)
+    Key(String),
+    /// Index of an array, from its end when negative
+    Index(i64),
+    /// All children of an object or an array
+    Wildcard,
+    /// Past the last element of an array (`-` in a JSON Pointer)
+    End,
+    /// The segment applied to the node and all its descendants (`..` in a JSONPath)
+    Descendant(Box<Segment>),
+}
+
+#[derive(Debug, Clone)]
+enum Step {
+    Key(String),
+    Index(usize),
+}
+
+impl JsonEditKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            JsonEditKind::Set => "set",
+            JsonEditKind::Remove => "remove",
+            JsonEditKind::Append => "append",
+        }
+    }
+}
+
+impl JsonFilterBodyAction {
+    pub fn new(
+        id: Option<String>,
+        operations: Vec<JSONOperation>,
+        max_size: Option<usize>,
+        unit_trace: Option<Rc<RefCell<UnitTrace>>>,
+    ) -> Option<Self> {
+        let mut edits = Vec::new();
+
+        for operation in operations {
+            let (kind, path, value) = match operation {
+                JSONOperation::Set { path, value } => (JsonEditKind::Set, path, value.into()),
+                JSONOperation::Remove { path } => (JsonEditKind::Remove, path, Value::Null),
+                JSONOperation::Append { path, value } => (JsonEditKind::Append, path, value.into()),
+            };
+
+            match parse_path(path.as_str()) {
+                Ok(segments) => edits.push(JsonEdit {
+                    kind,
+                    path,
+                    segments,
+                    value,
+                }),
+                Err(err) => tracing::error!("invalid path {path} in edit_json body filter: {err}"),
+            }
+        }
+
+        if edits.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            id,
+            operations: edits,
+            max_size: max_size.unwrap_or(DEFAULT_MAX_SIZE),
+            buffer: Vec::new(),
+            overflowed: false,
+            unit_trace,
+        })
+    }
+
+    pub fn filter(&mut self, data: Vec<u8>) -> Vec<u8> {
+        if self.overflowed {
+            return data;
+        }
+
+        if self.buffer.len() + data.len() > self.max_size {
+            tracing::warn!("json body is larger than {} bytes, it will not be edited", self.max_size);
+            self.overflowed = true;
+
+            let mut buffer = std::mem::take(&mut self.buffer);
+            buffer.extend(data);
+
+            return buffer;
+        }
+
+        self.buffer.extend(data);
+
+        Vec::new()
+    }
+
+    pub fn end(mut self) -> Vec<u8> {
+        if self.overflowed {
+            return Vec::new();
+        }
+
+        let buffer = std::mem::take(&mut self.buffer);
+        let mut document = match serde_json::from_slice::<Value>(&buffer) {
+            Ok(document) => document,
+            Err(err) => {
+                tracing::warn!("body is not valid json, it will not be edited: {err}");
+
+                return buffer;
+            }
+        };
+
+        let mut edited = false;
+
+        for edit in &self.operations {
+            let count = edit.apply(&mut document);
+
+            if let Some(trace) = self.unit_trace.as_ref() {
+                trace
+                    .borrow_mut()
+                    .add_json_operation(self.id.as_deref(), edit.kind.as_str(), edit.path.as_str(), count);
+            }
+
+            edited = edited || count > 0;
+        }
+
+        if !edited {
+            return buffer;
+        }
+
+        if let Some(trace) = self.unit_trace.take()
+            && let Some(id) = self.id.as_deref()
+        {
+            trace.borrow_mut().add_unit_id_with_target("json", id);
+        }
+
+        match serde_json::to_vec(&document) {
+            Ok(body) => body,
+            Err(err) => {
+                tracing::error!("unable to serialize edited json body: {err}");
+
+                buffer
+            }
+        }
+    }
+}
+
+impl JsonEdit {
+    /// Apply the edit, returns the number of locations changed
+    fn apply(&self, document: &mut Value) -> usize {
+        let Some((last, parents)) = self.segments.split_last() else {
+            // The whole document
+            return match self.kind {
+                JsonEditKind::Set => {
+                    *document = self.value.clone();
+                    1
+                }
+                JsonEditKind::Remove => 0,
+                JsonEditKind::Append => append(document, &self.value) as usize,
+            };
+        };
+
+        let mut paths = Vec::new();
+
+        let locations = match last {
+            Segment::Descendant(_) => {
+                // Only existing locations are matched by a descendant segment, edit them from their parent
+                select(document, &self.segments, Vec::new(), &mut paths);
+
+                paths
+                    .into_iter()
+                    .filter_map(|mut path| {
+                        let segment = match path.pop()? {
+                            Step::Key(key) => Segment::Key(key),
+                            Step::Index(index) => Segment::Index(index as i64),
+                        };
+
+                        Some((path, segment))
+                    })
+                    .collect::<Vec<_>>()
+            }
+            last => {
+                select(document, parents, Vec::new(), &mut paths);
+
+                paths.into_iter().map(|path| (path, last.clone())).collect()
+            }
+        };
+
+        let mut count = 0;
+
+        // Later locations first, so removing an element does not shift the ones still to edit
+        for (path, segment) in locations.iter().rev() {
+            if let Some(parent) = resolve_mut(document, path) {
+                count += self.apply_on_parent(parent, segment);
+            }
+        }
+
+        count
+    }
+
+    fn apply_on_parent(&self, parent: &mut Value, segment: &Segment) -> usize {
+        match (self.kind, segment, parent) {
+            (_, Segment::Descendant(_), _) => 0,
+            (JsonEditKind::Set, Segment::Key(key), Value::Object(map)) => {
+                map.insert(key.clone(), self.value.clone());
+                1
+            }
+            (JsonEditKind::Remove, Segment::Key(key), Value::Object(map)) => map.shift_remove(key).is_some() as usize,
+            (JsonEditKind::Append, Segment::Key(key), Value::Object(map)) => match map.get_mut(key) {
+                Some(target) => append(target, &self.value) as usize,
+                None => {
+                    map.insert(key.clone(), Value::Array(vec![self.value.clone()]));
+                    1
+                }
+            },
+            (kind, Segment::Key(key), Value::Array(values)) => match key.parse::<usize>() {
+                Ok(index) => self.apply_on_index(kind, values, Some(index)),
+                Err(_) => 0,
+            },
+            (kind, Segment::Index(index), Value::Array(values)) => {
+                let index = resolve_index(*index, values.len());
+                self.apply_on_index(kind, values, index)
+            }
+            (JsonEditKind::Set | JsonEditKind::Append, Segment::End, Value::Array(values)) => {
+                values.push(self.value.clone());
+                1
+            }
+            (JsonEditKind::Remove, Segment::Wildcard, Value::Object(map)) => {
+                let count = map.len();
+                map.clear();
+                count
+            }
+            (JsonEditKind::Remove, Segment::Wildcard, Value::Array(values)) => {
+                let count = values.len();
+                values.clear();
+                count
+            }
+            (kind, Segment::Wildcard, Value::Object(map)) => map.values_mut().map(|target| self.apply_on_value(kind, target)).sum(),
+            (kind, Segment::Wildcard, Value::Array(values)) => values.iter_mut().map(|target| self.apply_on_value(kind, target)).sum(),
+            _ => 0,
+        }
+    }
+
+    fn apply_on_index(&self, kind: JsonEditKind, values: &mut Vec<Value>, index: Option<usize>) -> usize {
+        match index {
+            Some(index) if index < values.len() => match kind {
+                JsonEditKind::Remove => {
+                    values.remove(index);
+                    1
+                }
+                kind => self.apply_on_value(kind, &mut values[index]),
+            },
+            _ => 0,
+        }
+    }
+
+    fn apply_on_value(&self, kind: JsonEditKind, target: &mut Value) -> usize {
+        match kind {
+            JsonEditKind::Set => {
+                *target = self.value.clone();
+                1
+            }
+            JsonEditKind::Append => append(target, &self.value) as usize,
+            JsonEditKind::Remove => 0,
+        }
+    }
+}
+
+/// Append to an array, or concatenate to a string
+fn append(target: &mut Value, value: &Value) -> bool {
+    match (target, value) {
+        (Value::Array(values), value) => {
+            values.push(value.clone());
+            true
+        }
+        (Value::String(str), Value::String(value)) => {
+            str.push_str(value);
+            true
+        }
+        _ => false,
+    }
+}
+
+fn resolve_index(index: i64, len: usize) -> Option<usize> {
+    if index < 0 {
+        len.checked_sub(index.unsigned_abs() as usize)
+    } else {
+        Some(index as usize)
+    }
+}
+
+/// Collect the concrete locations matched by the segments
+fn select(value: &Value, segments: &[Segment], prefix: Vec<Step>, paths: &mut Vec<Vec<Step>>) {
+    let Some((segment, rest)) = segments.split_first() else {
+        paths.push(prefix);
+        return;
+    };
+
+    let with = |step: Step| {
+        let mut path = prefix.clone();
+        path.push(step);
+        path
+    };
+
+    match (segment, value) {
+        (Segment::Key(key), Value::Object(map)) => {
+            if let Some(child) = map.get(key) {
+                select(child, rest, with(Step::Key(key.clone())), paths);
+            }
+        }
+        (Segment::Key(key), Value::Array(values)) => {
+            if let Some(index) = key.parse::<usize>().ok().filter(|index| *index < values.len()) {
+                select(&values[index], rest, with(Step::Index(index)), paths);
+            }
+        }
+        (Segment::Index(index), Value::Array(values)) => {
+            if let Some(index) = resolve_index(*index, values.len()).filter(|index| *index < values.len()) {
+                select(&values[index], rest, with(Step::Index(index)), paths);
+            }
+        }
+        (Segment::Wildcard, Value::Object(map)) => {
+            for (key, child) in map {
+                select(child, rest, with(Step::Key(key.clone())), paths);
+            }
+        }
+        (Segment::Wildcard, Value::Array(values)) => {
+            for (index, child) in values.iter().enumerate() {
+                select(child, rest, with(Step::Index(index)), paths);
+            }
+        }
+        (Segment::Descendant(inner), value) => {
+            let mut nodes = Vec::new();
+            descendants(value, prefix.clone(), &mut nodes);
+
+            let mut segments = vec![inner.as_ref().clone()];
+            segments.extend_from_slice(rest);
+
+            for node in nodes {
+                if let Some(node_value) = resolve(value, &node[prefix.len()..]) {
+                    select(node_value, &segments, node, paths);
+                }
+            }
+        }
+        _ => (),
+    }
+}
+
+fn descendants(value: &Value, prefix: Vec<Step>, nodes: &mut Vec<Vec<Step>>) {
+    nodes.push(prefix.clone());
+
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let mut path = prefix.clone();
+                path.push(Step::Key(key.clone()));
+                descendants(child, path, nodes);
+            }
+        }
+        Value::Array(values) => {
+            for (index, child) in values.iter().enumerate() {
+                let mut path = prefix.clone();
+                path.push(Step::Index(index));
+                descendants(child, path, nodes);
+            }
+        }
+        _ => (),
+    }
+}
+
+fn resolve<'a>(mut value: &'a Value, path: &[Step]) -> Option<&'a Value> {
+    for step in path {
+        value = match step {
+            Step::Key(key) => value.get(key.as_str())?,
+            Step::Index(index) => value.get_index(*index)?,
+        };
+    }
+
+    Some(value)
+}
+
+fn resolve_mut<'a>(mut value: &'a mut Value, path: &[Step]) -> Option<&'a mut Value> {
+    for step in path {
+        value = match step {
+            Step::Key(key) => value.get_mut(key.as_str())?,
+            Step::Index(index) => value.get_index_mut(*index)?,
+        };
+    }
+
+    Some(value)
+}
+
+/// Parse a JSON Pointer (RFC 6901) or a JSONPath made of `.name`, `['name']`, `[0]`, `[-1]`,
+/// `*` and `..` segments
+fn parse_path(path: &str) -> Result<Vec<Segment>, String> {
+    if path.starts_with('$') {
+        return parse_json_path(path);
+    }
+
+    if path.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let Some(pointer) = path.strip_prefix('/') else {
+        return Err("a path must be a json pointer starting with / or a jsonpath starting with $".to_string());
+    };
+
+    Ok(pointer
+        .split('/')
+        .map(|token| match token {
+            "-" => Segment::End,
+            token => Segment::Key(token.replace("~1", "/").replace("~0", "~")),
+        })
+        .collect())
+}
+
+fn parse_json_path(path: &str) -> Result<Vec<Segment>, String> {
+    let chars = path.chars().collect::<Vec<_>>();
+    let mut segments = Vec::new();
+    let mut position = 1;
+
+    while position < chars.len() {
+        let descendant = chars[position] == '.' && chars.get(position + 1) == Some(&'.');
+
+        let segment = match chars[position] {
+            '.' => {
+                position += if descendant { 2 } else { 1 };
+
+                if chars.get(position) == Some(&'[') {
+                    parse_bracket(&chars, &mut position)?
+                } else if chars.get(position) == Some(&'*') {
+                    position += 1;
+                    Segment::Wildcard
+                } else {
+                    let start = position;
+
+                    while position < chars.len() && chars[position] != '.' && chars[position] != '[' {
+                        position += 1;
+                    }
+
+                    if start == position {
+                        return Err(format!("empty name at {start}"));
+                    }
+
+                    Segment::Key(chars[start..position].iter().collect())
+                }
+            }
+            '[' => parse_bracket(&chars, &mut position)?,
+            char => return Err(format!("unexpected {char} at {position}")),
+        };
+
+        segments.push(if descendant {
+            Segment::Descendant(Box::new(segment))
+        } else {
+            segment
+        });
+    }
+
+    Ok(segments)
+}
+
+fn parse_bracket(chars: &[char], position: &mut usize) -> Result<Segment, String> {
+    let start = *position + 1;
+    let Some(length) = chars[start..].iter().position(|char| *char == ']') else {
+        return Err(format!("unclosed [ at {}", *position));
+    };
+    let inner = chars[start..start + length].iter().collect::<String>();
+    let inner = inner.trim();
+    *position = start + length + 1;
+
+    if inner == "*" {
+        return Ok(Segment::Wildcard);
+    }
+
+    for quote in ['\'', '"'] {
+        if let Some(name) = inner.strip_prefix(quote).and_then(|inner| inner.strip_suffix(quote)) {
+            return Ok(Segment::Key(name.to_string()));
+        }
+    }
+
+    inner
+        .parse::<i64>()
+        .map(Segment::Index)
+        .map_err(|_| format!("unsupported selector [{inner}]"))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::{JsonFilterBodyAction, Segment, parse_path};
+    use crate::api::JSONOperation;
+
+    fn edit(body: &str, operations: Vec<JSONOperation>) -> Value {
+        let mut filter = JsonFilterBodyAction::new(None, operations, None, None).unwrap();
+        let mut output = filter.filter(body.as_bytes()[..body.len() / 2].to_vec());
+        output.extend(filter.filter(body.as_bytes()[body.len() / 2..].to_vec()));
+        output.extend(filter.end());
+
+        serde_json::from_slice(&output).unwrap()
+    }
+
+    #[test]
+    fn parses_json_pointers_and_json_paths() {
+        assert_eq!(
+            parse_path("/a~1b/0/-").unwrap(),
+            vec![Segment::Key("a/b".to_string()), Segment::Key("0".to_string()), Segment::End]
+        );
+        assert_eq!(
+            parse_path("$.items[*]['href']").unwrap(),
+            vec![
+                Segment::Key("items".to_string()),
+                Segment::Wildcard,
+                Segment::Key("href".to_string())
+            ]
+        );
+        assert_eq!(
+            parse_path("$..id[-1]").unwrap(),
+            vec![Segment::Descendant(Box::new(Segment::Key("id".to_string()))), Segment::Index(-1)]
+        );
+        assert!(parse_path("links").is_err());
+    }
+
+    #[test]
+    fn sets_removes_and_appends() {
+        let body = r#"{"links":{"self":"http://old/1","next":"http://old/2"},"internal":true,"tags":["a"]}"#;
+        let output = edit(
+            body,
+            vec![
+                JSONOperation::Set {
+                    path: "/links/self".to_string(),
+                    value: json!("https://new/1"),
+                },
+                JSONOperation::Remove {
+                    path: "$.internal".to_string(),
+                },
+                JSONOperation::Append {
+                    path: "/tags".to_string(),
+                    value: json!("b"),
+                },
+                JSONOperation::Set {
+                    path: "/source".to_string(),
+                    value: json!({"name": "proxy"}),
+                },
+            ],
+        );
+
+        assert_eq!(
+            output,
+            json!({"links":{"self":"https://new/1","next":"http://old/2"},"tags":["a","b"],"source":{"name":"proxy"}})
+        );
+    }
+
+    #[test]
+    fn edits_every_location_matched_by_a_json_path() {
+        let body = r#"{"items":[{"id":1,"secret":"x"},{"id":2,"secret":"y"},{"id":3}],"meta":{"id":4}}"#;
+        let output = edit(
+            body,
+            vec![
+                JSONOperation::Remove {
+                    path: "$.items[*].secret".to_string(),
+                },
+                JSONOperation::Set {
+                    path: "$..id".to_string(),
+                    value: json!(0),
+                },
+                JSONOperation::Remove {
+                    path: "$.items[-1]".to_string(),
+                },
+            ],
+        );
+
+        assert_eq!(output, json!({"items":[{"id":0},{"id":0}],"meta":{"id":0}}));
+    }
+
+    #[test]
+    fn keeps_the_order_of_keys() {
+        let mut filter = JsonFilterBodyAction::new(
+            None,
+            vec![JSONOperation::Set {
+                path: "/b".to_string(),
+                value: json!(0),
+            }],
+            None,
+            None,
+        )
+        .unwrap();
+        let mut output = filter.filter(br#"{"z":1,"b":2,"a":3}"#.to_vec());
+        output.extend(filter.end());
+
+        assert_eq!(output, br#"{"z":1,"b":0,"a":3}"#);
+    }
+
+    #[test]
+    fn large_or_invalid_bodies_are_sent_unchanged() {
+        let operations = || vec![JSONOperation::Remove { path: "/a".to_string() }];
+
+        let mut filter = JsonFilterBodyAction::new(None, operations(), Some(8), None).unwrap();
+        let mut output = filter.filter(br#"{"a":1,"#.to_vec());
+        output.extend(filter.filter(br#""b":2}"#.to_vec()));
+        output.extend(filter.end());
+        assert_eq!(output, br#"{"a":1,"b":2}"#);
+
+        let mut filter = JsonFilterBodyAction::new(None, operations(), None, None).unwrap();
+        let mut output = filter.filter(b"not json".to_vec());
+        output.extend(filter.end());
+        assert_eq!(output, b"not json");
+    }
+}
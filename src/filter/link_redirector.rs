@@ -0,0 +1,337 @@
+#[cfg(feature = "router")]
+use std::{cell::RefCell, collections::HashMap, rc::Rc, sync::Arc};
+
+#[cfg(feature = "router")]
+use url::{Position, Url};
+
+#[cfg(feature = "router")]
+use crate::{
+    action::{Action, UnitTrace},
+    api::Rule,
+    http::Request,
+    router::Router,
+};
+
+/// Links looked up in the router for a response when no limit is given
+pub const DEFAULT_MAX_LINK_LOOKUPS: usize = 500;
+
+// Redirects followed from a link to its final target
+#[cfg(feature = "router")]
+const MAX_REDIRECT_HOPS: usize = 5;
+
+#[cfg(feature = "router")]
+const REDIRECTION_CODES: [u16; 4] = [301, 302, 307, 308];
+
+/// Router where the links of the body are looked up, set on the action by the proxy
+#[cfg(feature = "router")]
+#[derive(Debug, Clone)]
+pub struct LinkRouter {
+    router: Arc<Router<Rule>>,
+    // Url of the request, relative links are resolved against it
+    base: Option<Url>,
+    max_lookups: usize,
+}
+
+#[cfg(feature = "router")]
+impl LinkRouter {
+    pub fn new(router: Arc<Router<Rule>>, request_url: Option<&str>, max_lookups: Option<usize>) -> Self {
+        Self {
+            router,
+            base: request_url.and_then(|url| Url::parse(url).ok()),
+            max_lookups: max_lookups.unwrap_or(DEFAULT_MAX_LINK_LOOKUPS),
+        }
+    }
+}
+
+/// Redirects of the links found in a response body, looked up in the router
+///
+/// One is created for each filtered response and shared by its filters: lookups are cached,
+/// and at most `max_lookups` distinct links are looked up, the following ones are left
+/// unchanged.
+#[cfg(feature = "router")]
+#[derive(Debug)]
+pub struct LinkRedirector {
+    router: LinkRouter,
+    cache: HashMap<String, Option<String>>,
+    unit_trace: Option<Rc<RefCell<UnitTrace>>>,
+}
+
+/// Without the router, links are never redirected
+#[cfg(not(feature = "router"))]
+#[derive(Debug)]
+pub struct LinkRedirector;
+
+// Link resolved to what can be matched by the router
+#[cfg(feature = "router")]
+enum ResolvedLink {
+    Url(Url),
+    // Path and query, when there is no url to resolve a relative link against
+    Path(String),
+}
+
+#[cfg(not(feature = "router"))]
+impl LinkRedirector {
+    pub fn redirect(&mut self, _link: &str) -> Option<String> {
+        None
+    }
+}
+
+#[cfg(feature = "router")]
+impl LinkRedirector {
+    pub fn new(router: LinkRouter, unit_trace: Option<Rc<RefCell<UnitTrace>>>) -> Self {
+        Self {
+            router,
+            cache: HashMap::new(),
+            unit_trace,
+        }
+    }
+
+    /// Final target of the redirects matching the link, if any
+    pub fn redirect(&mut self, link: &str) -> Option<String> {
+        if let Some(target) = self.cache.get(link) {
+            return target.clone();
+        }
+
+        if self.cache.len() >= self.router.max_lookups {
+            if let Some(trace) = self.unit_trace.as_ref() {
+                trace.borrow_mut().set_link_lookup_limit_reached();
+            }
+
+            return None;
+        }
+
+        let redirect = self.lookup(link);
+
+        if let (Some(trace), Some((target, rule_ids))) = (self.unit_trace.as_ref(), redirect.as_ref()) {
+            trace.borrow_mut().add_link_redirect(link, target, rule_ids.clone());
+        }
+
+        let target = redirect.map(|(target, _)| target);
+        self.cache.insert(link.to_string(), target.clone());
+
+        target
+    }
+
+    // Target and ids of the rules redirecting the link
+    fn lookup(&self, link: &str) -> Option<(String, Vec<String>)> {
+        // Fragments are never sent to the server
+        let (link, fragment) = match link.split_once('#') {
+            Some((link, fragment)) => (link, Some(fragment)),
+            None => (link, None),
+        };
+
+        let base = self.router.base.as_ref();
+        let mut current = resolve(link, base)?;
+        let mut rule_ids = Vec::new();
+        let mut seen = vec![request_key(&current)];
+
+        for _ in 0..MAX_REDIRECT_HOPS {
+            let Some((location, ids)) = self.match_redirect(&current) else {
+                break;
+            };
+            let current_url = match &current {
+                ResolvedLink::Url(url) => Some(url),
+                ResolvedLink::Path(_) => None,
+            };
+            let Some(next) = resolve(location.as_str(), current_url) else {
+                break;
+            };
+
+            rule_ids.extend(ids);
+            current = next;
+
+            // Redirection loop, stop at the first link seen again
+            let key = request_key(&current);
+
+            if seen.contains(&key) {
+                break;
+            }
+
+            seen.push(key);
+        }
+
+        // Redirected back to itself
+        if rule_ids.is_empty() || request_key(&current) == seen[0] {
+            return None;
+        }
+
+        let mut target = match current {
+            ResolvedLink::Path(path) => path,
+            // Relative links stay relative when the target is on the same origin
+            ResolvedLink::Url(url) if Url::parse(link).is_err() && base.is_some_and(|base| base.origin() == url.origin()) => {
+                url[Position::BeforePath..].to_string()
+            }
+            ResolvedLink::Url(url) => url.to_string(),
+        };
+
+        if let Some(fragment) = fragment
+            && !target.contains('#')
+        {
+            target.push('#');
+            target.push_str(fragment);
+        }
+
+        Some((target, rule_ids))
+    }
+
+    // Location of the redirect matching the link, with the ids of the applied rules
+    fn match_redirect(&self, link: &ResolvedLink) -> Option<(String, Vec<String>)> {
+        let router = &self.router.router;
+        let request = match link {
+            ResolvedLink::Url(url) => {
+                let host = match url.port() {
+                    Some(port) => format!("{}:{port}", url.host_str().unwrap_or_default()),
+                    None => url.host_str().unwrap_or_default().to_string(),
+                };
+
+                Request::from_config(
+                    &router.config,
+                    url[Position::BeforePath..Position::AfterQuery].to_string(),
+                    Some(host),
+                    Some(url.scheme().to_string()),
+                    Some("GET".to_string()),
+                    None,
+                    None,
+                )
+            }
+            ResolvedLink::Path(path) => Request::from_config(&router.config, path.clone(), None, None, Some("GET".to_string()), None, None),
+        };
+
+        let routes = router.match_request(&request);
+
+        if routes.is_empty() {
+            return None;
+        }
+
+        let mut action = Action::from_routes_rule(router, routes, &request, None);
+        let status_code = action.get_status_code(0, None);
+
+        if !REDIRECTION_CODES.contains(&status_code) {
+            return None;
+        }
+
+        let location = action
+            .filter_headers(Vec::new(), status_code, false, None)
+            .into_iter()
+            .find(|header| header.name.eq_ignore_ascii_case("location"))?
+            .value;
+
+        Some((location, action.get_applied_rule_ids_vec()))
+    }
+}
+
+#[cfg(feature = "router")]
+fn resolve(link: &str, base: Option<&Url>) -> Option<ResolvedLink> {
+    let link = link.trim();
+
+    match Url::parse(link) {
+        Ok(url) if url.has_host() && matches!(url.scheme(), "http" | "https") => Some(ResolvedLink::Url(url)),
+        // Other schemes, like mailto: or data:
+        Ok(_) => None,
+        Err(url::ParseError::RelativeUrlWithoutBase) => match base {
+            Some(base) => base.join(link).ok().map(ResolvedLink::Url),
+            None if link.starts_with('/') && !link.starts_with("//") => Some(ResolvedLink::Path(link.to_string())),
+            None => None,
+        },
+        Err(_) => None,
+    }
+}
+
+#[cfg(feature = "router")]
+fn request_key(link: &ResolvedLink) -> String {
+    match link {
+        ResolvedLink::Url(url) => url[..Position::AfterQuery].to_string(),
+        ResolvedLink::Path(path) => path.clone(),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "router")]
+mod tests {
+    use std::{cell::RefCell, rc::Rc, sync::Arc};
+
+    use super::{LinkRedirector, LinkRouter};
+    use crate::{action::UnitTrace, api::Rule, router::Router};
+
+    fn router() -> Arc<Router<Rule>> {
+        let mut router = Router::<Rule>::default();
+
+        for (id, path, target) in [
+            ("a", "/old", "/new"),
+            ("b", "/gone", "https://other.example.com/"),
+            ("c", "/first", "/old"),
+            ("d", "/loop-a", "/loop-b"),
+            ("e", "/loop-b", "/loop-a"),
+        ] {
+            router.insert(
+                serde_json::from_value::<Rule>(serde_json::json!({
+                    "id": id,
+                    "source": {"path": path},
+                    "target": target,
+                    "status_code": 301,
+                    "rank": 0,
+                }))
+                .unwrap(),
+            );
+        }
+
+        Arc::new(router)
+    }
+
+    #[test]
+    fn redirects_relative_and_absolute_links() {
+        let mut redirector = LinkRedirector::new(LinkRouter::new(router(), None, None), None);
+
+        assert_eq!(redirector.redirect("/old").as_deref(), Some("/new"));
+        assert_eq!(
+            redirector.redirect("https://www.example.com/old#top").as_deref(),
+            Some("https://www.example.com/new#top")
+        );
+        assert_eq!(redirector.redirect("/gone").as_deref(), Some("https://other.example.com/"));
+        assert_eq!(redirector.redirect("/page"), None);
+        assert_eq!(redirector.redirect("old"), None);
+        assert_eq!(redirector.redirect("mailto:contact@example.com"), None);
+    }
+
+    #[test]
+    fn resolves_links_against_the_request_url() {
+        let mut redirector = LinkRedirector::new(LinkRouter::new(router(), Some("https://www.example.com/blog/"), None), None);
+
+        assert_eq!(redirector.redirect("../old").as_deref(), Some("/new"));
+        assert_eq!(redirector.redirect("//www.example.com/old").as_deref(), Some("/new"));
+        assert_eq!(redirector.redirect("/gone").as_deref(), Some("https://other.example.com/"));
+        assert_eq!(
+            redirector.redirect("https://cdn.example.com/old").as_deref(),
+            Some("https://cdn.example.com/new")
+        );
+    }
+
+    #[test]
+    fn follows_redirects_to_the_final_target() {
+        let trace = Rc::new(RefCell::new(UnitTrace::default()));
+        let mut redirector = LinkRedirector::new(LinkRouter::new(router(), None, None), Some(trace.clone()));
+
+        assert_eq!(redirector.redirect("/first").as_deref(), Some("/new"));
+        assert_eq!(redirector.redirect("/loop-a"), None);
+
+        let trace = serde_json::to_value(&*trace.borrow()).unwrap();
+
+        assert_eq!(trace["link_redirects"][0]["link"], "/first");
+        assert_eq!(trace["link_redirects"][0]["rule_ids"], serde_json::json!(["c", "a"]));
+    }
+
+    #[test]
+    fn stops_looking_up_after_the_limit() {
+        let trace = Rc::new(RefCell::new(UnitTrace::default()));
+        let mut redirector = LinkRedirector::new(LinkRouter::new(router(), None, Some(1)), Some(trace.clone()));
+
+        assert_eq!(redirector.redirect("/old").as_deref(), Some("/new"));
+        assert_eq!(redirector.redirect("/old").as_deref(), Some("/new"));
+        assert_eq!(redirector.redirect("/gone"), None);
+
+        let trace = serde_json::to_value(&*trace.borrow()).unwrap();
+
+        assert_eq!(trace["link_redirects"][0]["target"], "/new");
+        assert_eq!(trace["link_lookup_limit_reached"], true);
+    }
+}
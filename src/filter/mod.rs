@@ -1,5 +1,6 @@
 pub mod buffer;
 mod buffer_filter_body;
+mod charset;
 #[cfg(feature = "compress")]
 mod encoding;
 mod error;
@@ -9,11 +10,20 @@ mod header_action;
 mod html_body_action;
 mod html_filter_body;
 mod html_to_markdown;
+mod json_filter_body;
+mod json_value;
+mod link_redirector;
 mod text_filter_body;
+mod text_regex_filter_body;
+mod xml_filter_body;
 
 pub use buffer::Buffer;
 #[cfg(feature = "compress")]
 pub use encoding::SupportedEncoding;
 pub use filter_body::FilterBodyAction;
+pub(crate) use filter_body::may_accept_text_splicing;
 pub use filter_header::FilterHeaderAction;
 pub use html_filter_body::HtmlFilterBodyAction;
+#[cfg(feature = "router")]
+pub use link_redirector::LinkRouter;
+pub use link_redirector::{DEFAULT_MAX_LINK_LOOKUPS, LinkRedirector};
@@ -0,0 +1,220 @@
+use std::{cell::RefCell, rc::Rc};
+
+use regex::bytes::Regex;
+
+use crate::action::UnitTrace;
+
+/// Bytes kept between two chunks when the rule does not set a lookbehind
+pub const DEFAULT_LOOKBEHIND: usize = 1024;
+
+// Bytes already sent kept in front of the pending data, so word boundaries and anchors see
+// the character before the first pending byte (one UTF-8 character at most)
+const CONTEXT_LEN: usize = 4;
+
+/// Search and replace a regex in a streamed body
+///
+/// The end of each chunk, up to `lookbehind` bytes, is held back until the next chunk, so a
+/// match split between two chunks is still replaced. A match longer than `lookbehind` is only
+/// guaranteed to be replaced when it is received in a single chunk.
+#[derive(Debug)]
+pub struct TextRegexFilterBodyAction {
+    id: Option<String>,
+    regex: Regex,
+    replacement: Vec<u8>,
+    lookbehind: usize,
+    // Data not sent yet, preceded by `context` bytes already sent
+    pending: Vec<u8>,
+    context: usize,
+    unit_trace: Option<Rc<RefCell<UnitTrace>>>,
+}
+
+impl TextRegexFilterBodyAction {
+    pub fn new(
+        id: Option<String>,
+        regex: Regex,
+        replacement: String,
+        lookbehind: Option<usize>,
+        unit_trace: Option<Rc<RefCell<UnitTrace>>>,
+    ) -> Self {
+        Self {
+            id,
+            regex,
+            replacement: replacement.into_bytes(),
+            lookbehind: lookbehind.unwrap_or(DEFAULT_LOOKBEHIND),
+            pending: Vec::new(),
+            context: 0,
+            unit_trace,
+        }
+    }
+
+    pub fn filter(&mut self, data: Vec<u8>) -> Vec<u8> {
+        self.pending.extend(data);
+        self.replace(false)
+    }
+
+    pub fn end(mut self) -> Vec<u8> {
+        self.replace(true)
+    }
+
+    fn replace(&mut self, last: bool) -> Vec<u8> {
+        let len = self.pending.len();
+        // Nothing before this offset may still be part of a match completed by a following chunk
+        let hold_from = if last {
+            len + 1
+        } else {
+            len.saturating_sub(self.lookbehind).max(self.context)
+        };
+        let mut output = Vec::new();
+        let mut sent = self.context;
+        let mut position = self.context;
+        let mut count = 0;
+
+        while position <= len {
+            let Some(captures) = self.regex.captures_at(&self.pending, position) else {
+                break;
+            };
+            let matched = captures.get(0).unwrap();
+
+            if matched.start() >= hold_from {
+                // The match may extend in the next chunk, hold it back
+                break;
+            }
+
+            output.extend_from_slice(&self.pending[sent..matched.start()]);
+            captures.expand(&self.replacement, &mut output);
+            sent = matched.end();
+            count += 1;
+
+            position = matched.end();
+
+            if matched.is_empty() {
+                // Skip to the next character, so an empty match is not found again
+                position += 1;
+
+                while position < len && self.pending[position] & 0xC0 == 0x80 {
+                    position += 1;
+                }
+            }
+        }
+
+        let keep_from = hold_from.max(sent).min(len);
+
+        if sent < keep_from {
+            output.extend_from_slice(&self.pending[sent..keep_from]);
+        }
+
+        let context_start = keep_from.saturating_sub(CONTEXT_LEN);
+        self.pending.drain(..context_start);
+        self.context = keep_from - context_start;
+
+        if count > 0 {
+            self.trace_replacements(count);
+        }
+
+        output
+    }
+
+    fn trace_replacements(&self, count: usize) {
+        if let Some(trace) = self.unit_trace.as_ref() {
+            let mut trace = trace.borrow_mut();
+            let key = self.id.as_deref().unwrap_or_else(|| self.regex.as_str());
+
+            trace.add_body_replacements(key, count);
+
+            if let Some(id) = self.id.as_deref() {
+                trace.add_unit_id_with_target("text", id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use regex::bytes::Regex;
+
+    use super::TextRegexFilterBodyAction;
+    use crate::action::UnitTrace;
+
+    fn replace_in_chunks(pattern: &str, replacement: &str, lookbehind: Option<usize>, chunks: &[&str]) -> String {
+        let mut filter = TextRegexFilterBodyAction::new(None, Regex::new(pattern).unwrap(), replacement.to_string(), lookbehind, None);
+        let mut output = Vec::new();
+
+        for chunk in chunks {
+            output.extend(filter.filter(chunk.as_bytes().to_vec()));
+        }
+
+        output.extend(filter.end());
+
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn replaces_with_capture_groups() {
+        let output = replace_in_chunks(
+            r"(\w+)@example\.com",
+            "$1@example.org",
+            None,
+            &["contact: john@example.com, jane@example.com"],
+        );
+
+        assert_eq!(output, "contact: john@example.org, jane@example.org");
+    }
+
+    #[test]
+    fn replaces_a_match_split_between_chunks() {
+        let output = replace_in_chunks(
+            "http://old.example.com",
+            "https://new.example.com",
+            None,
+            &["<a href=\"http://ol", "d.example.", "com/page\">link</a>"],
+        );
+
+        assert_eq!(output, "<a href=\"https://new.example.com/page\">link</a>");
+    }
+
+    #[test]
+    fn greedy_match_at_the_end_of_a_chunk_waits_for_the_next_one() {
+        let output = replace_in_chunks("a+", "b", None, &["xaa", "aay"]);
+
+        assert_eq!(output, "xby");
+    }
+
+    #[test]
+    fn word_boundaries_see_the_data_already_sent() {
+        let output = replace_in_chunks(r"\bcat\b", "dog", Some(4), &["concat", "enated cat"]);
+
+        assert_eq!(output, "concatenated dog");
+    }
+
+    #[test]
+    fn holds_back_at_most_the_lookbehind() {
+        let mut filter = TextRegexFilterBodyAction::new(None, Regex::new("needle").unwrap(), "pin".to_string(), Some(8), None);
+        let sent = filter.filter(vec![b'x'; 100]);
+
+        assert_eq!(sent.len(), 92);
+        assert!(filter.pending.len() <= 8 + super::CONTEXT_LEN);
+    }
+
+    #[test]
+    fn counts_replacements_in_unit_trace() {
+        let trace = Rc::new(RefCell::new(UnitTrace::default()));
+        let mut filter = TextRegexFilterBodyAction::new(
+            Some("unit-1".to_string()),
+            Regex::new("foo").unwrap(),
+            "bar".to_string(),
+            None,
+            Some(trace.clone()),
+        );
+
+        filter.filter(b"foo foo f".to_vec());
+        filter.filter(b"oo".to_vec());
+        filter.end();
+
+        trace.borrow_mut().squash_with_target_unit_traces();
+
+        assert_eq!(trace.borrow().get_body_replacements("unit-1"), 3);
+        assert!(trace.borrow().get_unit_ids_applied().contains("unit-1"));
+    }
+}
@@ -0,0 +1,792 @@
+use std::{borrow::Cow, cell::RefCell, rc::Rc};
+
+use regex::Regex;
+
+use crate::{
+    action::UnitTrace,
+    api::{XMLBodyFilter, XMLEdit},
+    filter::link_redirector::LinkRedirector,
+};
+
+/// Size of a single tag, comment or declaration above which the rest of the body is sent unchanged
+const MAX_TOKEN_SIZE: usize = 1024 * 1024;
+
+/// Size of the text of a selected element above which it is sent unchanged
+const MAX_TEXT_SIZE: usize = 64 * 1024;
+
+const CDATA_START: &[u8] = b"<![CDATA[";
+const CDATA_END: &[u8] = b"]]>";
+
+/// Edit the text or attributes of elements of a streamed XML document, like the urls of a
+/// sitemap or a feed
+///
+/// The document is tokenized as it is received, only the text of a selected element is held
+/// back until its end tag. Text containing child elements or comments is left unchanged.
+#[derive(Debug)]
+pub struct XmlFilterBodyAction {
+    id: Option<String>,
+    edits: Vec<Edit>,
+    // Data not tokenized yet, starting at a token boundary
+    pending: Vec<u8>,
+    // Names of the open elements, from the root
+    elements: Vec<String>,
+    text: Option<SelectedText>,
+    pass_through: bool,
+    replacements: usize,
+    links: Option<Rc<RefCell<LinkRedirector>>>,
+    unit_trace: Option<Rc<RefCell<UnitTrace>>>,
+}
+
+#[derive(Debug)]
+struct Edit {
+    path: XmlPath,
+    regex: Option<Regex>,
+    replacement: Option<String>,
+    redirect: bool,
+}
+
+#[derive(Debug)]
+struct XmlPath {
+    absolute: bool,
+    elements: Vec<String>,
+    attribute: Option<String>,
+}
+
+// Text of a selected element, with the edits to apply on it
+#[derive(Debug)]
+struct SelectedText {
+    edits: Vec<usize>,
+    segments: Vec<Segment>,
+    size: usize,
+}
+
+#[derive(Debug)]
+enum Segment {
+    Text(Vec<u8>),
+    CData(Vec<u8>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TokenKind {
+    Text,
+    CData,
+    StartTag,
+    EndTag,
+    // Comments, processing instructions and declarations
+    Other,
+}
+
+impl XmlFilterBodyAction {
+    /// Values of edits with `redirect` are looked up in `links`, these edits have no effect when
+    /// no router is set on the action
+    pub fn new(
+        filter: XMLBodyFilter,
+        links: Option<Rc<RefCell<LinkRedirector>>>,
+        unit_trace: Option<Rc<RefCell<UnitTrace>>>,
+    ) -> Option<Self> {
+        if links.is_none() && filter.edits.iter().any(|edit| edit.redirect) {
+            tracing::warn!(
+                "redirect of edit_xml body filter {} ignored, no router is set on the action",
+                filter.id.as_deref().unwrap_or_default()
+            );
+        }
+
+        let edits = filter.edits.into_iter().filter_map(Edit::new).collect::<Vec<_>>();
+
+        if edits.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            id: filter.id,
+            edits,
+            pending: Vec::new(),
+            elements: Vec::new(),
+            text: None,
+            pass_through: false,
+            replacements: 0,
+            links,
+            unit_trace,
+        })
+    }
+
+    pub fn filter(&mut self, data: Vec<u8>) -> Vec<u8> {
+        if self.pass_through {
+            return data;
+        }
+
+        self.pending.extend(data);
+        self.process(false)
+    }
+
+    pub fn end(mut self) -> Vec<u8> {
+        if self.pass_through {
+            return Vec::new();
+        }
+
+        let mut output = self.process(true);
+        self.abort_text(&mut output);
+
+        output
+    }
+
+    fn process(&mut self, last: bool) -> Vec<u8> {
+        let pending = std::mem::take(&mut self.pending);
+        let mut output = Vec::new();
+        let mut position = 0;
+
+        while position < pending.len() {
+            let (kind, len) = match next_token(&pending[position..]) {
+                Some(token) => token,
+                // Truncated document, send it as is
+                None if last => (TokenKind::Other, pending.len() - position),
+                None => break,
+            };
+
+            self.handle_token(kind, &pending[position..position + len], &mut output);
+            position += len;
+        }
+
+        self.pending = pending[position..].to_vec();
+
+        if self.pending.len() > MAX_TOKEN_SIZE {
+            tracing::warn!("xml token larger than {MAX_TOKEN_SIZE} bytes, the rest of the body will not be edited");
+
+            self.abort_text(&mut output);
+            output.append(&mut self.pending);
+            self.pass_through = true;
+        }
+
+        if self.replacements > 0 {
+            self.trace_replacements();
+        }
+
+        output
+    }
+
+    fn handle_token(&mut self, kind: TokenKind, token: &[u8], output: &mut Vec<u8>) {
+        match kind {
+            TokenKind::Text | TokenKind::CData => {
+                let Some(text) = self.text.as_mut() else {
+                    output.extend_from_slice(token);
+
+                    return;
+                };
+
+                text.size += token.len();
+                text.segments.push(match kind {
+                    TokenKind::CData => Segment::CData(token[CDATA_START.len()..token.len() - CDATA_END.len()].to_vec()),
+                    _ => Segment::Text(token.to_vec()),
+                });
+
+                if text.size > MAX_TEXT_SIZE {
+                    self.abort_text(output);
+                }
+            }
+            TokenKind::StartTag => {
+                // Text with child elements is not edited
+                self.abort_text(output);
+
+                let name = tag_name(&token[1..]);
+                let self_closing = token.ends_with(b"/>");
+
+                self.elements.push(name);
+
+                match self.edit_attributes(token) {
+                    Some(tag) => output.extend(tag),
+                    None => output.extend_from_slice(token),
+                }
+
+                if self_closing {
+                    self.elements.pop();
+
+                    return;
+                }
+
+                let edits = self
+                    .edits
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, edit)| edit.path.attribute.is_none() && edit.path.matches(&self.elements))
+                    .map(|(index, _)| index)
+                    .collect::<Vec<_>>();
+
+                if !edits.is_empty() {
+                    self.text = Some(SelectedText {
+                        edits,
+                        segments: Vec::new(),
+                        size: 0,
+                    });
+                }
+            }
+            TokenKind::EndTag => {
+                if let Some(text) = self.text.take() {
+                    self.edit_text(text, output);
+                }
+
+                let name = tag_name(&token[2..]);
+
+                if let Some(index) = self.elements.iter().rposition(|element| *element == name) {
+                    self.elements.truncate(index);
+                }
+
+                output.extend_from_slice(token);
+            }
+            TokenKind::Other => {
+                self.abort_text(output);
+                output.extend_from_slice(token);
+            }
+        }
+    }
+
+    // Send the text held back unchanged
+    fn abort_text(&mut self, output: &mut Vec<u8>) {
+        if let Some(text) = self.text.take() {
+            for segment in text.segments {
+                write_segment(&segment, output);
+            }
+        }
+    }
+
+    fn edit_text(&mut self, text: SelectedText, output: &mut Vec<u8>) {
+        let mut value = Vec::new();
+        // Entities may be split between text segments
+        let mut raw_text = Vec::new();
+
+        for segment in &text.segments {
+            match segment {
+                Segment::Text(raw) => raw_text.extend_from_slice(raw),
+                Segment::CData(raw) => {
+                    value.extend_from_slice(decode(&String::from_utf8_lossy(&raw_text)).as_bytes());
+                    value.extend_from_slice(raw);
+                    raw_text.clear();
+                }
+            }
+        }
+
+        value.extend_from_slice(decode(&String::from_utf8_lossy(&raw_text)).as_bytes());
+
+        let new_value = match String::from_utf8(value) {
+            Ok(value) => self.apply_edits(&text.edits, &value),
+            Err(_) => None,
+        };
+
+        match new_value {
+            // Keep a single CDATA section as CDATA, as feeds often use them for urls
+            Some(new_value) if matches!(text.segments.as_slice(), [Segment::CData(_)]) && !new_value.contains("]]>") => {
+                output.extend_from_slice(CDATA_START);
+                output.extend_from_slice(new_value.as_bytes());
+                output.extend_from_slice(CDATA_END);
+            }
+            Some(new_value) => output.extend_from_slice(escape(&new_value, None).as_bytes()),
+            None => {
+                for segment in &text.segments {
+                    write_segment(segment, output);
+                }
+            }
+        }
+    }
+
+    fn edit_attributes(&mut self, tag: &[u8]) -> Option<Vec<u8>> {
+        if !self
+            .edits
+            .iter()
+            .any(|edit| edit.path.attribute.is_some() && edit.path.matches(&self.elements))
+        {
+            return None;
+        }
+
+        let mut new_tag = Vec::new();
+        let mut sent = 0;
+
+        for attribute in parse_attributes(tag) {
+            let name = String::from_utf8_lossy(&tag[attribute.name.0..attribute.name.1]);
+            let edits = self
+                .edits
+                .iter()
+                .enumerate()
+                .filter(|(_, edit)| edit.path.attribute.as_deref() == Some(name.as_ref()) && edit.path.matches(&self.elements))
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>();
+
+            if edits.is_empty() {
+                continue;
+            }
+
+            let Ok(value) = std::str::from_utf8(&tag[attribute.value.0..attribute.value.1]) else {
+                continue;
+            };
+
+            if let Some(new_value) = self.apply_edits(&edits, &decode(value)) {
+                new_tag.extend_from_slice(&tag[sent..attribute.value.0]);
+                new_tag.extend_from_slice(escape(&new_value, Some(attribute.quote)).as_bytes());
+                sent = attribute.value.1;
+            }
+        }
+
+        if sent == 0 {
+            return None;
+        }
+
+        new_tag.extend_from_slice(&tag[sent..]);
+
+        Some(new_tag)
+    }
+
+    // New value after the edits, None when it is unchanged
+    fn apply_edits(&mut self, edits: &[usize], value: &str) -> Option<String> {
+        let mut new_value = value.to_string();
+
+        for index in edits {
+            let edit = &self.edits[*index];
+
+            if let Some(regex) = edit.regex.as_ref() {
+                new_value = regex
+                    .replace_all(&new_value, edit.replacement.as_deref().unwrap_or_default())
+                    .into_owned();
+            } else if let Some(replacement) = edit.replacement.as_ref() {
+                new_value.clone_from(replacement);
+            }
+
+            if edit.redirect
+                && let Some(links) = self.links.as_ref()
+            {
+                // Keep the whitespace around the url
+                let link = new_value.trim();
+
+                if !link.is_empty()
+                    && let Some(target) = links.borrow_mut().redirect(link)
+                {
+                    new_value = new_value.replacen(link, &target, 1);
+                }
+            }
+        }
+
+        if new_value == value {
+            return None;
+        }
+
+        self.replacements += 1;
+
+        Some(new_value)
+    }
+
+    fn trace_replacements(&mut self) {
+        if let Some(trace) = self.unit_trace.as_ref() {
+            let mut trace = trace.borrow_mut();
+
+            trace.add_body_replacements(self.id.as_deref().unwrap_or("xml"), self.replacements);
+
+            if let Some(id) = self.id.as_deref() {
+                trace.add_unit_id_with_target("xml", id);
+            }
+        }
+
+        self.replacements = 0;
+    }
+}
+
+impl Edit {
+    fn new(edit: XMLEdit) -> Option<Self> {
+        let path = XmlPath::parse(edit.path.as_str())?;
+        let regex = match edit.pattern.as_deref().map(Regex::new).transpose() {
+            Ok(regex) => regex,
+            Err(err) => {
+                tracing::error!("invalid regex in edit_xml body filter: {err}");
+
+                return None;
+            }
+        };
+
+        Some(Self {
+            path,
+            regex,
+            replacement: edit.replacement,
+            redirect: edit.redirect,
+        })
+    }
+}
+
+impl XmlPath {
+    fn parse(path: &str) -> Option<Self> {
+        let path = path.trim();
+        // `//loc` selects `loc` elements anywhere, like a relative path
+        let absolute = path.starts_with('/') && !path.starts_with("//");
+        let mut elements = path
+            .split('/')
+            .filter(|step| !step.is_empty())
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        let attribute = match elements.last() {
+            Some(step) if step.starts_with('@') => elements.pop().map(|step| step[1..].to_string()),
+            _ => None,
+        };
+
+        if elements.is_empty() && attribute.is_none() {
+            tracing::error!("empty path in edit_xml body filter");
+
+            return None;
+        }
+
+        Some(Self {
+            absolute,
+            elements,
+            attribute,
+        })
+    }
+
+    fn matches(&self, elements: &[String]) -> bool {
+        if elements.len() < self.elements.len() || (self.absolute && elements.len() != self.elements.len()) {
+            return false;
+        }
+
+        self.elements
+            .iter()
+            .rev()
+            .zip(elements.iter().rev())
+            .all(|(step, element)| step == "*" || step == element)
+    }
+}
+
+/// Kind and length of the token at the start of data, None when it is not complete yet
+fn next_token(data: &[u8]) -> Option<(TokenKind, usize)> {
+    if data[0] != b'<' {
+        // Text can be sent as it is received
+        let len = data.iter().position(|byte| *byte == b'<').unwrap_or(data.len());
+
+        return Some((TokenKind::Text, len));
+    }
+
+    if data.starts_with(b"<!--") {
+        return find(data, 4, b"-->").map(|len| (TokenKind::Other, len));
+    }
+
+    if data.starts_with(CDATA_START) {
+        return find(data, CDATA_START.len(), CDATA_END).map(|len| (TokenKind::CData, len));
+    }
+
+    if data.starts_with(b"<?") {
+        return find(data, 2, b"?>").map(|len| (TokenKind::Other, len));
+    }
+
+    if data.len() < CDATA_START.len() && (b"<!--".starts_with(data) || CDATA_START.starts_with(data)) {
+        return None;
+    }
+
+    let kind = match data.get(1)? {
+        b'/' => TokenKind::EndTag,
+        b'!' => TokenKind::Other,
+        _ => TokenKind::StartTag,
+    };
+
+    // Tags and declarations end at the first `>` outside of quotes, and of the internal
+    // subset of a doctype
+    let mut quote = None;
+    let mut depth = 0usize;
+
+    for (index, byte) in data.iter().enumerate().skip(1) {
+        match (quote, *byte) {
+            (Some(quote_byte), byte) if byte == quote_byte => quote = None,
+            (Some(_), _) => (),
+            (None, b'"' | b'\'') => quote = Some(*byte),
+            (None, b'[') => depth += 1,
+            (None, b']') => depth = depth.saturating_sub(1),
+            (None, b'>') if depth == 0 => return Some((kind, index + 1)),
+            _ => (),
+        }
+    }
+
+    None
+}
+
+fn find(data: &[u8], from: usize, needle: &[u8]) -> Option<usize> {
+    data.get(from..)?
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|index| from + index + needle.len())
+}
+
+fn tag_name(data: &[u8]) -> String {
+    let len = data
+        .iter()
+        .position(|byte| byte.is_ascii_whitespace() || *byte == b'/' || *byte == b'>')
+        .unwrap_or(data.len());
+
+    String::from_utf8_lossy(&data[..len]).into_owned()
+}
+
+struct Attribute {
+    name: (usize, usize),
+    value: (usize, usize),
+    quote: u8,
+}
+
+// Quoted attributes of a start tag, with the offsets of their name and value in the tag
+fn parse_attributes(tag: &[u8]) -> Vec<Attribute> {
+    let mut attributes = Vec::new();
+    let mut position = 1 + tag_name(&tag[1..]).len();
+
+    loop {
+        while position < tag.len() && tag[position].is_ascii_whitespace() {
+            position += 1;
+        }
+
+        let name_start = position;
+
+        while position < tag.len() && !matches!(tag[position], b'=' | b'/' | b'>') && !tag[position].is_ascii_whitespace() {
+            position += 1;
+        }
+
+        if position == name_start {
+            return attributes;
+        }
+
+        let name_end = position;
+
+        while position < tag.len() && tag[position].is_ascii_whitespace() {
+            position += 1;
+        }
+
+        if tag.get(position) != Some(&b'=') {
+            continue;
+        }
+
+        position += 1;
+
+        while position < tag.len() && tag[position].is_ascii_whitespace() {
+            position += 1;
+        }
+
+        let Some(quote) = tag.get(position).copied().filter(|byte| matches!(byte, b'"' | b'\'')) else {
+            continue;
+        };
+        let Some(len) = tag[position + 1..].iter().position(|byte| *byte == quote) else {
+            return attributes;
+        };
+
+        attributes.push(Attribute {
+            name: (name_start, name_end),
+            value: (position + 1, position + 1 + len),
+            quote,
+        });
+
+        position += len + 2;
+    }
+}
+
+fn write_segment(segment: &Segment, output: &mut Vec<u8>) {
+    match segment {
+        Segment::Text(raw) => output.extend_from_slice(raw),
+        Segment::CData(raw) => {
+            output.extend_from_slice(CDATA_START);
+            output.extend_from_slice(raw);
+            output.extend_from_slice(CDATA_END);
+        }
+    }
+}
+
+// Decode the predefined and numeric entities, others are kept as is
+fn decode(raw: &str) -> Cow<'_, str> {
+    if !raw.contains('&') {
+        return Cow::Borrowed(raw);
+    }
+
+    let mut decoded = String::with_capacity(raw.len());
+    let mut rest = raw;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let character = entity.and_then(|(name, _)| match name {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok().and_then(char::from_u32),
+                None => name.strip_prefix('#').and_then(|dec| dec.parse().ok()).and_then(char::from_u32),
+            },
+        });
+
+        match (character, entity) {
+            (Some(character), Some((_, end))) => {
+                decoded.push(character);
+                rest = &rest[end + 1..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+
+    Cow::Owned(decoded)
+}
+
+fn escape(value: &str, quote: Option<u8>) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for character in value.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' if quote == Some(b'"') => escaped.push_str("&quot;"),
+            '\'' if quote == Some(b'\'') => escaped.push_str("&apos;"),
+            character => escaped.push(character),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::XmlFilterBodyAction;
+    use crate::{
+        action::UnitTrace,
+        api::{XMLAction, XMLBodyFilter, XMLEdit},
+    };
+
+    fn edit(path: &str, pattern: Option<&str>, replacement: &str) -> XMLEdit {
+        XMLEdit {
+            path: path.to_string(),
+            pattern: pattern.map(str::to_string),
+            replacement: Some(replacement.to_string()),
+            redirect: false,
+        }
+    }
+
+    fn filter(edits: Vec<XMLEdit>) -> XmlFilterBodyAction {
+        XmlFilterBodyAction::new(
+            XMLBodyFilter {
+                action: XMLAction::Edit,
+                edits,
+                id: None,
+                target_hash: None,
+            },
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    // Send the document one byte at a time, to split every token
+    fn filter_bytes(mut filter: XmlFilterBodyAction, input: &str) -> String {
+        let mut output = Vec::new();
+
+        for byte in input.bytes() {
+            output.extend(filter.filter(vec![byte]));
+        }
+
+        output.extend(filter.end());
+
+        String::from_utf8(output).unwrap()
+    }
+
+    const SITEMAP: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9" xmlns:xhtml="http://www.w3.org/1999/xhtml">
+  <!-- <loc>http://old.example.com/comment</loc> -->
+  <url>
+    <loc>
+      http://old.example.com/a?x=1&amp;y=2
+    </loc>
+    <xhtml:link rel="alternate" hreflang="fr" href="http://old.example.com/fr/a"/>
+  </url>
+  <url><loc><![CDATA[http://old.example.com/b]]></loc><lastmod>2024-01-01</lastmod></url>
+</urlset>"#;
+
+    #[test]
+    fn edits_text_and_attributes_in_chunks() {
+        let output = filter_bytes(
+            filter(vec![
+                edit("url/loc", Some("^(\\s*)http://old"), "${1}https://new"),
+                edit("xhtml:link/@href", Some("http://old"), "https://new"),
+            ]),
+            SITEMAP,
+        );
+
+        assert_eq!(
+            output,
+            SITEMAP
+                .replace("http://old.example.com/a", "https://new.example.com/a")
+                .replace("http://old.example.com/b", "https://new.example.com/b")
+                .replace("http://old.example.com/fr/a", "https://new.example.com/fr/a")
+        );
+    }
+
+    #[test]
+    fn absolute_paths_match_from_the_root() {
+        let output = filter_bytes(
+            filter(vec![
+                edit("/urlset/lastmod", None, "x"),
+                edit("/urlset/url/lastmod", None, "2025-01-01"),
+            ]),
+            SITEMAP,
+        );
+
+        assert_eq!(output, SITEMAP.replace("2024-01-01", "2025-01-01"));
+    }
+
+    #[test]
+    fn escapes_new_values() {
+        let output = filter_bytes(
+            filter(vec![edit("item/title", None, "Tom & Jerry <3"), edit("item/@id", None, "a\"b")]),
+            "<rss><item id=\"1\"><title>Old</title></item></rss>",
+        );
+
+        assert_eq!(
+            output,
+            "<rss><item id=\"a&quot;b\"><title>Tom &amp; Jerry &lt;3</title></item></rss>"
+        );
+    }
+
+    #[test]
+    fn leaves_mixed_content_unchanged() {
+        let input = "<feed><entry><summary>Hello <b>world</b></summary></entry></feed>";
+
+        assert_eq!(filter_bytes(filter(vec![edit("entry/summary", None, "x")]), input), input);
+    }
+
+    #[test]
+    fn sends_truncated_documents_unchanged() {
+        let mut filter = filter(vec![edit("loc", None, "x")]);
+        let mut output = filter.filter(b"<urlset><url><loc>a</loc><loc attr=\"".to_vec());
+
+        output.extend(filter.end());
+
+        assert_eq!(String::from_utf8(output).unwrap(), "<urlset><url><loc>x</loc><loc attr=\"");
+    }
+
+    #[test]
+    fn counts_replacements_in_unit_trace() {
+        let trace = Rc::new(RefCell::new(UnitTrace::default()));
+        let mut filter = XmlFilterBodyAction::new(
+            XMLBodyFilter {
+                action: XMLAction::Edit,
+                edits: vec![edit("loc", Some("old"), "new")],
+                id: Some("unit-1".to_string()),
+                target_hash: None,
+            },
+            None,
+            Some(trace.clone()),
+        )
+        .unwrap();
+
+        filter.filter(b"<urlset><url><loc>/old</loc></url><url><loc>/o".to_vec());
+        filter.filter(b"ld</loc></url><url><loc>/other</loc></url></urlset>".to_vec());
+        filter.end();
+
+        trace.borrow_mut().squash_with_target_unit_traces();
+
+        assert_eq!(trace.borrow().get_body_replacements("unit-1"), 2);
+        assert!(trace.borrow().get_unit_ids_applied().contains("unit-1"));
+    }
+}
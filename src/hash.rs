@@ -0,0 +1,25 @@
+/// 64 bits hash which stays the same across builds and platforms, unlike the std hasher,
+/// for decisions which must be consistent between proxy instances
+pub(crate) fn stable_hash(value: &[u8]) -> u64 {
+    // FNV-1a
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+
+    for byte in value {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+
+    // Mix the bits, FNV alone is poorly distributed on short inputs
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    hash ^= hash >> 33;
+
+    hash
+}
+
+/// Hash mapped to a float in ]0, 1]
+pub(crate) fn stable_unit_hash(value: &[u8]) -> f64 {
+    ((stable_hash(value) >> 11) + 1) as f64 / (1u64 << 53) as f64
+}
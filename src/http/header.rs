@@ -2,6 +2,7 @@ use http::{HeaderMap, header::HeaderName};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Header {
     pub name: String,
     pub value: String,
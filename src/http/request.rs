@@ -19,6 +19,7 @@ use crate::router_config::RouterConfig;
 const QUERY_ENCODE_SET: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'#').add(b'<').add(b'>');
 
 #[derive(Serialize, Deserialize, Debug, Clone, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Request {
     #[serde(rename = "path_and_query")]
     pub path_and_query_skipped: PathAndQueryWithSkipped,
@@ -179,6 +180,17 @@ impl Request {
         }
     }
 
+    /// Same request with another path and query, as sent to the upstream after an internal rewrite
+    #[cfg(feature = "router")]
+    pub fn with_path_and_query(&self, config: &RouterConfig, path_and_query: &str) -> Self {
+        let mut request = self.clone();
+
+        request.path_and_query_skipped = PathAndQueryWithSkipped::from_config(config, path_and_query);
+        request.path_and_query = Some(path_and_query.to_string());
+
+        request
+    }
+
     pub fn add_header(&mut self, name: String, value: String, ignore_case: bool) {
         self.headers.push(Header {
             name,
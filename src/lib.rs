@@ -12,6 +12,8 @@ pub mod marker;
 pub mod regex_radix_tree;
 #[cfg(feature = "router")]
 pub mod router;
+#[cfg(feature = "schema")]
+pub mod schema;
 
 #[cfg(not(target_arch = "wasm32"))]
 mod callback_log;
@@ -19,6 +21,7 @@ mod callback_log;
 mod dot;
 #[cfg(not(target_arch = "wasm32"))]
 mod ffi_helpers;
+mod hash;
 mod regex;
 mod router_config;
 #[cfg(feature = "wasmbind")]
@@ -1,4 +1,5 @@
 mod transformer;
+mod validator;
 
 use std::{
     collections::HashMap,
@@ -8,34 +9,51 @@ use std::{
 
 use serde::{Deserialize, Serialize};
 pub use transformer::{Camelize, Dasherize, Lowercase, Replace, Slice, Transform, Underscorize, Uppercase};
+pub use validator::MarkerValidator;
 
 use crate::{api::VariableValue, regex::LazyRegex};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Marker {
     name: String,
     regex: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    validator: Option<MarkerValidator>,
 }
 
 #[derive(Serialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum StaticOrDynamic {
     Static(String),
     Dynamic(MarkerString),
 }
 
 #[derive(Serialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct MarkerString {
     pub regex: String,
     pub capture: String,
     pub ignore_case: bool,
     markers: HashMap<String, String>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    validators: HashMap<String, MarkerValidator>,
     #[serde(skip)]
     regex_capture: Arc<RwLock<LazyRegex>>,
 }
 
 impl Marker {
     pub fn new(name: String, regex: String) -> Marker {
-        Marker { name, regex }
+        Marker {
+            name,
+            regex,
+            validator: None,
+        }
+    }
+
+    pub fn with_validator(mut self, validator: Option<MarkerValidator>) -> Marker {
+        self.validator = validator;
+        self
     }
 
     pub fn format(&self) -> String {
@@ -48,6 +66,7 @@ impl MarkerString {
         let mut regex = regex::escape(str);
         let mut capture = regex.clone();
         let mut marker_map = HashMap::new();
+        let mut validators = HashMap::new();
 
         markers.sort_by_key(|b| std::cmp::Reverse(b.name.len()));
 
@@ -59,6 +78,10 @@ impl MarkerString {
                 regex = regex.replace(marker.format().as_str(), marker_regex.as_str());
                 capture = capture.replace(marker.format().as_str(), marker_capture.as_str());
                 marker_map.insert(marker.name.clone(), marker_capture);
+
+                if let Some(validator) = &marker.validator {
+                    validators.insert(marker.name.clone(), validator.clone());
+                }
             }
         }
 
@@ -71,23 +94,35 @@ impl MarkerString {
             regex_capture: Arc::new(RwLock::new(LazyRegex::new_leaf(capture.as_str(), ignore_case))),
             capture,
             markers: marker_map,
+            validators,
             ignore_case,
         })
     }
 
     pub fn capture(&self, str: &str) -> HashMap<String, String> {
+        self.try_capture(str).unwrap_or_default()
+    }
+
+    /// Whether a captured value may be rejected after the regex matched, see `try_capture`.
+    pub fn has_validators(&self) -> bool {
+        !self.validators.is_empty()
+    }
+
+    /// Same as `capture`, but returns `None` when a typed marker captured a value its regex
+    /// accepts but its type does not, like an integer out of range.
+    pub fn try_capture(&self, str: &str) -> Option<HashMap<String, String>> {
         let mut parameters = HashMap::new();
 
         let regex = match self.regex_capture.read() {
             Ok(regex) => match regex.regex() {
                 Some(regex) => regex,
-                None => return parameters,
+                None => return Some(parameters),
             },
-            Err(_) => return parameters,
+            Err(_) => return Some(parameters),
         };
 
         let capture = match regex.captures(str) {
-            None => return parameters,
+            None => return Some(parameters),
             Some(capture) => capture,
         };
 
@@ -102,10 +137,16 @@ impl MarkerString {
                 Some(matched) => matched.as_str().to_string(),
             };
 
+            if let Some(validator) = self.validators.get(name)
+                && !validator.is_valid(value.as_str())
+            {
+                return None;
+            }
+
             parameters.insert(name.to_string(), value);
         }
 
-        parameters
+        Some(parameters)
     }
 
     pub fn compile(&self) -> bool {
@@ -143,6 +184,20 @@ impl StaticOrDynamic {
         }
     }
 
+    pub fn try_capture(&self, str: &str) -> Option<HashMap<String, String>> {
+        match self {
+            StaticOrDynamic::Static(_) => Some(HashMap::new()),
+            StaticOrDynamic::Dynamic(marker_string) => marker_string.try_capture(str),
+        }
+    }
+
+    pub fn has_validators(&self) -> bool {
+        match self {
+            StaticOrDynamic::Static(_) => false,
+            StaticOrDynamic::Dynamic(marker_string) => marker_string.has_validators(),
+        }
+    }
+
     pub fn replace(mut str: String, variables: &[(String, VariableValue)], use_default: bool) -> String {
         let mut marker_buf = String::new();
 
@@ -0,0 +1,23 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Checks a regex cannot express, applied on the captured value of a typed marker.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum MarkerValidator {
+    IntegerRange { min: Option<i64>, max: Option<i64> },
+    Date,
+}
+
+impl MarkerValidator {
+    pub fn is_valid(&self, value: &str) -> bool {
+        match self {
+            MarkerValidator::IntegerRange { min, max } => match value.parse::<i64>() {
+                Ok(value) => min.is_none_or(|min| value >= min) && max.is_none_or(|max| value <= max),
+                Err(_) => false,
+            },
+            MarkerValidator::Date => NaiveDate::parse_from_str(value, "%Y-%m-%d").is_ok(),
+        }
+    }
+}
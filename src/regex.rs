@@ -1,8 +1,13 @@
 use std::{fmt::Display, hash::Hash, sync::Arc};
 
 use regex::{Regex, RegexBuilder};
+use regex_syntax::hir::{Class, Hir, HirKind};
 use serde::Serialize;
 
+/// Characters tried first when a sample has to pick a character out of a class, so samples
+/// stay usable in a path, a host or a header value.
+const SAMPLE_PREFERRED_CHARS: &str = "a0A-_.~";
+
 #[derive(Debug, Clone)]
 pub struct LazyRegex {
     pub(crate) original: String,
@@ -20,6 +25,17 @@ impl Serialize for LazyRegex {
     }
 }
 
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for LazyRegex {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "LazyRegex".into()
+    }
+
+    fn json_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({ "type": "string", "format": "regex" })
+    }
+}
+
 impl Eq for LazyRegex {}
 
 impl PartialEq for LazyRegex {
@@ -133,3 +149,79 @@ impl LazyRegex {
         }
     }
 }
+
+/// Build a string matched by `regex`, or `None` when the pattern cannot be parsed or only
+/// matches unprintable text.
+///
+/// Optional parts are taken once and alternations take their first branch, so the sample is
+/// short but never empty when the pattern requires content.
+pub fn sample(regex: &str) -> Option<String> {
+    let hir = regex_syntax::parse(regex).ok()?;
+    let mut sample = String::new();
+
+    push_sample(&hir, &mut sample)?;
+
+    Some(sample)
+}
+
+fn push_sample(hir: &Hir, sample: &mut String) -> Option<()> {
+    match hir.kind() {
+        HirKind::Empty | HirKind::Look(_) => (),
+        HirKind::Literal(literal) => sample.push_str(std::str::from_utf8(&literal.0).ok()?),
+        HirKind::Class(Class::Unicode(class)) => {
+            sample.push(pick_char(class.ranges().iter().map(|range| (range.start(), range.end())))?);
+        }
+        HirKind::Class(Class::Bytes(class)) => {
+            sample.push(pick_char(
+                class
+                    .ranges()
+                    .iter()
+                    .filter(|range| range.start().is_ascii())
+                    .map(|range| (range.start() as char, range.end().min(0x7f) as char)),
+            )?);
+        }
+        HirKind::Repetition(repetition) => {
+            let count = match (repetition.min, repetition.max) {
+                (0, Some(0)) => 0,
+                (0, _) => 1,
+                (min, _) => min,
+            };
+
+            for _ in 0..count {
+                push_sample(&repetition.sub, sample)?;
+            }
+        }
+        HirKind::Capture(capture) => push_sample(&capture.sub, sample)?,
+        HirKind::Concat(hirs) => {
+            for hir in hirs {
+                push_sample(hir, sample)?;
+            }
+        }
+        HirKind::Alternation(hirs) => {
+            let branch = hirs.iter().find_map(|hir| {
+                let mut branch = String::new();
+
+                push_sample(hir, &mut branch).map(|_| branch)
+            })?;
+
+            sample.push_str(branch.as_str());
+        }
+    }
+
+    Some(())
+}
+
+fn pick_char<I>(ranges: I) -> Option<char>
+where
+    I: Iterator<Item = (char, char)> + Clone,
+{
+    SAMPLE_PREFERRED_CHARS
+        .chars()
+        .find(|c| ranges.clone().any(|(start, end)| start <= *c && *c <= end))
+        .or_else(|| ranges.clone().find_map(|(start, end)| (start..=end).find(|c| c.is_alphanumeric())))
+        .or_else(|| {
+            ranges
+                .clone()
+                .find_map(|(start, end)| (start..=end).find(|c| !c.is_control() && !c.is_whitespace()))
+        })
+}
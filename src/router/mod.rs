@@ -1,3 +1,4 @@
+mod path_index;
 pub mod request_matcher;
 mod route;
 mod route_datetime;
@@ -15,6 +16,7 @@ use std::{
 
 #[cfg(feature = "dot")]
 use dot_graph::{Edge, Graph, Kind, Node};
+use path_index::PathIndex;
 pub use request_matcher::{DateTimeMatcher, HostMatcher, IpMatcher, MethodMatcher, PathAndQueryMatcher, SchemeMatcher};
 pub use route::{IntoRoute, Route};
 pub use route_datetime::RouteDateTime;
@@ -33,6 +35,7 @@ pub struct Router<T> {
     matcher: SchemeMatcher<T>,
     pub config: Arc<RouterConfig>,
     pub routes: HashMap<String, Arc<Route<T>>>,
+    path_index: PathIndex,
 }
 
 impl<T> Default for Router<T> {
@@ -43,6 +46,7 @@ impl<T> Default for Router<T> {
             matcher: SchemeMatcher::new(config.clone()),
             config,
             routes: HashMap::new(),
+            path_index: PathIndex::default(),
         }
     }
 }
@@ -57,12 +61,18 @@ impl<T> Router<T> {
             matcher: SchemeMatcher::new(config.clone()),
             config,
             routes: HashMap::new(),
+            path_index: PathIndex::default(),
         }
     }
 
     pub fn insert_route(&mut self, route: Route<T>) {
         let arc_route = Arc::new(route);
 
+        if let Some(previous) = self.routes.get(arc_route.id()) {
+            self.path_index.remove(previous);
+        }
+
+        self.path_index.insert(&arc_route);
         self.matcher.insert(arc_route.clone());
         self.routes.insert(arc_route.id().to_string(), arc_route);
     }
@@ -72,8 +82,8 @@ impl<T> Router<T> {
     }
 
     pub fn remove(&mut self, id: &str) -> Option<Arc<Route<T>>> {
-        if self.routes.contains_key(id) {
-            self.routes.remove(id);
+        if let Some(route) = self.routes.remove(id) {
+            self.path_index.remove(&route);
 
             self.matcher.remove(id)
         } else {
@@ -82,7 +92,12 @@ impl<T> Router<T> {
     }
 
     pub fn batch_remove(&mut self, ids: &HashSet<String>) {
-        self.routes.retain(|id, _| !ids.contains(id));
+        for id in ids {
+            if let Some(route) = self.routes.remove(id) {
+                self.path_index.remove(&route);
+            }
+        }
+
         self.matcher.batch_remove(ids);
     }
 
@@ -91,7 +106,11 @@ impl<T> Router<T> {
     }
 
     pub fn match_request(&self, request: &Request) -> Vec<Arc<Route<T>>> {
-        self.matcher.match_request(request)
+        let mut routes = self.matcher.match_request(request);
+
+        routes.retain(|route| !route.has_marker_validators() || route.try_capture(request).is_some());
+
+        routes
     }
 
     pub fn len(&self) -> usize {
@@ -106,10 +125,27 @@ impl<T> Router<T> {
         &self.routes
     }
 
+    /// Routes whose source path or target starts with the same segment as this path, or whose
+    /// source starts with a marker, as candidates for suggestions
+    pub fn routes_sharing_path_prefix(&self, path: &str) -> Vec<Arc<Route<T>>> {
+        self.path_index
+            .find(path)
+            .into_iter()
+            .filter_map(|id| self.routes.get(id).cloned())
+            .collect()
+    }
+
     pub fn trace_request(&self, request: &Request) -> Vec<Trace<T>> {
         let request_rebuild = Request::rebuild_with_config(self.config.as_ref(), request);
 
-        self.matcher.trace(&request_rebuild)
+        let mut traces = self.matcher.trace(&request_rebuild);
+
+        // Typed markers are validated after matching, as in `match_request`
+        for trace in &mut traces {
+            trace.retain_routes(&|route: &Route<T>| !route.has_marker_validators() || route.try_capture(&request_rebuild).is_some());
+        }
+
+        traces
     }
 
     pub fn get_route(&self, request: &Request) -> Option<Arc<Route<T>>> {
@@ -215,3 +251,35 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::Rule;
+
+    #[test]
+    fn traces_reject_routes_with_invalid_typed_markers() {
+        let mut router = Router::<Rule>::default();
+        router.insert(
+            serde_json::from_str::<Rule>(
+                r#"{"id": "integer", "rank": 0, "source": {"path": "/product/@id"}, "markers": [{"name": "id", "type": {"integer": {"min": 1, "max": 100}}}], "target": "/p/@id", "status_code": 301}"#,
+            )
+            .unwrap(),
+        );
+
+        for (path, matched) in [("/product/42", true), ("/product/500", false)] {
+            let request = Request::from_config(&router.config, path.to_string(), None, None, None, None, None);
+            let traces = router.trace_request(&request);
+
+            assert_eq!(router.match_request(&request).is_empty(), !matched);
+            assert_eq!(Trace::get_routes_from_traces(&traces).is_empty(), !matched);
+            assert_eq!(
+                traces
+                    .iter()
+                    .filter(|trace| matches!(trace.info, trace::TraceInfo::PathAndQueryRegex { .. }))
+                    .all(|trace| trace.matched),
+                matched
+            );
+        }
+    }
+}
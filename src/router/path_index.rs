@@ -0,0 +1,87 @@
+use std::collections::{HashMap, HashSet};
+
+use super::Route;
+use crate::marker::StaticOrDynamic;
+
+/// Route ids indexed by the first segment of their source path and of their target, to find
+/// routes sharing a prefix with a path without going through every route of the router.
+#[derive(Debug, Clone, Default)]
+pub struct PathIndex {
+    // Routes by lowercased first segment, `None` when the first segment of the source has a marker
+    segments: HashMap<Option<String>, HashSet<String>>,
+}
+
+impl PathIndex {
+    pub fn insert<T>(&mut self, route: &Route<T>) {
+        for key in keys(route) {
+            self.segments.entry(key).or_default().insert(route.id().to_string());
+        }
+    }
+
+    pub fn remove<T>(&mut self, route: &Route<T>) {
+        for key in keys(route) {
+            if let Some(ids) = self.segments.get_mut(&key) {
+                ids.remove(route.id());
+
+                if ids.is_empty() {
+                    self.segments.remove(&key);
+                }
+            }
+        }
+    }
+
+    /// Ids of routes whose source or target has the same first segment as this path, and of
+    /// routes whose source starts with a marker
+    pub fn find(&self, path: &str) -> HashSet<&String> {
+        let mut ids = HashSet::new();
+
+        for key in [Some(first_segment(path).to_lowercase()), None] {
+            if let Some(found) = self.segments.get(&key) {
+                ids.extend(found);
+            }
+        }
+
+        ids
+    }
+}
+
+fn keys<T>(route: &Route<T>) -> Vec<Option<String>> {
+    let source = match route.path_and_query() {
+        StaticOrDynamic::Static(path) => Some(first_segment(path).to_lowercase()),
+        StaticOrDynamic::Dynamic(marker_string) => literal_first_segment(marker_string.regex.as_str()),
+    };
+
+    let mut keys = vec![source];
+
+    if let Some(target_path) = route.target_path() {
+        keys.push(Some(first_segment(target_path).to_lowercase()));
+    }
+
+    keys
+}
+
+fn first_segment(path: &str) -> &str {
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+
+    path.split('/').find(|segment| !segment.is_empty()).unwrap_or_default()
+}
+
+// First segment of an escaped path regex, when it contains no marker
+fn literal_first_segment(regex: &str) -> Option<String> {
+    let mut segment = String::new();
+    let mut chars = regex.trim_start_matches('/').chars();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '/' => break,
+            '\\' => match chars.next()? {
+                '?' | '#' => break,
+                escaped => segment.push(escaped),
+            },
+            '(' | '[' | '.' | '*' | '+' | '|' | '^' | '$' | '{' => return None,
+            _ => segment.push(char),
+        }
+    }
+
+    Some(segment.to_lowercase())
+}
@@ -29,6 +29,7 @@ pub struct DateTimeMatcher<T> {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 #[serde(tag = "type", content = "date_time_type")]
 pub enum DateTimeCondition {
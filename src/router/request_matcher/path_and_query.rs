@@ -17,16 +17,27 @@ use crate::{
 
 #[derive(Debug, Clone)]
 pub struct PathAndQueryMatcher<T> {
+    config: Arc<RouterConfig>,
+    // Routes using the case sensitivity of the router config
+    default_case: PathAndQueryStorage<T>,
+    // Routes overriding the case sensitivity of the router config
+    overridden_case: PathAndQueryStorage<T>,
+    count: usize,
+}
+
+#[derive(Debug, Clone)]
+struct PathAndQueryStorage<T> {
+    ignore_case: bool,
     regex_tree_rule: RegexTreeMap<Arc<Route<T>>>,
     static_rules: HashMap<String, HashMap<String, Arc<Route<T>>>>,
-    count: usize,
 }
 
 impl<T> PathAndQueryMatcher<T> {
     pub fn new(config: Arc<RouterConfig>) -> Self {
         PathAndQueryMatcher {
-            regex_tree_rule: RegexTreeMap::new(config.ignore_path_and_query_case),
-            static_rules: HashMap::new(),
+            default_case: PathAndQueryStorage::new(config.ignore_path_and_query_case),
+            overridden_case: PathAndQueryStorage::new(!config.ignore_path_and_query_case),
+            config,
             count: 0,
         }
     }
@@ -34,6 +45,85 @@ impl<T> PathAndQueryMatcher<T> {
     pub fn insert(&mut self, route: Arc<Route<T>>) {
         self.count += 1;
 
+        if route.ignore_path_and_query_case() == self.config.ignore_path_and_query_case {
+            self.default_case.insert(route);
+        } else {
+            self.overridden_case.insert(route);
+        }
+    }
+
+    pub fn batch_remove(&mut self, ids: &HashSet<String>) -> bool {
+        self.default_case.batch_remove(ids);
+        self.overridden_case.batch_remove(ids);
+
+        self.default_case.is_empty() && self.overridden_case.is_empty()
+    }
+
+    pub fn remove(&mut self, id: &str) -> Option<Arc<Route<T>>> {
+        let removed = self.default_case.remove(id).or_else(|| self.overridden_case.remove(id));
+
+        if removed.is_some() {
+            self.count -= 1;
+        }
+
+        removed
+    }
+
+    pub fn match_request(&self, request: &Request) -> Vec<Arc<Route<T>>> {
+        let mut routes = self.default_case.match_path(request.path_and_query().as_str());
+
+        if !self.overridden_case.is_empty() {
+            routes.extend(self.overridden_case.match_path(self.overridden_path(request).as_str()));
+        }
+
+        routes
+    }
+
+    pub fn trace(&self, request: &Request) -> Vec<Trace<T>> {
+        let mut traces = self.default_case.trace(request.path_and_query());
+
+        if !self.overridden_case.is_empty() {
+            traces.extend(self.overridden_case.trace(self.overridden_path(request)));
+        }
+
+        traces
+    }
+
+    pub fn cache(&mut self, limit: u64, level: u64) -> u64 {
+        let limit = self.default_case.regex_tree_rule.cache(limit, Some(level));
+
+        self.overridden_case.regex_tree_rule.cache(limit, Some(level))
+    }
+
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// The request path is normalized with the case sensitivity of the router config, build
+    /// the other variant from the path before normalization
+    fn overridden_path(&self, request: &Request) -> String {
+        if self.overridden_case.ignore_case {
+            request.path_and_query_skipped.path_and_query.to_lowercase()
+        } else {
+            request.path_and_query_skipped.path_and_query.clone()
+        }
+    }
+}
+
+impl<T> PathAndQueryStorage<T> {
+    fn new(ignore_case: bool) -> Self {
+        PathAndQueryStorage {
+            ignore_case,
+            regex_tree_rule: RegexTreeMap::new(ignore_case),
+            static_rules: HashMap::new(),
+        }
+    }
+
+    fn insert(&mut self, route: Arc<Route<T>>) {
         match route.path_and_query() {
             StaticOrDynamic::Static(path) => {
                 if !self.static_rules.contains_key(path) {
@@ -51,7 +141,7 @@ impl<T> PathAndQueryMatcher<T> {
         }
     }
 
-    pub fn batch_remove(&mut self, ids: &HashSet<String>) -> bool {
+    fn batch_remove(&mut self, ids: &HashSet<String>) {
         self.static_rules.retain(|_, matcher| {
             matcher.retain(|id, _| !ids.contains(id));
 
@@ -59,18 +149,11 @@ impl<T> PathAndQueryMatcher<T> {
         });
 
         self.regex_tree_rule.retain(&|id, _| !ids.contains(id));
-
-        self.static_rules.is_empty() && self.regex_tree_rule.is_empty()
     }
 
-    pub fn remove(&mut self, id: &str) -> Option<Arc<Route<T>>> {
-        match self.regex_tree_rule.remove(id) {
-            None => (),
-            Some(route) => {
-                self.count -= 1;
-
-                return Some(route);
-            }
+    fn remove(&mut self, id: &str) -> Option<Arc<Route<T>>> {
+        if let Some(route) = self.regex_tree_rule.remove(id) {
+            return Some(route);
         }
 
         let mut removed = None;
@@ -85,23 +168,17 @@ impl<T> PathAndQueryMatcher<T> {
             !matcher.is_empty()
         });
 
-        if removed.is_some() {
-            self.count -= 1;
-        }
-
         removed
     }
 
-    pub fn match_request(&self, request: &Request) -> Vec<Arc<Route<T>>> {
-        let path = request.path_and_query();
-        let mut routes: Vec<Arc<Route<T>>> = self
-            .regex_tree_rule
-            .find(path.as_str())
-            .iter()
-            .map(|route| (*route).clone())
-            .collect();
-
-        match self.static_rules.get(path.as_str()) {
+    fn is_empty(&self) -> bool {
+        self.static_rules.is_empty() && self.regex_tree_rule.is_empty()
+    }
+
+    fn match_path(&self, path: &str) -> Vec<Arc<Route<T>>> {
+        let mut routes: Vec<Arc<Route<T>>> = self.regex_tree_rule.find(path).iter().map(|route| (*route).clone()).collect();
+
+        match self.static_rules.get(path) {
             None => (),
             Some(static_storage) => {
                 routes.extend(static_storage.values().cloned().collect::<Vec<Arc<Route<T>>>>());
@@ -111,8 +188,7 @@ impl<T> PathAndQueryMatcher<T> {
         routes
     }
 
-    pub fn trace(&self, request: &Request) -> Vec<Trace<T>> {
-        let path = request.path_and_query();
+    fn trace(&self, path: String) -> Vec<Trace<T>> {
         let trace = tree_trace_to_trace(path.as_str(), self.regex_tree_rule.trace(path.as_str()));
 
         let mut traces = vec![Trace::new(
@@ -120,7 +196,9 @@ impl<T> PathAndQueryMatcher<T> {
             true,
             trace.count,
             vec![trace],
-            TraceInfo::PathAndQueryRegex,
+            TraceInfo::PathAndQueryRegex {
+                ignore_case: self.ignore_case,
+            },
         )];
 
         let static_traces = match self.static_rules.get(path.as_str()) {
@@ -143,23 +221,14 @@ impl<T> PathAndQueryMatcher<T> {
             true,
             self.static_rules.len() as u64,
             static_traces,
-            TraceInfo::PathAndQueryStatic { request: path },
+            TraceInfo::PathAndQueryStatic {
+                request: path,
+                ignore_case: self.ignore_case,
+            },
         ));
 
         traces
     }
-
-    pub fn cache(&mut self, limit: u64, level: u64) -> u64 {
-        self.regex_tree_rule.cache(limit, Some(level))
-    }
-
-    pub fn len(&self) -> usize {
-        self.count
-    }
-
-    pub fn is_empty(&self) -> bool {
-        self.count == 0
-    }
 }
 
 fn tree_trace_to_trace<T>(haystack: &str, tree_trace: TreeTrace<Arc<Route<T>>>) -> Trace<T> {
@@ -204,13 +273,22 @@ impl<V> DotBuilder for PathAndQueryMatcher<V> {
         *id += 1;
         graph.add_node(Node::new(&node_name).label("path matcher"));
 
-        if let Some(key) = self.regex_tree_rule.graph(id, graph) {
-            graph.add_edge(Edge::new(&node_name, &key, "regex tree"));
-        }
+        for storage in [&self.default_case, &self.overridden_case] {
+            let case = if storage.ignore_case {
+                "case insensitive"
+            } else {
+                "case sensitive"
+            };
 
-        let static_node_name = format!("static_matcher_{}", id);
-        graph.add_node(Node::new(static_node_name.as_str()));
-        graph.add_edge(Edge::new(&node_name, &static_node_name, "static rules"));
+            if let Some(key) = storage.regex_tree_rule.graph(id, graph) {
+                graph.add_edge(Edge::new(&node_name, &key, format!("regex tree ({case})").as_str()));
+            }
+
+            let static_node_name = format!("static_matcher_{}", id);
+            *id += 1;
+            graph.add_node(Node::new(static_node_name.as_str()));
+            graph.add_edge(Edge::new(&node_name, &static_node_name, format!("static rules ({case})").as_str()));
+        }
 
         Some(node_name)
     }
@@ -12,6 +12,7 @@ use crate::dot::DotBuilder;
 use crate::{http::Request, marker::StaticOrDynamic, router::RouterConfig};
 
 #[derive(Serialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Route<T> {
     handler: T,
     scheme: Option<String>,
@@ -19,6 +20,7 @@ pub struct Route<T> {
     methods: Option<Vec<String>>,
     exclude_methods: Option<bool>,
     path_and_query: StaticOrDynamic,
+    ignore_path_and_query_case: bool,
     headers: Vec<RouteHeader>,
     ips: Option<Vec<RouteIp>>,
     datetime: Option<Vec<RouteDateTime>>,
@@ -26,6 +28,8 @@ pub struct Route<T> {
     weekdays: Option<RouteWeekday>,
     id: String,
     priority: i64,
+    #[serde(skip)]
+    target_path: Option<String>,
 }
 
 impl<T> Route<T> {
@@ -36,6 +40,7 @@ impl<T> Route<T> {
         scheme: Option<String>,
         host: Option<StaticOrDynamic>,
         path_and_query: StaticOrDynamic,
+        ignore_path_and_query_case: bool,
         headers: Vec<RouteHeader>,
         ips: Option<Vec<RouteIp>>,
         datetime: Option<Vec<RouteDateTime>>,
@@ -52,6 +57,7 @@ impl<T> Route<T> {
             methods,
             exclude_methods,
             path_and_query,
+            ignore_path_and_query_case,
             headers,
             ips,
             datetime,
@@ -59,9 +65,16 @@ impl<T> Route<T> {
             weekdays,
             id,
             priority,
+            target_path: None,
         }
     }
 
+    /// Set the path this route redirects to, so the router can find it by its target
+    pub fn with_target_path(mut self, target_path: Option<String>) -> Route<T> {
+        self.target_path = target_path;
+        self
+    }
+
     pub fn handler(&self) -> &T {
         &self.handler
     }
@@ -94,6 +107,10 @@ impl<T> Route<T> {
         &self.path_and_query
     }
 
+    pub fn ignore_path_and_query_case(&self) -> bool {
+        self.ignore_path_and_query_case
+    }
+
     pub fn ips(&self) -> Option<&Vec<RouteIp>> {
         self.ips.as_ref()
     }
@@ -114,14 +131,23 @@ impl<T> Route<T> {
         self.id.as_str()
     }
 
+    pub fn target_path(&self) -> Option<&str> {
+        self.target_path.as_deref()
+    }
+
     pub fn capture(&self, request: &Request) -> HashMap<String, String> {
+        self.try_capture(request).unwrap_or_default()
+    }
+
+    /// Captures markers of the request, or `None` when a typed marker rejects its value.
+    pub fn try_capture(&self, request: &Request) -> Option<HashMap<String, String>> {
         let path = request.path_and_query_skipped.path_and_query.as_str();
-        let mut parameters = self.path_and_query().capture(path);
+        let mut parameters = self.path_and_query().try_capture(path)?;
 
         if let Some(host) = self.host()
             && let Some(request_host) = request.host.as_ref()
         {
-            parameters.extend(host.capture(request_host));
+            parameters.extend(host.try_capture(request_host)?);
         }
 
         for header in self.headers() {
@@ -130,11 +156,19 @@ impl<T> Route<T> {
                     continue;
                 }
 
-                parameters.extend(header.capture(request_header.value.as_str()));
+                parameters.extend(header.try_capture(request_header.value.as_str())?);
             }
         }
 
-        parameters
+        Some(parameters)
+    }
+
+    /// Whether this route has typed markers that must be checked on captured values, as the
+    /// request matchers only check regexes.
+    pub fn has_marker_validators(&self) -> bool {
+        self.path_and_query.has_validators()
+            || self.host.as_ref().is_some_and(|host| host.has_validators())
+            || self.headers.iter().any(|header| header.has_validators())
     }
 
     pub fn compile(&self) -> u8 {
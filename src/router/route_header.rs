@@ -5,6 +5,7 @@ use serde::Serialize;
 use crate::marker::MarkerString;
 
 #[derive(Serialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum RouteHeaderKind {
     IsDefined,
     IsNotDefined,
@@ -18,6 +19,7 @@ pub enum RouteHeaderKind {
 }
 
 #[derive(Serialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RouteHeader {
     pub kind: RouteHeaderKind,
     pub name: String,
@@ -30,4 +32,18 @@ impl RouteHeader {
             _ => HashMap::new(),
         }
     }
+
+    pub fn try_capture(&self, str: &str) -> Option<HashMap<String, String>> {
+        match &self.kind {
+            RouteHeaderKind::MatchRegex(marker_string) => marker_string.try_capture(str),
+            _ => Some(HashMap::new()),
+        }
+    }
+
+    pub fn has_validators(&self) -> bool {
+        match &self.kind {
+            RouteHeaderKind::MatchRegex(marker_string) => marker_string.has_validators(),
+            _ => false,
+        }
+    }
 }
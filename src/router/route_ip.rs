@@ -4,9 +4,10 @@ use cidr::AnyIpCidr;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Hash, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum RouteIp {
-    InRange(AnyIpCidr),
-    NotInRange(AnyIpCidr),
+    InRange(#[cfg_attr(feature = "schema", schemars(with = "String"))] AnyIpCidr),
+    NotInRange(#[cfg_attr(feature = "schema", schemars(with = "String"))] AnyIpCidr),
     NotOneOf(Vec<IpAddr>),
 }
 
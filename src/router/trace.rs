@@ -8,6 +8,7 @@ use super::{
 };
 
 #[derive(Serialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RouteTrace<T> {
     traces: Vec<Trace<T>>,
     routes: Vec<Arc<Route<T>>>,
@@ -15,6 +16,7 @@ pub struct RouteTrace<T> {
 }
 
 #[derive(Serialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Trace<T> {
     pub(crate) matched: bool,
     pub(crate) executed: bool,
@@ -25,6 +27,7 @@ pub struct Trace<T> {
 }
 
 #[derive(Serialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 #[serde(tag = "type")]
 pub enum TraceInfo<T> {
@@ -36,13 +39,14 @@ pub enum TraceInfo<T> {
     Method { request: String, against: Option<String> },
     ExcludeMethods { request: String, against: Option<Vec<String>> },
     HeaderGroup { conditions: Vec<TraceInfoHeaderCondition> },
-    PathAndQueryStatic { request: String },
-    PathAndQueryRegex,
+    PathAndQueryStatic { request: String, ignore_case: bool },
+    PathAndQueryRegex { ignore_case: bool },
     Regex { request: String, against: String },
     Storage { routes: Vec<Arc<Route<T>>> },
 }
 
 #[derive(Serialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TraceInfoHeaderCondition {
     pub result: Option<bool>,
     pub name: String,
@@ -51,6 +55,7 @@ pub struct TraceInfoHeaderCondition {
 }
 
 #[derive(Serialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TraceInfoDateTimeCondition {
     pub result: Option<bool>,
     pub condition: DateTimeCondition,
@@ -78,6 +83,33 @@ impl<T> Trace<T> {
         }
     }
 
+    /// Remove routes rejected by the predicate from the storages of this trace, the trace is no
+    /// longer matched when all the routes it led to are removed. Returns the number of routes
+    /// stored before and after.
+    pub(crate) fn retain_routes(&mut self, predicate: &impl Fn(&Route<T>) -> bool) -> (usize, usize) {
+        let (mut before, mut after) = (0, 0);
+
+        if let TraceInfo::Storage { routes } = &mut self.info {
+            before += routes.len();
+            routes.retain(|route| predicate(route));
+            after += routes.len();
+            self.count = routes.len() as u64;
+        }
+
+        for child in &mut self.children {
+            let (child_before, child_after) = child.retain_routes(predicate);
+
+            before += child_before;
+            after += child_after;
+        }
+
+        if before > 0 && after == 0 {
+            self.matched = false;
+        }
+
+        (before, after)
+    }
+
     pub fn get_routes_from_traces(traces: &[Trace<T>]) -> Vec<Arc<Route<T>>> {
         let mut routes = Vec::new();
 
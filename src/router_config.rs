@@ -3,6 +3,7 @@ use std::{collections::HashSet, hash::Hash};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RouterConfig {
     #[serde(default = "default_as_true")]
     pub ignore_host_case: bool,
@@ -22,6 +23,10 @@ pub struct RouterConfig {
     pub always_match_any_host: bool,
     #[serde(default = "default_as_true")]
     pub ignore_query_param_order: bool,
+    /// Number of times a request rewritten by a rule is matched again against the router,
+    /// 0 disables re-matching
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub max_rewrite_passes: u8,
 }
 
 impl Hash for RouterConfig {
@@ -34,6 +39,7 @@ impl Hash for RouterConfig {
         self.pass_marketing_query_params_to_target.hash(state);
         self.always_match_any_host.hash(state);
         self.ignore_query_param_order.hash(state);
+        self.max_rewrite_passes.hash(state);
 
         // order hash set to make sure it's always the same
         let mut marketing_query_params: Vec<String> = self.marketing_query_params.iter().cloned().collect();
@@ -51,6 +57,10 @@ fn default_as_true() -> bool {
     true
 }
 
+fn is_zero(value: &u8) -> bool {
+    *value == 0
+}
+
 fn default_marketing_parameters() -> HashSet<String> {
     let mut parameters = HashSet::new();
 
@@ -83,6 +93,7 @@ impl Default for RouterConfig {
             pass_marketing_query_params_to_target: true,
             always_match_any_host: true,
             ignore_query_param_order: true,
+            max_rewrite_passes: 0,
         }
     }
 }
@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+
+use schemars::{JsonSchema, Schema, generate::SchemaSettings};
+
+use crate::{
+    action::{Action, UnitTrace},
+    api::{
+        ExplainRequestInput, ExplainRequestOutput, ExplainRequestProjectInput, GenerateExamplesInput, GenerateExamplesOutput,
+        GenerateExamplesProjectInput, ImpactInput, ImpactOutput, ImpactProjectInput, Log, Rule, RuleChangeSet, RulesMessage,
+        SuggestRulesInput, SuggestRulesOutput, SuggestRulesProjectInput, TestExamplesInput, TestExamplesOutput, TestExamplesProjectInput,
+        UnitIdsInput, UnitIdsOutput, UnitIdsProjectInput,
+    },
+    router_config::RouterConfig,
+};
+
+/// JSON Schemas of the payloads read by this library, keyed by type name
+///
+/// Schemas describe what is accepted when deserializing: fields with a default are optional
+pub fn input_schemas() -> BTreeMap<&'static str, Schema> {
+    let settings = SchemaSettings::draft2020_12();
+    let mut schemas = BTreeMap::new();
+
+    insert::<Action>(&mut schemas, &settings, "Action");
+    insert::<ExplainRequestInput>(&mut schemas, &settings, "ExplainRequestInput");
+    insert::<ExplainRequestProjectInput>(&mut schemas, &settings, "ExplainRequestProjectInput");
+    insert::<GenerateExamplesInput>(&mut schemas, &settings, "GenerateExamplesInput");
+    insert::<GenerateExamplesProjectInput>(&mut schemas, &settings, "GenerateExamplesProjectInput");
+    insert::<ImpactInput>(&mut schemas, &settings, "ImpactInput");
+    insert::<ImpactProjectInput>(&mut schemas, &settings, "ImpactProjectInput");
+    insert::<RouterConfig>(&mut schemas, &settings, "RouterConfig");
+    insert::<Rule>(&mut schemas, &settings, "Rule");
+    insert::<RuleChangeSet>(&mut schemas, &settings, "RuleChangeSet");
+    insert::<RulesMessage>(&mut schemas, &settings, "RulesMessage");
+    insert::<SuggestRulesInput>(&mut schemas, &settings, "SuggestRulesInput");
+    insert::<SuggestRulesProjectInput>(&mut schemas, &settings, "SuggestRulesProjectInput");
+    insert::<TestExamplesInput>(&mut schemas, &settings, "TestExamplesInput");
+    insert::<TestExamplesProjectInput>(&mut schemas, &settings, "TestExamplesProjectInput");
+    insert::<UnitIdsInput>(&mut schemas, &settings, "UnitIdsInput");
+    insert::<UnitIdsProjectInput>(&mut schemas, &settings, "UnitIdsProjectInput");
+
+    schemas
+}
+
+/// JSON Schemas of the payloads written by this library, keyed by type name
+///
+/// Schemas describe what is produced when serializing: fields skipped when empty are optional
+pub fn output_schemas() -> BTreeMap<&'static str, Schema> {
+    let settings = SchemaSettings::draft2020_12().for_serialize();
+    let mut schemas = BTreeMap::new();
+
+    insert::<Action>(&mut schemas, &settings, "Action");
+    insert::<ExplainRequestOutput>(&mut schemas, &settings, "ExplainRequestOutput");
+    insert::<GenerateExamplesOutput>(&mut schemas, &settings, "GenerateExamplesOutput");
+    insert::<ImpactOutput>(&mut schemas, &settings, "ImpactOutput");
+    insert::<Log>(&mut schemas, &settings, "Log");
+    insert::<Rule>(&mut schemas, &settings, "Rule");
+    insert::<SuggestRulesOutput>(&mut schemas, &settings, "SuggestRulesOutput");
+    insert::<TestExamplesOutput>(&mut schemas, &settings, "TestExamplesOutput");
+    insert::<UnitIdsOutput>(&mut schemas, &settings, "UnitIdsOutput");
+    insert::<UnitTrace>(&mut schemas, &settings, "UnitTrace");
+
+    schemas
+}
+
+fn insert<T: JsonSchema>(schemas: &mut BTreeMap<&'static str, Schema>, settings: &SchemaSettings, name: &'static str) {
+    schemas.insert(name, settings.clone().into_generator().into_root_schema_for::<T>());
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::{input_schemas, output_schemas};
+
+    fn definition(schema: &Value, name: &str) -> Value {
+        schema["$defs"][name].clone()
+    }
+
+    #[test]
+    fn untagged_fallbacks_accept_any_value() {
+        let rule = input_schemas()["Rule"].as_value().clone();
+        let body_filter = definition(&rule, "BodyFilter");
+        let variable_kind = definition(&rule, "VariableKind");
+
+        assert!(body_filter["anyOf"].as_array().unwrap().contains(&json!(true)));
+        assert!(variable_kind["anyOf"].as_array().unwrap().contains(&json!(true)));
+    }
+
+    #[test]
+    fn status_code_ranges_are_numbers_or_strings() {
+        let action = output_schemas()["Action"].as_value().clone();
+        let range = definition(&action, "StatusCodeRange");
+
+        assert_eq!(range["anyOf"][0]["type"], "integer");
+        assert_eq!(range["anyOf"][1]["type"], "string");
+    }
+
+    #[test]
+    fn schemas_are_exported_for_inputs_and_outputs() {
+        let inputs = input_schemas();
+        let outputs = output_schemas();
+
+        for name in ["ExplainRequestInput", "ImpactInput", "TestExamplesInput", "Rule", "Action"] {
+            assert!(inputs.contains_key(name), "{name}");
+        }
+
+        for name in ["ExplainRequestOutput", "ImpactOutput", "TestExamplesOutput", "Rule", "Action"] {
+            assert!(outputs.contains_key(name), "{name}");
+        }
+
+        assert_eq!(inputs["Rule"].as_value()["title"], Value::from("Rule"));
+    }
+}
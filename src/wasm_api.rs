@@ -1,3 +1,5 @@
+#[cfg(feature = "router")]
+use std::sync::Arc;
 use std::{
     collections::hash_map::DefaultHasher,
     hash::{Hash, Hasher},
@@ -15,6 +17,8 @@ use crate::{
     filter::FilterBodyAction,
     http::{Addr, Header, PathAndQueryWithSkipped, Request as RedirectionioRequest},
 };
+#[cfg(feature = "router")]
+use crate::{api::Rule, router::Router};
 
 #[wasm_bindgen()]
 pub struct Request {
@@ -40,6 +44,13 @@ pub struct BodyFilter {
     pub filter: Option<FilterBodyAction>,
 }
 
+#[cfg(feature = "router")]
+#[wasm_bindgen()]
+pub struct LinkRouter {
+    #[wasm_bindgen(skip)]
+    pub router: Option<Arc<Router<Rule>>>,
+}
+
 #[wasm_bindgen()]
 impl Request {
     #[wasm_bindgen(constructor)]
@@ -176,6 +187,15 @@ impl Action {
         0
     }
 
+    /// Status code with the headers of the backend response, needed by rules conditioned on them
+    pub fn get_status_code_with_response_headers(&mut self, response_status_code: u16, headers: &HeaderMap) -> u16 {
+        if let Some(action) = self.action.as_mut() {
+            return action.get_status_code_with_response_headers(response_status_code, Some(&headers.headers), None);
+        }
+
+        0
+    }
+
     pub fn filter_headers(&mut self, headers: HeaderMap, response_status_code: u16, add_rule_ids_header: bool) -> HeaderMap {
         if self.action.is_none() {
             return headers;
@@ -187,6 +207,90 @@ impl Action {
         HeaderMap { headers: new_headers }
     }
 
+    pub fn filter_request_headers(&mut self, headers: HeaderMap) -> HeaderMap {
+        if self.action.is_none() {
+            return headers;
+        }
+
+        let action = self.action.as_mut().unwrap();
+        let new_headers = action.filter_request_headers(headers.headers, None);
+
+        HeaderMap { headers: new_headers }
+    }
+
+    /// Peer to retry the request on as JSON, `attempt` being the number of fallback peers already tried
+    pub fn get_retry_peer(&mut self, method: String, response_status_code: u16, attempt: usize) -> Option<String> {
+        let action = self.action.as_mut()?;
+        let peer = action.get_retry_peer(method.as_str(), response_status_code, attempt, None)?;
+
+        match json_encode(peer) {
+            Err(err) => {
+                tracing::error!("unable to serialize retry peer: {}", err);
+
+                None
+            }
+            Ok(peer_serialized) => Some(peer_serialized),
+        }
+    }
+
+    /// Same as `get_retry_peer`, with the headers of the backend response needed by rules conditioned on them
+    pub fn get_retry_peer_with_response_headers(
+        &mut self,
+        method: String,
+        response_status_code: u16,
+        headers: &HeaderMap,
+        attempt: usize,
+    ) -> Option<String> {
+        let action = self.action.as_mut()?;
+        let peer =
+            action.get_retry_peer_with_response_headers(method.as_str(), response_status_code, Some(&headers.headers), attempt, None)?;
+
+        match json_encode(peer) {
+            Err(err) => {
+                tracing::error!("unable to serialize retry peer: {}", err);
+
+                None
+            }
+            Ok(peer_serialized) => Some(peer_serialized),
+        }
+    }
+
+    /// Look up the links found by body filters in the router, before creating the body filter
+    #[cfg(feature = "router")]
+    pub fn set_link_router(&mut self, link_router: &LinkRouter, request_url: Option<String>, max_link_lookups: Option<usize>) {
+        if let (Some(action), Some(router)) = (self.action.as_mut(), link_router.router.as_ref()) {
+            action.set_link_router(router.clone(), request_url.as_deref(), max_link_lookups);
+        }
+    }
+
+    /// Path and query to send to the upstream instead of the requested ones
+    pub fn get_rewrite(&mut self) -> Option<String> {
+        self.action.as_mut()?.get_rewrite(None).map(str::to_string)
+    }
+
+    pub fn synthetic_response_status_code(&mut self) -> u16 {
+        match self.action.as_mut().and_then(|action| action.get_synthetic_response(None)) {
+            None => 0,
+            Some(synthetic_response) => synthetic_response.status_code,
+        }
+    }
+
+    pub fn synthetic_response_headers(&mut self) -> HeaderMap {
+        match self.action.as_mut().and_then(|action| action.get_synthetic_response(None)) {
+            None => HeaderMap { headers: Vec::new() },
+            Some(synthetic_response) => HeaderMap {
+                headers: synthetic_response.headers.clone(),
+            },
+        }
+    }
+
+    pub fn synthetic_response_body(&mut self) -> String {
+        match self.action.as_mut().and_then(|action| action.get_synthetic_response(None)) {
+            None => "".to_string(),
+            Some(synthetic_response) => synthetic_response.body.clone(),
+        }
+    }
+
     pub fn create_body_filter(&mut self, response_status_code: u16, headers: &HeaderMap) -> BodyFilter {
         if self.action.is_none() {
             return BodyFilter { filter: None };
@@ -208,6 +312,18 @@ impl Action {
         action.should_log_request(true, response_status_code, None)
     }
 
+    /// Whether to log the request with the headers of the backend response, needed by rules
+    /// conditioned on them
+    pub fn should_log_request_with_response_headers(&mut self, response_status_code: u16, headers: &HeaderMap) -> bool {
+        if self.action.is_none() {
+            return true;
+        }
+
+        let action = self.action.as_mut().unwrap();
+
+        action.should_log_request_with_response_headers(true, response_status_code, Some(&headers.headers), None)
+    }
+
     pub fn need_proxification(&self) -> bool {
         if let Some(action) = &self.action {
             return action.need_proxification();
@@ -238,6 +354,45 @@ impl BodyFilter {
     }
 }
 
+#[cfg(feature = "router")]
+#[wasm_bindgen()]
+impl LinkRouter {
+    /// Router where body filters look up links, from a JSON array of rules and a JSON router config
+    #[wasm_bindgen(constructor)]
+    pub fn new(rules: String, config: Option<String>) -> LinkRouter {
+        let rules: Vec<Rule> = match json_decode(rules.as_str()) {
+            Err(error) => {
+                tracing::error!("unable to deserialize rules of link router: {}", error);
+
+                return LinkRouter { router: None };
+            }
+            Ok(rules) => rules,
+        };
+
+        let config = match config.as_deref().map(json_decode::<RouterConfig>) {
+            None => RouterConfig::default(),
+            Some(Ok(config)) => config,
+            Some(Err(error)) => {
+                tracing::error!("unable to deserialize router config: {}", error);
+
+                return LinkRouter { router: None };
+            }
+        };
+
+        let mut router = Router::<Rule>::from_config(config);
+
+        for rule in rules {
+            router.insert(rule);
+        }
+
+        router.cache(None);
+
+        LinkRouter {
+            router: Some(Arc::new(router)),
+        }
+    }
+}
+
 #[wasm_bindgen()]
 pub fn init_log() {
     wasm_tracing::set_as_global_default();
@@ -269,10 +424,23 @@ pub fn create_log_in_json(
             Some(proxy_response_time.into())
         },
         client_ip.as_str(),
-    );
+    )
+    .with_weight(action.action.as_ref().and_then(|a| a.get_log_weight()));
 
     match json_encode(&log) {
         Err(_) => "".to_string(),
         Ok(s) => s,
     }
 }
+
+/// JSON Schemas of the payloads read and written by this package, as `{"input": {...}, "output": {...}}`
+#[cfg(feature = "schema")]
+#[wasm_bindgen()]
+pub fn json_schemas() -> String {
+    let schemas = serde_json::json!({
+        "input": crate::schema::input_schemas(),
+        "output": crate::schema::output_schemas(),
+    });
+
+    schemas.to_string()
+}
@@ -40,7 +40,7 @@ fn test_00_common_rules_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -51,7 +51,7 @@ fn test_00_common_rules_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -136,7 +136,7 @@ fn test_01_straight_rule_match_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -147,7 +147,7 @@ fn test_01_straight_rule_match_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/example.org--host-path-only"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -166,7 +166,7 @@ fn test_01_straight_rule_match_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -177,7 +177,7 @@ fn test_01_straight_rule_match_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/example.org--host-path-query"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -214,7 +214,7 @@ fn test_01_straight_rule_match_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -225,7 +225,7 @@ fn test_01_straight_rule_match_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/empty"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -262,7 +262,7 @@ fn test_01_straight_rule_match_6() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -273,7 +273,7 @@ fn test_01_straight_rule_match_6() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/any-host--path-query"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -292,7 +292,7 @@ fn test_01_straight_rule_match_7() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -303,7 +303,7 @@ fn test_01_straight_rule_match_7() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/example.net--host-path-only"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -322,7 +322,7 @@ fn test_01_straight_rule_match_8() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -333,7 +333,7 @@ fn test_01_straight_rule_match_8() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/example.net--host-path-query"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -352,7 +352,7 @@ fn test_01_straight_rule_match_9() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -363,7 +363,7 @@ fn test_01_straight_rule_match_9() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/space"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -382,7 +382,7 @@ fn test_01_straight_rule_match_10() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -393,7 +393,7 @@ fn test_01_straight_rule_match_10() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/space"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -412,7 +412,7 @@ fn test_01_straight_rule_match_11() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -423,7 +423,7 @@ fn test_01_straight_rule_match_11() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/plus-sign"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_03_priority_match() -> Router<Rule> {
@@ -469,7 +469,7 @@ fn test_03_priority_match_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -480,7 +480,7 @@ fn test_03_priority_match_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/straight-example-org"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -499,7 +499,7 @@ fn test_03_priority_match_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -510,7 +510,7 @@ fn test_03_priority_match_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/straight-any-host"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -529,7 +529,7 @@ fn test_03_priority_match_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -540,7 +540,7 @@ fn test_03_priority_match_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/straight-example-net"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -559,7 +559,7 @@ fn test_03_priority_match_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -570,7 +570,7 @@ fn test_03_priority_match_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/straight-example-fr"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_04_rfc3986_relative_references() -> Router<Rule> {
@@ -607,7 +607,7 @@ fn test_04_rfc3986_relative_references_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -618,7 +618,7 @@ fn test_04_rfc3986_relative_references_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/xyz"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -655,7 +655,7 @@ fn test_04_rfc3986_relative_references_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -666,7 +666,7 @@ fn test_04_rfc3986_relative_references_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"//target"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -685,7 +685,7 @@ fn test_04_rfc3986_relative_references_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -696,7 +696,7 @@ fn test_04_rfc3986_relative_references_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/simpledragon"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_05_query_parameters_order() -> Router<Rule> {
@@ -730,7 +730,7 @@ fn test_05_query_parameters_order_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -741,7 +741,7 @@ fn test_05_query_parameters_order_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -760,7 +760,7 @@ fn test_05_query_parameters_order_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -771,7 +771,7 @@ fn test_05_query_parameters_order_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -808,7 +808,7 @@ fn test_05_query_parameters_order_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -819,7 +819,7 @@ fn test_05_query_parameters_order_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar-inverted"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -838,7 +838,7 @@ fn test_05_query_parameters_order_5() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -849,7 +849,7 @@ fn test_05_query_parameters_order_5() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar-inverted"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_06_emojis() -> Router<Rule> {
@@ -880,7 +880,7 @@ fn test_06_emojis_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -891,7 +891,7 @@ fn test_06_emojis_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_07_internationalized_domain_name() -> Router<Rule> {
@@ -925,7 +925,7 @@ fn test_07_internationalized_domain_name_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -936,7 +936,7 @@ fn test_07_internationalized_domain_name_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -955,7 +955,7 @@ fn test_07_internationalized_domain_name_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -966,7 +966,7 @@ fn test_07_internationalized_domain_name_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar2"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_custom_body() -> Router<Rule> {
@@ -1000,7 +1000,7 @@ fn test_action_custom_body_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -1013,7 +1013,7 @@ fn test_action_custom_body_1() {
     let mut new_body = body_filter.filter(r#""#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"{"pets":[{"name":"Jo","species":"Parrot","birthYear":2016},{"name":"Charlotte","species":"Cat","birthYear":2008}]}"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
     let mut response_headers = Vec::new();
 
     response_headers.push(Header {
@@ -1046,7 +1046,7 @@ fn test_action_custom_body_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -1059,7 +1059,7 @@ fn test_action_custom_body_2() {
     let mut new_body = body_filter.filter(r#""#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"{"pets":[{"name":"Jo","species":"Parrot","birthYear":2016},{"name":"Charlotte","species":"Cat","birthYear":2008}]}"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
     let mut response_headers = Vec::new();
 
     response_headers.push(Header {
@@ -1107,12 +1107,12 @@ fn test_action_disable_log_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 0);
-    assert_eq!(action.should_log_request(true, response_status_code, None), false);
+    assert!(!action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -1149,12 +1149,12 @@ fn test_action_disable_log_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 0);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_filter_header_add() -> Router<Rule> {
@@ -1185,12 +1185,12 @@ fn test_action_filter_header_add_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 0);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
     let mut response_headers = Vec::new();
 
     response_headers.push(Header {
@@ -1227,12 +1227,12 @@ fn test_action_filter_header_add_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 0);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
     let response_headers = Vec::new();
 
     let filtered_headers = action.filter_headers(response_headers, response_status_code, false, None);
@@ -1272,12 +1272,12 @@ fn test_action_filter_header_default_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 0);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
     let mut response_headers = Vec::new();
 
     response_headers.push(Header {
@@ -1310,12 +1310,12 @@ fn test_action_filter_header_default_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 0);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
     let response_headers = Vec::new();
 
     let filtered_headers = action.filter_headers(response_headers, response_status_code, false, None);
@@ -1355,12 +1355,12 @@ fn test_action_filter_header_override_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 0);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
     let mut response_headers = Vec::new();
 
     response_headers.push(Header {
@@ -1397,12 +1397,12 @@ fn test_action_filter_header_override_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 0);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
     let response_headers = Vec::new();
 
     let filtered_headers = action.filter_headers(response_headers, response_status_code, false, None);
@@ -1446,12 +1446,12 @@ fn test_action_filter_header_remove_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 0);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
     let mut response_headers = Vec::new();
 
     response_headers.push(Header {
@@ -1505,12 +1505,12 @@ fn test_action_filter_header_replace_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 0);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
     let mut response_headers = Vec::new();
 
     response_headers.push(Header {
@@ -1552,12 +1552,12 @@ fn test_action_filter_header_replace_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 0);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
     let mut response_headers = Vec::new();
 
     response_headers.push(Header {
@@ -1606,7 +1606,7 @@ fn test_action_html_after_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -1619,7 +1619,7 @@ fn test_action_html_after_1() {
     let mut new_body = body_filter.filter(r#"<html><body><h1>Title</h1></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body><h1>Title</h1><h2>After</h2></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -1638,7 +1638,7 @@ fn test_action_html_after_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -1651,7 +1651,7 @@ fn test_action_html_after_2() {
     let mut new_body = body_filter.filter(r#"<html><body></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -1670,7 +1670,7 @@ fn test_action_html_after_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -1683,7 +1683,7 @@ fn test_action_html_after_3() {
     let mut new_body = body_filter.filter(r#"<html><body><h1>Title</h1><h2>Exists</h2></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body><h1>Title</h1><h2>After</h2><h2>Exists</h2></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_html_append() -> Router<Rule> {
@@ -1714,7 +1714,7 @@ fn test_action_html_append_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -1727,7 +1727,7 @@ fn test_action_html_append_1() {
     let mut new_body = body_filter.filter(r#"<html><body></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body><h1>Append</h1></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -1746,7 +1746,7 @@ fn test_action_html_append_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -1759,7 +1759,7 @@ fn test_action_html_append_2() {
     let mut new_body = body_filter.filter(r#"<html><body><h1>Exist</h1></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body><h1>Exist</h1><h1>Append</h1></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_html_append_ignore() -> Router<Rule> {
@@ -1790,7 +1790,7 @@ fn test_action_html_append_ignore_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -1803,7 +1803,7 @@ fn test_action_html_append_ignore_1() {
     let mut new_body = body_filter.filter(r#"<html><body></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body><h1>Append</h1></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -1822,7 +1822,7 @@ fn test_action_html_append_ignore_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -1835,7 +1835,7 @@ fn test_action_html_append_ignore_2() {
     let mut new_body = body_filter.filter(r#"<html><body><h1>Exist</h1></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body><h1>Exist</h1></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_html_before() -> Router<Rule> {
@@ -1866,7 +1866,7 @@ fn test_action_html_before_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -1879,7 +1879,7 @@ fn test_action_html_before_1() {
     let mut new_body = body_filter.filter(r#"<html><body><h2>Sub title</h2></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body><h1>Before</h1><h2>Sub title</h2></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -1898,7 +1898,7 @@ fn test_action_html_before_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -1911,7 +1911,7 @@ fn test_action_html_before_2() {
     let mut new_body = body_filter.filter(r#"<html><body></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -1930,7 +1930,7 @@ fn test_action_html_before_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -1943,7 +1943,7 @@ fn test_action_html_before_3() {
     let mut new_body = body_filter.filter(r#"<html><body><h1>Exists</h1><h2>Sub title</h2></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body><h1>Exists</h1><h1>Before</h1><h2>Sub title</h2></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_html_legacy_body_end() -> Router<Rule> {
@@ -1974,7 +1974,7 @@ fn test_action_html_legacy_body_end_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -1987,7 +1987,7 @@ fn test_action_html_legacy_body_end_1() {
     let mut new_body = body_filter.filter(r#"<html><body></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body><h1>Append</h1></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -2006,7 +2006,7 @@ fn test_action_html_legacy_body_end_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2019,7 +2019,7 @@ fn test_action_html_legacy_body_end_2() {
     let mut new_body = body_filter.filter(r#"<html><body><h1>Exist</h1></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body><h1>Exist</h1><h1>Append</h1></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_html_legacy_body_start() -> Router<Rule> {
@@ -2050,7 +2050,7 @@ fn test_action_html_legacy_body_start_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2063,7 +2063,7 @@ fn test_action_html_legacy_body_start_1() {
     let mut new_body = body_filter.filter(r#"<html><body></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body><h1>Prepend</h1></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -2082,7 +2082,7 @@ fn test_action_html_legacy_body_start_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2095,7 +2095,7 @@ fn test_action_html_legacy_body_start_2() {
     let mut new_body = body_filter.filter(r#"<html><body><h1>Exist</h1></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body><h1>Prepend</h1><h1>Exist</h1></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_html_legacy_head_end() -> Router<Rule> {
@@ -2126,7 +2126,7 @@ fn test_action_html_legacy_head_end_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2139,7 +2139,7 @@ fn test_action_html_legacy_head_end_1() {
     let mut new_body = body_filter.filter(r#"<html><head></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><title>Append</title></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -2158,7 +2158,7 @@ fn test_action_html_legacy_head_end_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2171,7 +2171,7 @@ fn test_action_html_legacy_head_end_2() {
     let mut new_body = body_filter.filter(r#"<html><head><meta>Exist</meta></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta>Exist</meta><title>Append</title></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_html_legacy_head_start() -> Router<Rule> {
@@ -2202,7 +2202,7 @@ fn test_action_html_legacy_head_start_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2215,7 +2215,7 @@ fn test_action_html_legacy_head_start_1() {
     let mut new_body = body_filter.filter(r#"<html><head></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><title>Prepend</title></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -2234,7 +2234,7 @@ fn test_action_html_legacy_head_start_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2247,7 +2247,7 @@ fn test_action_html_legacy_head_start_2() {
     let mut new_body = body_filter.filter(r#"<html><head><meta>Exist</meta></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><title>Prepend</title><meta>Exist</meta></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_html_prepend() -> Router<Rule> {
@@ -2278,7 +2278,7 @@ fn test_action_html_prepend_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2291,7 +2291,7 @@ fn test_action_html_prepend_1() {
     let mut new_body = body_filter.filter(r#"<html><body></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body><h1>Prepend</h1></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -2310,7 +2310,7 @@ fn test_action_html_prepend_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2323,7 +2323,7 @@ fn test_action_html_prepend_2() {
     let mut new_body = body_filter.filter(r#"<html><body><h1>Exist</h1></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body><h1>Prepend</h1><h1>Exist</h1></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_html_remove() -> Router<Rule> {
@@ -2354,7 +2354,7 @@ fn test_action_html_remove_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2367,7 +2367,7 @@ fn test_action_html_remove_1() {
     let mut new_body = body_filter.filter(r#"<html><body><h1>Title</h1></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -2386,7 +2386,7 @@ fn test_action_html_remove_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2399,7 +2399,7 @@ fn test_action_html_remove_2() {
     let mut new_body = body_filter.filter(r#"<html><body></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_html_replace() -> Router<Rule> {
@@ -2430,7 +2430,7 @@ fn test_action_html_replace_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2443,7 +2443,7 @@ fn test_action_html_replace_1() {
     let mut new_body = body_filter.filter(r#"<html><body><h1>Title</h1></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body><h2>Replaced</h2></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -2462,7 +2462,7 @@ fn test_action_html_replace_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2475,7 +2475,7 @@ fn test_action_html_replace_2() {
     let mut new_body = body_filter.filter(r#"<html><body><h1>Title</h1><h1>Title</h1></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body><h2>Replaced</h2><h2>Replaced</h2></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -2494,7 +2494,7 @@ fn test_action_html_replace_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2507,7 +2507,7 @@ fn test_action_html_replace_3() {
     let mut new_body = body_filter.filter(r#"<html><body></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_html_replace_first() -> Router<Rule> {
@@ -2538,7 +2538,7 @@ fn test_action_html_replace_first_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2551,7 +2551,7 @@ fn test_action_html_replace_first_1() {
     let mut new_body = body_filter.filter(r#"<html><body><h1>Title</h1></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body><h2>Replaced</h2></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -2570,7 +2570,7 @@ fn test_action_html_replace_first_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2583,7 +2583,7 @@ fn test_action_html_replace_first_2() {
     let mut new_body = body_filter.filter(r#"<html><body><h1>Title</h1><h1>Title</h1></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body><h2>Replaced</h2><h1>Title</h1></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -2602,7 +2602,7 @@ fn test_action_html_replace_first_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2615,7 +2615,7 @@ fn test_action_html_replace_first_3() {
     let mut new_body = body_filter.filter(r#"<html><body></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_html_to_markdown() -> Router<Rule> {
@@ -2646,7 +2646,7 @@ fn test_action_html_to_markdown_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2660,7 +2660,7 @@ fn test_action_html_to_markdown_1() {
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"# Title
 "#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -2679,7 +2679,7 @@ fn test_action_html_to_markdown_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2692,7 +2692,7 @@ fn test_action_html_to_markdown_2() {
     let mut new_body = body_filter.filter(r#"<html><body></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#""#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -2711,7 +2711,7 @@ fn test_action_html_to_markdown_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2727,7 +2727,7 @@ fn test_action_html_to_markdown_3() {
 
 ## Exists
 "#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -2746,7 +2746,7 @@ fn test_action_html_to_markdown_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2767,7 +2767,7 @@ title: Page title
 
 ## Exists
 "#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -2786,7 +2786,7 @@ fn test_action_html_to_markdown_5() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2800,7 +2800,7 @@ fn test_action_html_to_markdown_5() {
     new_body.extend(body_filter.end(None));
     assert_eq!(&new_body, b"\xc2\x89\x50\x4e\x47\x0d\x0a\x1a\x0a\x00\x00\x00\x0d\x49\x48\x44\x52\x00\x00\x00\x01\x00\x00\x00\x01\x08\x06\x00\x00\x00\x1f\x15\xc3\x84\xc2\x89\x00\x00\x00\x0a\x49\x44\x41\x54\x78\xc3\x9a\x63\xc3\xb8\x0f\x04\x00\x09\xc3\xb9\x02\xc3\xb5\xc2\xa7\xc3\xa2\xc3\x88\xc2\xb0\x00\x00\x00\x00\x49\x45\x4e\x44\xc2\xae\x42\x60\xc2\x82");
 
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -2819,7 +2819,7 @@ fn test_action_html_to_markdown_6() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2833,7 +2833,7 @@ fn test_action_html_to_markdown_6() {
     new_body.extend(body_filter.end(None));
     assert_eq!(&new_body, b"\xc2\x89\x50\x4e\x47\x0d\x0a\x1a\x0a\x00\x00\x00\x0d\x49\x48\x44\x52\x00\x00\x00\x01\x00\x00\x00\x01\x08\x06\x00\x00\x00\x1f\x15\xc3\x84\xc2\x89\x00\x00\x00\x0a\x49\x44\x41\x54\x78\xc3\x9a\x63\xc3\xb8\x0f\x04\x00\x09\xc3\xb9\x02\xc3\xb5\xc2\xa7\xc3\xa2\xc3\x88\xc2\xb0\x00\x00\x00\x00\x49\x45\x4e\x44\xc2\xae\x42\x60\xc2\x82");
 
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 
@@ -2871,12 +2871,12 @@ fn test_action_reset_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 0);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
     let response_headers = Vec::new();
 
     let filtered_headers = action.filter_headers(response_headers, response_status_code, false, None);
@@ -2920,7 +2920,7 @@ fn test_action_robots_txt_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2933,7 +2933,7 @@ fn test_action_robots_txt_1() {
     let mut new_body = body_filter.filter(r#""#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"User-Agent: *"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_seo_override_canonical() -> Router<Rule> {
@@ -2964,7 +2964,7 @@ fn test_action_seo_override_canonical_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -2977,7 +2977,7 @@ fn test_action_seo_override_canonical_1() {
     let mut new_body = body_filter.filter(r#"<html><head><meta /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta /><link rel="canonical" href="http://example.com/new-url" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -2996,7 +2996,7 @@ fn test_action_seo_override_canonical_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3009,7 +3009,7 @@ fn test_action_seo_override_canonical_2() {
     let mut new_body = body_filter.filter(r#"<html><head><link rel="canonical" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><link rel="canonical" href="http://example.com/new-url" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3028,7 +3028,7 @@ fn test_action_seo_override_canonical_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3041,7 +3041,7 @@ fn test_action_seo_override_canonical_3() {
     let mut new_body = body_filter.filter(r#"<html><head><link rel="canonical" href="yolo" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><link rel="canonical" href="http://example.com/new-url" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3060,7 +3060,7 @@ fn test_action_seo_override_canonical_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3073,7 +3073,7 @@ fn test_action_seo_override_canonical_4() {
     let mut new_body = body_filter.filter(r#"<html><head><link rel="canonical" /><link rel="canonical" href="yolo" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><link rel="canonical" href="http://example.com/new-url" /><link rel="canonical" href="http://example.com/new-url" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3092,7 +3092,7 @@ fn test_action_seo_override_canonical_5() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3105,7 +3105,7 @@ fn test_action_seo_override_canonical_5() {
     let mut new_body = body_filter.filter(r#"<html><head><link rel="canonical" href="yolo" /><link rel="canonical" href="yolo 2" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><link rel="canonical" href="http://example.com/new-url" /><link rel="canonical" href="http://example.com/new-url" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3124,7 +3124,7 @@ fn test_action_seo_override_canonical_6() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3137,7 +3137,7 @@ fn test_action_seo_override_canonical_6() {
     let mut new_body = body_filter.filter(r#"<html><head><meta></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta><link rel="canonical" href="http://example.com/new-url" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3156,7 +3156,7 @@ fn test_action_seo_override_canonical_7() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3169,7 +3169,7 @@ fn test_action_seo_override_canonical_7() {
     let mut new_body = body_filter.filter(r#"<html><head><link rel="canonical"></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><link rel="canonical" href="http://example.com/new-url" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3188,7 +3188,7 @@ fn test_action_seo_override_canonical_8() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3201,7 +3201,7 @@ fn test_action_seo_override_canonical_8() {
     let mut new_body = body_filter.filter(r#"<html><head><link rel="canonical" href="yolo"></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><link rel="canonical" href="http://example.com/new-url" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_seo_override_meta_author() -> Router<Rule> {
@@ -3232,7 +3232,7 @@ fn test_action_seo_override_meta_author_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3245,7 +3245,7 @@ fn test_action_seo_override_meta_author_1() {
     let mut new_body = body_filter.filter(r#"<html><head><meta /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta /><meta name="author" content="Author name" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3264,7 +3264,7 @@ fn test_action_seo_override_meta_author_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3277,7 +3277,7 @@ fn test_action_seo_override_meta_author_2() {
     let mut new_body = body_filter.filter(r#"<html><head><meta name="author" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta name="author" content="Author name" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3296,7 +3296,7 @@ fn test_action_seo_override_meta_author_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3309,7 +3309,7 @@ fn test_action_seo_override_meta_author_3() {
     let mut new_body = body_filter.filter(r#"<html><head><meta name="author" content="Old Author name" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta name="author" content="Author name" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3328,7 +3328,7 @@ fn test_action_seo_override_meta_author_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3341,7 +3341,7 @@ fn test_action_seo_override_meta_author_4() {
     let mut new_body = body_filter.filter(r#"<html><head><meta name="author" /><meta name="author" content="Old Author name" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta name="author" content="Author name" /><meta name="author" content="Author name" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3360,7 +3360,7 @@ fn test_action_seo_override_meta_author_5() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3373,7 +3373,7 @@ fn test_action_seo_override_meta_author_5() {
     let mut new_body = body_filter.filter(r#"<html><head><meta name="author" content="Old first Author name" /><meta name="author" content="Old second Author name" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta name="author" content="Author name" /><meta name="author" content="Author name" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3392,7 +3392,7 @@ fn test_action_seo_override_meta_author_6() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3405,7 +3405,7 @@ fn test_action_seo_override_meta_author_6() {
     let mut new_body = body_filter.filter(r#"<html><head><meta></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta><meta name="author" content="Author name" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3424,7 +3424,7 @@ fn test_action_seo_override_meta_author_7() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3437,7 +3437,7 @@ fn test_action_seo_override_meta_author_7() {
     let mut new_body = body_filter.filter(r#"<html><head><meta name="author"></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta name="author" content="Author name" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3456,7 +3456,7 @@ fn test_action_seo_override_meta_author_8() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3469,7 +3469,7 @@ fn test_action_seo_override_meta_author_8() {
     let mut new_body = body_filter.filter(r#"<html><head><meta name="author" content="Old Author name"></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta name="author" content="Author name" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_seo_override_meta_description() -> Router<Rule> {
@@ -3500,7 +3500,7 @@ fn test_action_seo_override_meta_description_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3513,7 +3513,7 @@ fn test_action_seo_override_meta_description_1() {
     let mut new_body = body_filter.filter(r#"<html><head><meta /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta /><meta name="description" content="New Description" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3532,7 +3532,7 @@ fn test_action_seo_override_meta_description_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3545,7 +3545,7 @@ fn test_action_seo_override_meta_description_2() {
     let mut new_body = body_filter.filter(r#"<html><head><meta name="description" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta name="description" content="New Description" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3564,7 +3564,7 @@ fn test_action_seo_override_meta_description_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3577,7 +3577,7 @@ fn test_action_seo_override_meta_description_3() {
     let mut new_body = body_filter.filter(r#"<html><head><meta name="description" content="Old Description" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta name="description" content="New Description" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3596,7 +3596,7 @@ fn test_action_seo_override_meta_description_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3609,7 +3609,7 @@ fn test_action_seo_override_meta_description_4() {
     let mut new_body = body_filter.filter(r#"<html><head><meta></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta><meta name="description" content="New Description" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3628,7 +3628,7 @@ fn test_action_seo_override_meta_description_5() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3641,7 +3641,7 @@ fn test_action_seo_override_meta_description_5() {
     let mut new_body = body_filter.filter(r#"<html><head><meta name="description"></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta name="description" content="New Description" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3660,7 +3660,7 @@ fn test_action_seo_override_meta_description_6() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3673,7 +3673,7 @@ fn test_action_seo_override_meta_description_6() {
     let mut new_body = body_filter.filter(r#"<html><head><meta name="description" content="Old Description"></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta name="description" content="New Description" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_seo_override_meta_keywords() -> Router<Rule> {
@@ -3704,7 +3704,7 @@ fn test_action_seo_override_meta_keywords_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3717,7 +3717,7 @@ fn test_action_seo_override_meta_keywords_1() {
     let mut new_body = body_filter.filter(r#"<html><head><meta /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta /><meta name="keywords" content="some, keywords, here" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3736,7 +3736,7 @@ fn test_action_seo_override_meta_keywords_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3749,7 +3749,7 @@ fn test_action_seo_override_meta_keywords_2() {
     let mut new_body = body_filter.filter(r#"<html><head><meta name="keywords" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta name="keywords" content="some, keywords, here" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3768,7 +3768,7 @@ fn test_action_seo_override_meta_keywords_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3781,7 +3781,7 @@ fn test_action_seo_override_meta_keywords_3() {
     let mut new_body = body_filter.filter(r#"<html><head><meta name="keywords" content="these, were, old, keywords" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta name="keywords" content="some, keywords, here" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3800,7 +3800,7 @@ fn test_action_seo_override_meta_keywords_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3813,7 +3813,7 @@ fn test_action_seo_override_meta_keywords_4() {
     let mut new_body = body_filter.filter(r#"<html><head><meta></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta><meta name="keywords" content="some, keywords, here" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3832,7 +3832,7 @@ fn test_action_seo_override_meta_keywords_5() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3845,7 +3845,7 @@ fn test_action_seo_override_meta_keywords_5() {
     let mut new_body = body_filter.filter(r#"<html><head><meta name="keywords"></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta name="keywords" content="some, keywords, here" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3864,7 +3864,7 @@ fn test_action_seo_override_meta_keywords_6() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3877,7 +3877,7 @@ fn test_action_seo_override_meta_keywords_6() {
     let mut new_body = body_filter.filter(r#"<html><head><meta name="keywords" content="these, were, old, keywords"></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta name="keywords" content="some, keywords, here" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3896,7 +3896,7 @@ fn test_action_seo_override_meta_keywords_7() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3909,7 +3909,7 @@ fn test_action_seo_override_meta_keywords_7() {
     let mut new_body = body_filter.filter(r#"<html><head><link rel="shortcut icon" href="/favicon.ico"></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><link rel="shortcut icon" href="/favicon.ico"><meta name="keywords" content="some, keywords, here" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_seo_override_og_description() -> Router<Rule> {
@@ -3943,7 +3943,7 @@ fn test_action_seo_override_og_description_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3956,7 +3956,7 @@ fn test_action_seo_override_og_description_1() {
     let mut new_body = body_filter.filter(r#"<html><head><description>Old description</description><meta /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><description>Old description</description><meta /><meta property="og:description" content="New Description" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -3975,7 +3975,7 @@ fn test_action_seo_override_og_description_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -3988,7 +3988,7 @@ fn test_action_seo_override_og_description_2() {
     let mut new_body = body_filter.filter(r#"<html><head><meta /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta /><meta property="og:description" content="New Description" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -4007,7 +4007,7 @@ fn test_action_seo_override_og_description_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4020,7 +4020,7 @@ fn test_action_seo_override_og_description_3() {
     let mut new_body = body_filter.filter(r#"<html><head><description>Old description</description><meta /><meta property="og:description" content="Old Description" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><description>Old description</description><meta /><meta property="og:description" content="New Description" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -4039,7 +4039,7 @@ fn test_action_seo_override_og_description_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4052,7 +4052,7 @@ fn test_action_seo_override_og_description_4() {
     let mut new_body = body_filter.filter(r#"<html><head><description>Old description</description><meta><meta property="og:description" content="Old Description" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><description>Old description</description><meta><meta property="og:description" content="New Description" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -4071,7 +4071,7 @@ fn test_action_seo_override_og_description_5() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4084,7 +4084,7 @@ fn test_action_seo_override_og_description_5() {
     let mut new_body = body_filter.filter(r#"<html><head><description>Old description</description><meta></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><description>Old description</description><meta><meta property="og:description" content="New Description" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -4103,7 +4103,7 @@ fn test_action_seo_override_og_description_6() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4116,7 +4116,7 @@ fn test_action_seo_override_og_description_6() {
     let mut new_body = body_filter.filter(r#"<html><head><description>Old description</description><meta property="no-closing"><meta property="og:description" content="Old Description" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><description>Old description</description><meta property="no-closing"><meta property="og:description" content="New Description" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -4135,7 +4135,7 @@ fn test_action_seo_override_og_description_7() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4148,7 +4148,7 @@ fn test_action_seo_override_og_description_7() {
     let mut new_body = body_filter.filter(r#"<html><head><meta property="og:description" content="Pizza rapido" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta property="og:description" content="🍕🍕 Pizza rapido 🍕🍕" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_seo_override_og_image() -> Router<Rule> {
@@ -4179,7 +4179,7 @@ fn test_action_seo_override_og_image_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4192,7 +4192,7 @@ fn test_action_seo_override_og_image_1() {
     let mut new_body = body_filter.filter(r#"<html><head><meta /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta /><meta property="og:image" content="/some-image.png" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -4211,7 +4211,7 @@ fn test_action_seo_override_og_image_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4224,7 +4224,7 @@ fn test_action_seo_override_og_image_2() {
     let mut new_body = body_filter.filter(r#"<html><head><meta></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta><meta property="og:image" content="/some-image.png" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -4243,7 +4243,7 @@ fn test_action_seo_override_og_image_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4256,7 +4256,7 @@ fn test_action_seo_override_og_image_3() {
     let mut new_body = body_filter.filter(r#"<html><head><meta /><meta property="og:image" content="/old-image.png" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta /><meta property="og:image" content="/some-image.png" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -4275,7 +4275,7 @@ fn test_action_seo_override_og_image_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4288,7 +4288,7 @@ fn test_action_seo_override_og_image_4() {
     let mut new_body = body_filter.filter(r#"<html><head><meta property="no-closing"><meta property="og:image" content="/old-image.png" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta property="no-closing"><meta property="og:image" content="/some-image.png" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_seo_override_og_locale() -> Router<Rule> {
@@ -4319,7 +4319,7 @@ fn test_action_seo_override_og_locale_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4332,7 +4332,7 @@ fn test_action_seo_override_og_locale_1() {
     let mut new_body = body_filter.filter(r#"<html><head><meta /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta /><meta property="og:locale" content="fr_FR" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -4351,7 +4351,7 @@ fn test_action_seo_override_og_locale_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4364,7 +4364,7 @@ fn test_action_seo_override_og_locale_2() {
     let mut new_body = body_filter.filter(r#"<html><head><meta></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta><meta property="og:locale" content="fr_FR" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -4383,7 +4383,7 @@ fn test_action_seo_override_og_locale_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4396,7 +4396,7 @@ fn test_action_seo_override_og_locale_3() {
     let mut new_body = body_filter.filter(r#"<html><head><meta /><meta property="og:locale" content="en_GB" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta /><meta property="og:locale" content="fr_FR" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -4415,7 +4415,7 @@ fn test_action_seo_override_og_locale_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4428,7 +4428,7 @@ fn test_action_seo_override_og_locale_4() {
     let mut new_body = body_filter.filter(r#"<html><head><meta property="no-closing"><meta property="og:locale" content="en_GB" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta property="no-closing"><meta property="og:locale" content="fr_FR" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_seo_override_og_site_name() -> Router<Rule> {
@@ -4459,7 +4459,7 @@ fn test_action_seo_override_og_site_name_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4472,7 +4472,7 @@ fn test_action_seo_override_og_site_name_1() {
     let mut new_body = body_filter.filter(r#"<html><head><meta /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta /><meta property="og:site_name" content="redirection.io" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -4491,7 +4491,7 @@ fn test_action_seo_override_og_site_name_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4504,7 +4504,7 @@ fn test_action_seo_override_og_site_name_2() {
     let mut new_body = body_filter.filter(r#"<html><head><meta></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta><meta property="og:site_name" content="redirection.io" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -4523,7 +4523,7 @@ fn test_action_seo_override_og_site_name_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4536,7 +4536,7 @@ fn test_action_seo_override_og_site_name_3() {
     let mut new_body = body_filter.filter(r#"<html><head><meta /><meta property="og:site_name" content="JoliCode" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta /><meta property="og:site_name" content="redirection.io" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -4555,7 +4555,7 @@ fn test_action_seo_override_og_site_name_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4568,7 +4568,7 @@ fn test_action_seo_override_og_site_name_4() {
     let mut new_body = body_filter.filter(r#"<html><head><meta property="no-closing"><meta property="og:site_name" content="JoliCode" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta property="no-closing"><meta property="og:site_name" content="redirection.io" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_seo_override_og_title() -> Router<Rule> {
@@ -4599,7 +4599,7 @@ fn test_action_seo_override_og_title_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4612,7 +4612,7 @@ fn test_action_seo_override_og_title_1() {
     let mut new_body = body_filter.filter(r#"<html><head><title>Old title</title><meta /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><title>Old title</title><meta /><meta property="og:title" content="New Title" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -4631,7 +4631,7 @@ fn test_action_seo_override_og_title_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4644,7 +4644,7 @@ fn test_action_seo_override_og_title_2() {
     let mut new_body = body_filter.filter(r#"<html><head><meta /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta /><meta property="og:title" content="New Title" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -4663,7 +4663,7 @@ fn test_action_seo_override_og_title_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4676,7 +4676,7 @@ fn test_action_seo_override_og_title_3() {
     let mut new_body = body_filter.filter(r#"<html><head><title>Old title</title><meta /><meta property="og:title" content="Old Title" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><title>Old title</title><meta /><meta property="og:title" content="New Title" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -4695,7 +4695,7 @@ fn test_action_seo_override_og_title_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4708,7 +4708,7 @@ fn test_action_seo_override_og_title_4() {
     let mut new_body = body_filter.filter(r#"<html><head><title>Old title</title><meta property="no-closing"><meta property="og:title" content="Old Title" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><title>Old title</title><meta property="no-closing"><meta property="og:title" content="New Title" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_seo_override_og_type() -> Router<Rule> {
@@ -4739,7 +4739,7 @@ fn test_action_seo_override_og_type_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4752,7 +4752,7 @@ fn test_action_seo_override_og_type_1() {
     let mut new_body = body_filter.filter(r#"<html><head><meta /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta /><meta property="og:type" content="website" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -4771,7 +4771,7 @@ fn test_action_seo_override_og_type_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4784,7 +4784,7 @@ fn test_action_seo_override_og_type_2() {
     let mut new_body = body_filter.filter(r#"<html><head><meta /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta /><meta property="og:type" content="website" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -4803,7 +4803,7 @@ fn test_action_seo_override_og_type_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4816,7 +4816,7 @@ fn test_action_seo_override_og_type_3() {
     let mut new_body = body_filter.filter(r#"<html><head><meta /><meta property="og:type" content="article" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta /><meta property="og:type" content="website" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -4835,7 +4835,7 @@ fn test_action_seo_override_og_type_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4848,7 +4848,7 @@ fn test_action_seo_override_og_type_4() {
     let mut new_body = body_filter.filter(r#"<html><head><meta property="no-closing"><meta property="og:type" content="article" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta property="no-closing"><meta property="og:type" content="website" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_seo_override_og_url() -> Router<Rule> {
@@ -4879,7 +4879,7 @@ fn test_action_seo_override_og_url_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4892,7 +4892,7 @@ fn test_action_seo_override_og_url_1() {
     let mut new_body = body_filter.filter(r#"<html><head><url>Old url</url><meta /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><url>Old url</url><meta /><meta property="og:url" content="https://redirection.io/features" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -4911,7 +4911,7 @@ fn test_action_seo_override_og_url_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4924,7 +4924,7 @@ fn test_action_seo_override_og_url_2() {
     let mut new_body = body_filter.filter(r#"<html><head><meta /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta /><meta property="og:url" content="https://redirection.io/features" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -4943,7 +4943,7 @@ fn test_action_seo_override_og_url_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4956,7 +4956,7 @@ fn test_action_seo_override_og_url_3() {
     let mut new_body = body_filter.filter(r#"<html><head><url>Old url</url><meta /><meta property="og:url" content="https://jolicode.com/" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><url>Old url</url><meta /><meta property="og:url" content="https://redirection.io/features" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -4975,7 +4975,7 @@ fn test_action_seo_override_og_url_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -4988,7 +4988,7 @@ fn test_action_seo_override_og_url_4() {
     let mut new_body = body_filter.filter(r#"<html><head><url>Old url</url><meta property="no-closing"><meta property="og:url" content="https://jolicode.com/" /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><url>Old url</url><meta property="no-closing"><meta property="og:url" content="https://redirection.io/features" /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_seo_override_title() -> Router<Rule> {
@@ -5019,7 +5019,7 @@ fn test_action_seo_override_title_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -5032,7 +5032,7 @@ fn test_action_seo_override_title_1() {
     let mut new_body = body_filter.filter(r#"<html><head><title>Old title</title><meta /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><title>New Title</title><meta /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -5051,7 +5051,7 @@ fn test_action_seo_override_title_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -5064,7 +5064,7 @@ fn test_action_seo_override_title_2() {
     let mut new_body = body_filter.filter(r#"<html><head><meta /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta /><title>New Title</title></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -5083,7 +5083,7 @@ fn test_action_seo_override_title_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -5096,7 +5096,7 @@ fn test_action_seo_override_title_3() {
     let mut new_body = body_filter.filter(r#"<html><head><title>Old title</title><meta></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><title>New Title</title><meta></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -5115,7 +5115,7 @@ fn test_action_seo_override_title_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -5128,7 +5128,7 @@ fn test_action_seo_override_title_4() {
     let mut new_body = body_filter.filter(r#"<html><head><meta></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><meta><title>New Title</title></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_sitemap() -> Router<Rule> {
@@ -5159,7 +5159,7 @@ fn test_action_sitemap_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -5172,7 +5172,7 @@ fn test_action_sitemap_1() {
     let mut new_body = body_filter.filter(r#""#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9"><sitemap><loc>https://redirection.io/sitemap_static.xml</loc></sitemap><sitemap><loc>https://redirection.io/features/sitemap/</loc></sitemap><sitemap><loc>https://redirection.io/news/sitemap/</loc></sitemap></sitemapindex>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_stop() -> Router<Rule> {
@@ -5209,12 +5209,12 @@ fn test_action_stop_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 0);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
     let response_headers = Vec::new();
 
     let filtered_headers = action.filter_headers(response_headers, response_status_code, false, None);
@@ -5261,12 +5261,12 @@ fn test_action_switch_backend_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 0);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -5285,12 +5285,12 @@ fn test_action_switch_backend_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 0);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_text_append() -> Router<Rule> {
@@ -5321,7 +5321,7 @@ fn test_action_text_append_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -5334,7 +5334,7 @@ fn test_action_text_append_1() {
     let mut new_body = body_filter.filter(r#"Old content"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"Old contentnew content"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_text_prepend() -> Router<Rule> {
@@ -5365,7 +5365,7 @@ fn test_action_text_prepend_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -5378,7 +5378,7 @@ fn test_action_text_prepend_1() {
     let mut new_body = body_filter.filter(r#"Old content"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"new contentOld content"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_action_text_replace() -> Router<Rule> {
@@ -5409,7 +5409,7 @@ fn test_action_text_replace_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -5422,7 +5422,7 @@ fn test_action_text_replace_1() {
     let mut new_body = body_filter.filter(r#"Old content"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"new content"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -5441,7 +5441,7 @@ fn test_action_text_replace_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -5454,7 +5454,7 @@ fn test_action_text_replace_2() {
     let mut new_body = body_filter.filter(r#""#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"new content"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_ignore_all_query_parameters() -> Router<Rule> {
@@ -5485,7 +5485,7 @@ fn test_ignore_all_query_parameters_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -5496,7 +5496,7 @@ fn test_ignore_all_query_parameters_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -5515,7 +5515,7 @@ fn test_ignore_all_query_parameters_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -5526,7 +5526,7 @@ fn test_ignore_all_query_parameters_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar?param1=value1"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -5545,7 +5545,7 @@ fn test_ignore_all_query_parameters_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -5556,7 +5556,7 @@ fn test_ignore_all_query_parameters_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar?param1=value1&param2=value2"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -5575,7 +5575,7 @@ fn test_ignore_all_query_parameters_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -5586,7 +5586,7 @@ fn test_ignore_all_query_parameters_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar?param2=value1&param1=value2"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_ignore_path_case() -> Router<Rule> {
@@ -5620,7 +5620,7 @@ fn test_ignore_path_case_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -5631,7 +5631,7 @@ fn test_ignore_path_case_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -5650,7 +5650,7 @@ fn test_ignore_path_case_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -5661,7 +5661,7 @@ fn test_ignore_path_case_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -5680,7 +5680,7 @@ fn test_ignore_path_case_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -5691,7 +5691,7 @@ fn test_ignore_path_case_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -5710,7 +5710,7 @@ fn test_ignore_path_case_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -5721,7 +5721,7 @@ fn test_ignore_path_case_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -5740,7 +5740,7 @@ fn test_ignore_path_case_5() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -5751,7 +5751,7 @@ fn test_ignore_path_case_5() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/marker-target"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -5770,7 +5770,7 @@ fn test_ignore_path_case_6() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -5781,7 +5781,7 @@ fn test_ignore_path_case_6() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/marker-target"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -5836,7 +5836,7 @@ fn test_marker_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -5847,7 +5847,7 @@ fn test_marker_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar/test"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -5884,7 +5884,7 @@ fn test_marker_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -5895,7 +5895,7 @@ fn test_marker_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/a/TEST"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -5914,7 +5914,7 @@ fn test_marker_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -5925,7 +5925,7 @@ fn test_marker_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/a/TEST-TEST"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -5944,7 +5944,7 @@ fn test_marker_5() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -5955,7 +5955,7 @@ fn test_marker_5() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"https://www.usharbors.com/harbor/western-pacific-coast/test"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_marker_case() -> Router<Rule> {
@@ -5986,7 +5986,7 @@ fn test_marker_case_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -5997,7 +5997,7 @@ fn test_marker_case_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target/UPPERCASE"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -6016,7 +6016,7 @@ fn test_marker_case_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -6027,7 +6027,7 @@ fn test_marker_case_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target/UpErCase"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_marker_in_body_filter() -> Router<Rule> {
@@ -6058,7 +6058,7 @@ fn test_marker_in_body_filter_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -6071,7 +6071,7 @@ fn test_marker_in_body_filter_1() {
     let mut new_body = body_filter.filter(r#"<html><head><title>Old title</title><meta /></head></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><head><title>source</title><meta /></head></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_marker_in_header_filter() -> Router<Rule> {
@@ -6102,12 +6102,12 @@ fn test_marker_in_header_filter_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 0);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
     let mut response_headers = Vec::new();
 
     response_headers.push(Header {
@@ -6188,7 +6188,7 @@ fn test_marker_in_host_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -6199,7 +6199,7 @@ fn test_marker_in_host_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"https://www.test.io"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_marker_in_host_multiple_case_and_idn() -> Router<Rule> {
@@ -6233,7 +6233,7 @@ fn test_marker_in_host_multiple_case_and_idn_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -6244,7 +6244,7 @@ fn test_marker_in_host_multiple_case_and_idn_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"https://org.test.JOHN-SNOW-a.io/hello"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -6281,7 +6281,7 @@ fn test_marker_in_host_multiple_case_and_idn_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -6292,7 +6292,7 @@ fn test_marker_in_host_multiple_case_and_idn_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"https://org.test.中.JOHN-SNOW-a.io/国"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -6311,7 +6311,7 @@ fn test_marker_in_host_multiple_case_and_idn_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -6322,7 +6322,7 @@ fn test_marker_in_host_multiple_case_and_idn_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"https://net.test.中.JOHN-SNOW-b.io/国"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_marker_in_querystring() -> Router<Rule> {
@@ -6356,7 +6356,7 @@ fn test_marker_in_querystring_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -6367,7 +6367,7 @@ fn test_marker_in_querystring_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/querystring/target/some-target/coucou.html"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -6422,7 +6422,7 @@ fn test_marker_in_querystring_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -6433,7 +6433,7 @@ fn test_marker_in_querystring_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/b?yolo=yala"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_marker_transformation_camelize() -> Router<Rule> {
@@ -6464,7 +6464,7 @@ fn test_marker_transformation_camelize_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -6475,7 +6475,7 @@ fn test_marker_transformation_camelize_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/camelize/target/helloPoney"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -6494,7 +6494,7 @@ fn test_marker_transformation_camelize_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -6505,7 +6505,7 @@ fn test_marker_transformation_camelize_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/camelize/target/helloPoney"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -6524,7 +6524,7 @@ fn test_marker_transformation_camelize_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -6535,7 +6535,7 @@ fn test_marker_transformation_camelize_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/camelize/target/helloPoney"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -6554,7 +6554,7 @@ fn test_marker_transformation_camelize_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -6565,7 +6565,7 @@ fn test_marker_transformation_camelize_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/camelize/target/helloPOney"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_marker_transformation_dasherize() -> Router<Rule> {
@@ -6596,7 +6596,7 @@ fn test_marker_transformation_dasherize_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -6607,7 +6607,7 @@ fn test_marker_transformation_dasherize_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/dasherize/target/hello-poney"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -6626,7 +6626,7 @@ fn test_marker_transformation_dasherize_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -6637,7 +6637,7 @@ fn test_marker_transformation_dasherize_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/dasherize/target/hello-poney"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -6656,7 +6656,7 @@ fn test_marker_transformation_dasherize_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -6667,7 +6667,7 @@ fn test_marker_transformation_dasherize_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/dasherize/target/hello-poney"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_marker_transformation_lowercase() -> Router<Rule> {
@@ -6698,7 +6698,7 @@ fn test_marker_transformation_lowercase_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -6709,7 +6709,7 @@ fn test_marker_transformation_lowercase_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/lowercase/target/hello-poney"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -6728,7 +6728,7 @@ fn test_marker_transformation_lowercase_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -6739,7 +6739,7 @@ fn test_marker_transformation_lowercase_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/lowercase/target/hello-poney"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -6758,7 +6758,7 @@ fn test_marker_transformation_lowercase_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -6769,7 +6769,7 @@ fn test_marker_transformation_lowercase_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/lowercase/target/hello-poney"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_marker_transformation_replace() -> Router<Rule> {
@@ -6821,7 +6821,7 @@ fn test_marker_transformation_replace_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -6832,7 +6832,7 @@ fn test_marker_transformation_replace_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/replace/target/tiger"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -6851,7 +6851,7 @@ fn test_marker_transformation_replace_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -6862,7 +6862,7 @@ fn test_marker_transformation_replace_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/replace/target/dog"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -6881,7 +6881,7 @@ fn test_marker_transformation_replace_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -6892,7 +6892,7 @@ fn test_marker_transformation_replace_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -6911,7 +6911,7 @@ fn test_marker_transformation_replace_5() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -6922,7 +6922,7 @@ fn test_marker_transformation_replace_5() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/something"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_marker_transformation_slice() -> Router<Rule> {
@@ -6959,7 +6959,7 @@ fn test_marker_transformation_slice_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -6970,7 +6970,7 @@ fn test_marker_transformation_slice_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/slice/target/ABCDEFGHIJ"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -6989,7 +6989,7 @@ fn test_marker_transformation_slice_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7000,7 +7000,7 @@ fn test_marker_transformation_slice_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/slice/target/ABCD"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -7019,7 +7019,7 @@ fn test_marker_transformation_slice_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7030,7 +7030,7 @@ fn test_marker_transformation_slice_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/slice-middle/target/FGHIJKLMNO"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -7049,7 +7049,7 @@ fn test_marker_transformation_slice_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7060,7 +7060,7 @@ fn test_marker_transformation_slice_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/slice-middle/target/FGHIJ"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -7079,7 +7079,7 @@ fn test_marker_transformation_slice_5() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7090,7 +7090,7 @@ fn test_marker_transformation_slice_5() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/slice-middle/target/"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -7109,7 +7109,7 @@ fn test_marker_transformation_slice_6() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7120,7 +7120,7 @@ fn test_marker_transformation_slice_6() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/hi"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -7139,7 +7139,7 @@ fn test_marker_transformation_slice_7() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7150,7 +7150,7 @@ fn test_marker_transformation_slice_7() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/o"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -7169,7 +7169,7 @@ fn test_marker_transformation_slice_8() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7180,7 +7180,7 @@ fn test_marker_transformation_slice_8() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_marker_transformation_underscorize() -> Router<Rule> {
@@ -7211,7 +7211,7 @@ fn test_marker_transformation_underscorize_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7222,7 +7222,7 @@ fn test_marker_transformation_underscorize_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/underscorize/target/hello_poney"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -7241,7 +7241,7 @@ fn test_marker_transformation_underscorize_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7252,7 +7252,7 @@ fn test_marker_transformation_underscorize_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/underscorize/target/hello_poney"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -7271,7 +7271,7 @@ fn test_marker_transformation_underscorize_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7282,7 +7282,7 @@ fn test_marker_transformation_underscorize_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/underscorize/target/hello_poney"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -7301,7 +7301,7 @@ fn test_marker_transformation_underscorize_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7312,7 +7312,7 @@ fn test_marker_transformation_underscorize_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/underscorize/target/hello_poney"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_marker_transformation_uppercase() -> Router<Rule> {
@@ -7343,7 +7343,7 @@ fn test_marker_transformation_uppercase_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7354,7 +7354,7 @@ fn test_marker_transformation_uppercase_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/uppercase/target/HELLO-PONEY"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -7373,7 +7373,7 @@ fn test_marker_transformation_uppercase_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7384,7 +7384,7 @@ fn test_marker_transformation_uppercase_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/uppercase/target/HELLO-PONEY"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -7403,7 +7403,7 @@ fn test_marker_transformation_uppercase_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7414,7 +7414,7 @@ fn test_marker_transformation_uppercase_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/uppercase/target/HELLO-PONEY"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_marker_type_anything() -> Router<Rule> {
@@ -7445,7 +7445,7 @@ fn test_marker_type_anything_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7456,7 +7456,7 @@ fn test_marker_type_anything_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/anything/target/f6883ff9-f163-43d7-8177-bfa24277fd20"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -7475,7 +7475,7 @@ fn test_marker_type_anything_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7486,7 +7486,7 @@ fn test_marker_type_anything_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/anything/target/HELLO"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -7505,7 +7505,7 @@ fn test_marker_type_anything_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7516,7 +7516,7 @@ fn test_marker_type_anything_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/anything/target/%F0%9F%A4%98"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_marker_type_date() -> Router<Rule> {
@@ -7547,7 +7547,7 @@ fn test_marker_type_date_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7558,7 +7558,7 @@ fn test_marker_type_date_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/date/target/2018-11-23"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -7628,7 +7628,7 @@ fn test_marker_type_datetime_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7639,7 +7639,7 @@ fn test_marker_type_datetime_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/datetime/target/2018-07-15T14:59:12Z"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -7658,7 +7658,7 @@ fn test_marker_type_datetime_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7669,7 +7669,7 @@ fn test_marker_type_datetime_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/datetime/target/2018-07-15T14:59:12+02:00"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -7706,7 +7706,7 @@ fn test_marker_type_datetime_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7717,7 +7717,7 @@ fn test_marker_type_datetime_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/datetime-transform/target/2018-07-15"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_marker_type_enum() -> Router<Rule> {
@@ -7748,7 +7748,7 @@ fn test_marker_type_enum_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7759,7 +7759,7 @@ fn test_marker_type_enum_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/enum/target/cat"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -7796,7 +7796,7 @@ fn test_marker_type_enum_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7807,7 +7807,7 @@ fn test_marker_type_enum_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/enum/target/dog"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -7865,7 +7865,7 @@ fn test_marker_type_integer_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7876,7 +7876,7 @@ fn test_marker_type_integer_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/integer/target/2778"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -7931,7 +7931,7 @@ fn test_marker_type_integer_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7942,7 +7942,7 @@ fn test_marker_type_integer_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/integer-min/target/112"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -7979,7 +7979,7 @@ fn test_marker_type_integer_6() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -7990,7 +7990,7 @@ fn test_marker_type_integer_6() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/integer-max/target/11"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -8027,7 +8027,7 @@ fn test_marker_type_integer_8() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -8038,7 +8038,7 @@ fn test_marker_type_integer_8() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/integer-min-max/target/806"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -8165,7 +8165,7 @@ fn test_marker_type_string_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -8176,7 +8176,7 @@ fn test_marker_type_string_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/string-lowercase/target/coucou"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -8249,7 +8249,7 @@ fn test_marker_type_string_6() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -8260,7 +8260,7 @@ fn test_marker_type_string_6() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/string-uppercase/target/COUCOU"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -8333,7 +8333,7 @@ fn test_marker_type_string_10() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -8344,7 +8344,7 @@ fn test_marker_type_string_10() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/string-lowercase-uppercase-digits/target/coucou"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -8363,7 +8363,7 @@ fn test_marker_type_string_11() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -8374,7 +8374,7 @@ fn test_marker_type_string_11() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/string-lowercase-uppercase-digits/target/COUCOU"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -8411,7 +8411,7 @@ fn test_marker_type_string_13() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -8422,7 +8422,7 @@ fn test_marker_type_string_13() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/string-lowercase-uppercase-digits/target/l33t"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -8441,7 +8441,7 @@ fn test_marker_type_string_14() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -8452,7 +8452,7 @@ fn test_marker_type_string_14() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/string-lowercase-uppercase-digits/target/L33T"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -8471,7 +8471,7 @@ fn test_marker_type_string_15() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -8482,7 +8482,7 @@ fn test_marker_type_string_15() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/string-specificCharacters/target/-"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -8501,7 +8501,7 @@ fn test_marker_type_string_16() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -8512,7 +8512,7 @@ fn test_marker_type_string_16() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/string-specificCharacters/target/-_.+_-/._-_."#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -8531,7 +8531,7 @@ fn test_marker_type_string_17() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -8542,7 +8542,7 @@ fn test_marker_type_string_17() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/string-specificCharacters-other/target/z-a-z-a-zz"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -8579,7 +8579,7 @@ fn test_marker_type_string_19() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -8590,7 +8590,7 @@ fn test_marker_type_string_19() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/string-lowercase-specificCharacters-emoji/target/you-rock-dude-%F0%9F%A4%98"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -8609,7 +8609,7 @@ fn test_marker_type_string_20() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -8620,7 +8620,7 @@ fn test_marker_type_string_20() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/string-starting/target/JOHN-SNOW-knows-nothing"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -8675,7 +8675,7 @@ fn test_marker_type_string_23() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -8686,7 +8686,7 @@ fn test_marker_type_string_23() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/string-starting-shit/target/([A-Z])+-knows-nothing"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -8723,7 +8723,7 @@ fn test_marker_type_string_25() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -8734,7 +8734,7 @@ fn test_marker_type_string_25() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/string-ending/target/you-know-nothing-JOHN-SNOW"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -8771,7 +8771,7 @@ fn test_marker_type_string_27() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -8782,7 +8782,7 @@ fn test_marker_type_string_27() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/string-allowPercentEncodedChars/target/%2B%3A%26"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -8801,7 +8801,7 @@ fn test_marker_type_string_28() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -8812,7 +8812,7 @@ fn test_marker_type_string_28() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/string-allowPercentEncodedChars/target/%3A"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -8831,7 +8831,7 @@ fn test_marker_type_string_29() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -8842,7 +8842,7 @@ fn test_marker_type_string_29() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/string-allowPercentEncodedChars/target/%2B"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -8861,7 +8861,7 @@ fn test_marker_type_string_30() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -8872,7 +8872,7 @@ fn test_marker_type_string_30() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/string-allowPercentEncodedChars/target/%26"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -8927,7 +8927,7 @@ fn test_marker_type_string_33() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -8938,7 +8938,7 @@ fn test_marker_type_string_33() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/string-lowercase-digits-allowPercentEncodedChars/target/0%2B0%3Dtoto"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -8975,7 +8975,7 @@ fn test_marker_type_string_35() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -8986,7 +8986,7 @@ fn test_marker_type_string_35() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/string-lowercase-uppercase-digits-allowPercentEncodedChars-specificCharacters/target/Medios-de-Comunicaci%C3%B3n-y-Creatividad"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -9005,7 +9005,7 @@ fn test_marker_type_string_36() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -9016,7 +9016,7 @@ fn test_marker_type_string_36() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/string-lowercase-uppercase-digits-allowPercentEncodedChars-specificCharacters/target/Medios-de-Comunicaci%C3%B3n-y-Creatividad"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -9035,7 +9035,7 @@ fn test_marker_type_string_37() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -9046,7 +9046,7 @@ fn test_marker_type_string_37() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/string-containing/target/L33T"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -9065,7 +9065,7 @@ fn test_marker_type_string_38() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -9076,7 +9076,7 @@ fn test_marker_type_string_38() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/string-containing/target/L33TL33T"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -9113,7 +9113,7 @@ fn test_marker_type_string_40() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -9124,7 +9124,7 @@ fn test_marker_type_string_40() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/string-allowLowercaseAlphabet-specificCharacters-starting-containing/target/JOHN-SNOW-L33T-knows-nothing"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -9143,7 +9143,7 @@ fn test_marker_type_string_41() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -9154,7 +9154,7 @@ fn test_marker_type_string_41() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/string-allowLowercaseAlphabet-specificCharacters-starting-containing/target/JOHN-SNOWL33T"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -9239,7 +9239,7 @@ fn test_marker_type_uuid_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -9250,7 +9250,7 @@ fn test_marker_type_uuid_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/uuid/target/f6883ff9-f163-43d7-8177-bfa24277fd20"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -9317,7 +9317,7 @@ fn test_marketing_parameters_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -9328,7 +9328,7 @@ fn test_marketing_parameters_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -9347,7 +9347,7 @@ fn test_marketing_parameters_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -9358,7 +9358,7 @@ fn test_marketing_parameters_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar?param1=value1"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -9377,7 +9377,7 @@ fn test_marketing_parameters_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -9388,7 +9388,7 @@ fn test_marketing_parameters_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar?param1=value1&param2=value2"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -9407,7 +9407,7 @@ fn test_marketing_parameters_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -9418,7 +9418,7 @@ fn test_marketing_parameters_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar?param1=value2&param2=value1"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -9485,7 +9485,7 @@ fn test_marketing_parameters_notarget_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -9496,7 +9496,7 @@ fn test_marketing_parameters_notarget_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -9515,7 +9515,7 @@ fn test_marketing_parameters_notarget_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -9526,7 +9526,7 @@ fn test_marketing_parameters_notarget_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -9545,7 +9545,7 @@ fn test_marketing_parameters_notarget_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -9556,7 +9556,7 @@ fn test_marketing_parameters_notarget_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -9575,7 +9575,7 @@ fn test_marketing_parameters_notarget_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -9586,7 +9586,7 @@ fn test_marketing_parameters_notarget_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -9653,7 +9653,7 @@ fn test_marketing_parameters_with_catch_all_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -9664,7 +9664,7 @@ fn test_marketing_parameters_with_catch_all_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/us/en/story/275996-women-gifts/"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -9683,7 +9683,7 @@ fn test_marketing_parameters_with_catch_all_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -9694,7 +9694,7 @@ fn test_marketing_parameters_with_catch_all_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/us/en/story/275996-women-gifts/?utm_test=123"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -9713,7 +9713,7 @@ fn test_marketing_parameters_with_catch_all_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -9724,7 +9724,7 @@ fn test_marketing_parameters_with_catch_all_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/us/en/story/275996-women-gifts/?utm_randomstring=123"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -9743,7 +9743,7 @@ fn test_marketing_parameters_with_catch_all_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -9754,7 +9754,7 @@ fn test_marketing_parameters_with_catch_all_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/us/en/story/275996-women-gifts/?utm_source=123"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_no_marketing_parameterst() -> Router<Rule> {
@@ -9785,7 +9785,7 @@ fn test_no_marketing_parameterst_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -9796,7 +9796,7 @@ fn test_no_marketing_parameterst_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -9923,7 +9923,7 @@ fn test_query_parameters_order_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -9934,7 +9934,7 @@ fn test_query_parameters_order_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -10007,7 +10007,7 @@ fn test_query_parameters_order_5() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -10018,7 +10018,7 @@ fn test_query_parameters_order_5() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar-inverted"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -10037,7 +10037,7 @@ fn test_query_parameters_order_6() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -10048,7 +10048,7 @@ fn test_query_parameters_order_6() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -10067,7 +10067,7 @@ fn test_query_parameters_order_7() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -10078,7 +10078,7 @@ fn test_query_parameters_order_7() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_any_host_match() -> Router<Rule> {
@@ -10112,7 +10112,7 @@ fn test_rule_any_host_match_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -10123,7 +10123,7 @@ fn test_rule_any_host_match_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar-no-example"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -10142,7 +10142,7 @@ fn test_rule_any_host_match_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -10153,7 +10153,7 @@ fn test_rule_any_host_match_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar-no-example"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_any_host_no_match() -> Router<Rule> {
@@ -10187,7 +10187,7 @@ fn test_rule_any_host_no_match_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -10198,7 +10198,7 @@ fn test_rule_any_host_no_match_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar-no-example"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -10217,7 +10217,7 @@ fn test_rule_any_host_no_match_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -10228,7 +10228,7 @@ fn test_rule_any_host_no_match_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar-example"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_date_trigger_after() -> Router<Rule> {
@@ -10259,12 +10259,12 @@ fn test_rule_date_trigger_after_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -10285,12 +10285,12 @@ fn test_rule_date_trigger_after_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -10311,12 +10311,12 @@ fn test_rule_date_trigger_after_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -10427,12 +10427,12 @@ fn test_rule_date_trigger_before_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -10453,12 +10453,12 @@ fn test_rule_date_trigger_before_5() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_date_trigger_between() -> Router<Rule> {
@@ -10509,12 +10509,12 @@ fn test_rule_date_trigger_between_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -10535,12 +10535,12 @@ fn test_rule_date_trigger_between_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -10631,12 +10631,12 @@ fn test_rule_date_trigger_equals_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -10689,12 +10689,12 @@ fn test_rule_date_trigger_multiple_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 410);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -10715,12 +10715,12 @@ fn test_rule_date_trigger_multiple_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 410);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -10811,12 +10811,12 @@ fn test_rule_date_trigger_not_between_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -10877,12 +10877,12 @@ fn test_rule_date_trigger_not_between_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -10903,12 +10903,12 @@ fn test_rule_date_trigger_not_between_5() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_date_trigger_not_equals() -> Router<Rule> {
@@ -10939,12 +10939,12 @@ fn test_rule_date_trigger_not_equals_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -10985,12 +10985,12 @@ fn test_rule_date_trigger_not_equals_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_date_trigger_weekdays() -> Router<Rule> {
@@ -11023,12 +11023,12 @@ fn test_rule_date_trigger_weekdays_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -11049,12 +11049,12 @@ fn test_rule_date_trigger_weekdays_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -11125,12 +11125,12 @@ fn test_rule_datetime_trigger_after_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -11151,12 +11151,12 @@ fn test_rule_datetime_trigger_after_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -11177,12 +11177,12 @@ fn test_rule_datetime_trigger_after_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -11293,12 +11293,12 @@ fn test_rule_datetime_trigger_before_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -11319,12 +11319,12 @@ fn test_rule_datetime_trigger_before_5() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_datetime_trigger_between() -> Router<Rule> {
@@ -11375,12 +11375,12 @@ fn test_rule_datetime_trigger_between_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -11401,12 +11401,12 @@ fn test_rule_datetime_trigger_between_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -11477,12 +11477,12 @@ fn test_rule_datetime_trigger_not_between_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -11543,12 +11543,12 @@ fn test_rule_datetime_trigger_not_between_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -11569,12 +11569,12 @@ fn test_rule_datetime_trigger_not_between_5() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_header_regex() -> Router<Rule> {
@@ -11643,7 +11643,7 @@ fn test_rule_header_regex_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -11654,7 +11654,7 @@ fn test_rule_header_regex_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/es"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_host_port() -> Router<Rule> {
@@ -11721,7 +11721,7 @@ fn test_rule_host_port_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -11732,7 +11732,7 @@ fn test_rule_host_port_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"http://example.org:8081/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_ip_trigger() -> Router<Rule> {
@@ -11784,12 +11784,12 @@ fn test_rule_ip_trigger_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -11808,12 +11808,12 @@ fn test_rule_ip_trigger_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -11832,12 +11832,12 @@ fn test_rule_ip_trigger_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -11904,12 +11904,12 @@ fn test_rule_ip_trigger_equals_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_ip_trigger_greater_than() -> Router<Rule> {
@@ -11958,12 +11958,12 @@ fn test_rule_ip_trigger_greater_than_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -11982,12 +11982,12 @@ fn test_rule_ip_trigger_greater_than_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_ip_trigger_greater_than_or_equals() -> Router<Rule> {
@@ -12036,12 +12036,12 @@ fn test_rule_ip_trigger_greater_than_or_equals_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -12060,12 +12060,12 @@ fn test_rule_ip_trigger_greater_than_or_equals_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_ip_trigger_in_range() -> Router<Rule> {
@@ -12114,12 +12114,12 @@ fn test_rule_ip_trigger_in_range_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_ip_trigger_less_than() -> Router<Rule> {
@@ -12168,12 +12168,12 @@ fn test_rule_ip_trigger_less_than_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -12192,12 +12192,12 @@ fn test_rule_ip_trigger_less_than_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_ip_trigger_less_than_or_equals() -> Router<Rule> {
@@ -12246,12 +12246,12 @@ fn test_rule_ip_trigger_less_than_or_equals_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -12270,12 +12270,12 @@ fn test_rule_ip_trigger_less_than_or_equals_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_ip_trigger_not_equals() -> Router<Rule> {
@@ -12324,12 +12324,12 @@ fn test_rule_ip_trigger_not_equals_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_ip_trigger_not_in_range() -> Router<Rule> {
@@ -12378,12 +12378,12 @@ fn test_rule_ip_trigger_not_in_range_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_ip_trigger_not_one_of() -> Router<Rule> {
@@ -12450,12 +12450,12 @@ fn test_rule_ip_trigger_not_one_of_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_methods_trigger() -> Router<Rule> {
@@ -12489,7 +12489,7 @@ fn test_rule_methods_trigger_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -12500,7 +12500,7 @@ fn test_rule_methods_trigger_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -12555,7 +12555,7 @@ fn test_rule_methods_trigger_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -12566,7 +12566,7 @@ fn test_rule_methods_trigger_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_multiple_headers() -> Router<Rule> {
@@ -12655,7 +12655,7 @@ fn test_rule_multiple_headers_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -12666,7 +12666,7 @@ fn test_rule_multiple_headers_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_query_with_pipe() -> Router<Rule> {
@@ -12700,7 +12700,7 @@ fn test_rule_query_with_pipe_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -12711,7 +12711,7 @@ fn test_rule_query_with_pipe_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target-urlencoded"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -12730,7 +12730,7 @@ fn test_rule_query_with_pipe_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -12741,7 +12741,7 @@ fn test_rule_query_with_pipe_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target-urlencoded"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_query_with_pipe_2() -> Router<Rule> {
@@ -12772,7 +12772,7 @@ fn test_rule_query_with_pipe_2_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -12783,7 +12783,7 @@ fn test_rule_query_with_pipe_2_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -12802,7 +12802,7 @@ fn test_rule_query_with_pipe_2_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -12813,7 +12813,7 @@ fn test_rule_query_with_pipe_2_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_query_with_plus() -> Router<Rule> {
@@ -12862,7 +12862,7 @@ fn test_rule_query_with_plus_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -12873,7 +12873,7 @@ fn test_rule_query_with_plus_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -12892,7 +12892,7 @@ fn test_rule_query_with_plus_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -12903,7 +12903,7 @@ fn test_rule_query_with_plus_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -12922,7 +12922,7 @@ fn test_rule_query_with_plus_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -12933,7 +12933,7 @@ fn test_rule_query_with_plus_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_query_with_plus_2() -> Router<Rule> {
@@ -12964,7 +12964,7 @@ fn test_rule_query_with_plus_2_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -12975,7 +12975,7 @@ fn test_rule_query_with_plus_2_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target?foo=bar%2Bbaz"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_querystring() -> Router<Rule> {
@@ -13006,7 +13006,7 @@ fn test_rule_querystring_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -13017,7 +13017,7 @@ fn test_rule_querystring_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -13036,7 +13036,7 @@ fn test_rule_querystring_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -13047,7 +13047,7 @@ fn test_rule_querystring_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_response_status_codes_trigger() -> Router<Rule> {
@@ -13081,7 +13081,7 @@ fn test_rule_response_status_codes_trigger_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 200;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -13092,7 +13092,7 @@ fn test_rule_response_status_codes_trigger_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -13111,12 +13111,12 @@ fn test_rule_response_status_codes_trigger_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 400;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 0);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -13135,12 +13135,12 @@ fn test_rule_response_status_codes_trigger_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 200;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 0);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -13159,7 +13159,7 @@ fn test_rule_response_status_codes_trigger_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 400;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -13170,7 +13170,7 @@ fn test_rule_response_status_codes_trigger_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_sampling() -> Router<Rule> {
@@ -13204,12 +13204,12 @@ fn test_rule_sampling_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 0);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -13228,12 +13228,12 @@ fn test_rule_sampling_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_skipped_query_parameters() -> Router<Rule> {
@@ -13267,7 +13267,7 @@ fn test_rule_skipped_query_parameters_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -13278,7 +13278,7 @@ fn test_rule_skipped_query_parameters_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -13297,7 +13297,7 @@ fn test_rule_skipped_query_parameters_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -13308,7 +13308,7 @@ fn test_rule_skipped_query_parameters_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target?utm_source=test"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -13327,7 +13327,7 @@ fn test_rule_skipped_query_parameters_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -13338,7 +13338,7 @@ fn test_rule_skipped_query_parameters_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target?tutu=titi"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -13357,7 +13357,7 @@ fn test_rule_skipped_query_parameters_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -13368,7 +13368,7 @@ fn test_rule_skipped_query_parameters_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target?tutu=titi&utm_content=test&utm_source=test"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_time_trigger_after() -> Router<Rule> {
@@ -13421,12 +13421,12 @@ fn test_rule_time_trigger_after_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -13467,12 +13467,12 @@ fn test_rule_time_trigger_after_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_time_trigger_before() -> Router<Rule> {
@@ -13505,12 +13505,12 @@ fn test_rule_time_trigger_before_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -13551,12 +13551,12 @@ fn test_rule_time_trigger_before_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -13609,12 +13609,12 @@ fn test_rule_time_trigger_between_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -13635,12 +13635,12 @@ fn test_rule_time_trigger_between_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -13753,12 +13753,12 @@ fn test_rule_time_trigger_not_between_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -13779,12 +13779,12 @@ fn test_rule_time_trigger_not_between_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 302);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_with_header() -> Router<Rule> {
@@ -13821,7 +13821,7 @@ fn test_rule_with_header_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -13832,7 +13832,7 @@ fn test_rule_with_header_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bor"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -13852,7 +13852,7 @@ fn test_rule_with_header_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -13863,7 +13863,7 @@ fn test_rule_with_header_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/baz"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -13883,7 +13883,7 @@ fn test_rule_with_header_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -13894,7 +13894,7 @@ fn test_rule_with_header_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/baz/foo"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -13934,7 +13934,7 @@ fn test_rule_with_header_5() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -13945,7 +13945,7 @@ fn test_rule_with_header_5() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/baz/foofoo"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_with_method() -> Router<Rule> {
@@ -14015,7 +14015,7 @@ fn test_rule_with_method_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14026,7 +14026,7 @@ fn test_rule_with_method_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/baz"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -14045,7 +14045,7 @@ fn test_rule_with_method_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14056,7 +14056,7 @@ fn test_rule_with_method_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bor"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -14075,7 +14075,7 @@ fn test_rule_with_method_5() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14086,7 +14086,7 @@ fn test_rule_with_method_5() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bor"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_with_quotes() -> Router<Rule> {
@@ -14117,7 +14117,7 @@ fn test_rule_with_quotes_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14128,7 +14128,7 @@ fn test_rule_with_quotes_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target?gender=Dames&gender=Heren&gender=Kinderen"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_with_response_status_codes() -> Router<Rule> {
@@ -14168,12 +14168,12 @@ fn test_rule_with_response_status_codes_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 200;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 0);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -14192,12 +14192,12 @@ fn test_rule_with_response_status_codes_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 200;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 0);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -14216,7 +14216,7 @@ fn test_rule_with_response_status_codes_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 404;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14227,7 +14227,7 @@ fn test_rule_with_response_status_codes_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/baz"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -14246,7 +14246,7 @@ fn test_rule_with_response_status_codes_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 400;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14257,7 +14257,7 @@ fn test_rule_with_response_status_codes_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bor"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -14276,7 +14276,7 @@ fn test_rule_with_response_status_codes_5() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 402;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14287,7 +14287,7 @@ fn test_rule_with_response_status_codes_5() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bor"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -14306,7 +14306,7 @@ fn test_rule_with_response_status_codes_6() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 404;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14317,7 +14317,7 @@ fn test_rule_with_response_status_codes_6() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/A-target"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -14336,7 +14336,7 @@ fn test_rule_with_response_status_codes_7() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 200;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14347,7 +14347,7 @@ fn test_rule_with_response_status_codes_7() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/B-target"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -14366,12 +14366,12 @@ fn test_rule_with_response_status_codes_8() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
     assert_eq!(action_status_code, 0);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_with_slash() -> Router<Rule> {
@@ -14405,7 +14405,7 @@ fn test_rule_with_slash_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14416,7 +14416,7 @@ fn test_rule_with_slash_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -14435,7 +14435,7 @@ fn test_rule_with_slash_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14446,7 +14446,7 @@ fn test_rule_with_slash_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/bar/"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_rule_with_space() -> Router<Rule> {
@@ -14480,7 +14480,7 @@ fn test_rule_with_space_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14491,7 +14491,7 @@ fn test_rule_with_space_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/exact"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -14510,7 +14510,7 @@ fn test_rule_with_space_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14521,7 +14521,7 @@ fn test_rule_with_space_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/exact"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -14540,7 +14540,7 @@ fn test_rule_with_space_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14551,7 +14551,7 @@ fn test_rule_with_space_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/query-space"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -14570,7 +14570,7 @@ fn test_rule_with_space_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14581,7 +14581,7 @@ fn test_rule_with_space_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/query-space"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -14600,7 +14600,7 @@ fn test_rule_with_space_5() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14611,7 +14611,7 @@ fn test_rule_with_space_5() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/query-space"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_variable_body_html() -> Router<Rule> {
@@ -14642,7 +14642,7 @@ fn test_variable_body_html_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14655,7 +14655,7 @@ fn test_variable_body_html_1() {
     let mut new_body = body_filter.filter(r#"<html><body><h1>H1</h1><h2>H2</h2><h3>H3</h3></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body><h1>H1</h1><h2>H1</h2><h3>/source</h3></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -14674,7 +14674,7 @@ fn test_variable_body_html_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14687,7 +14687,7 @@ fn test_variable_body_html_2() {
     let mut new_body = body_filter.filter(r#"<html><body><h2>H2</h2></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body><h2>Foo</h2></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -14706,7 +14706,7 @@ fn test_variable_body_html_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14719,7 +14719,7 @@ fn test_variable_body_html_3() {
     let mut new_body = body_filter.filter(r#"<html><body><h2>H2</h2><h1>H1</h1></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body><h2>H1</h2><h1>H1</h1></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -14738,7 +14738,7 @@ fn test_variable_body_html_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14751,7 +14751,7 @@ fn test_variable_body_html_4() {
     let mut new_body = body_filter.filter(r#"<html><body><h2>H2</h2><h1>H1</h1><h1>H1V2</h1></body></html>"#.as_bytes().to_vec(), None);
     new_body.extend(body_filter.end(None));
     assert_eq!(&String::from_utf8(new_body).unwrap(), r#"<html><body><h2>H1V2</h2><h1>H1</h1><h1>H1V2</h1></body></html>"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_variable_marker() -> Router<Rule> {
@@ -14782,7 +14782,7 @@ fn test_variable_marker_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14793,7 +14793,7 @@ fn test_variable_marker_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/camelize/target/helloPoney"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -14812,7 +14812,7 @@ fn test_variable_marker_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14823,7 +14823,7 @@ fn test_variable_marker_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/camelize/target/helloPoney"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -14842,7 +14842,7 @@ fn test_variable_marker_3() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14853,7 +14853,7 @@ fn test_variable_marker_3() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/camelize/target/helloPoney"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -14872,7 +14872,7 @@ fn test_variable_marker_4() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14883,7 +14883,7 @@ fn test_variable_marker_4() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/camelize/target/helloPOney"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_variable_marker_legacy() -> Router<Rule> {
@@ -14914,7 +14914,7 @@ fn test_variable_marker_legacy_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14925,7 +14925,7 @@ fn test_variable_marker_legacy_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/camelize/helloPoney/target/helloPoney"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_variable_marker_legacy_1() -> Router<Rule> {
@@ -14956,7 +14956,7 @@ fn test_variable_marker_legacy_1_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -14967,7 +14967,7 @@ fn test_variable_marker_legacy_1_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/camelize/helloPoney/target/helloPoney"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_variable_marker_order() -> Router<Rule> {
@@ -14998,7 +14998,7 @@ fn test_variable_marker_order_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -15009,7 +15009,7 @@ fn test_variable_marker_order_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/camelize/helloPoney/https/target/test.com"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_variable_marker_transformer() -> Router<Rule> {
@@ -15043,7 +15043,7 @@ fn test_variable_marker_transformer_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -15054,7 +15054,7 @@ fn test_variable_marker_transformer_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/camelize/target/HELLO"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -15073,7 +15073,7 @@ fn test_variable_marker_transformer_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -15084,7 +15084,7 @@ fn test_variable_marker_transformer_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/DOG"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_variable_request_header() -> Router<Rule> {
@@ -15116,7 +15116,7 @@ fn test_variable_request_header_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -15127,7 +15127,7 @@ fn test_variable_request_header_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target/request-header/helloPoney"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -15146,7 +15146,7 @@ fn test_variable_request_header_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -15157,7 +15157,7 @@ fn test_variable_request_header_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target/request-header/Foo"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_variable_request_host() -> Router<Rule> {
@@ -15188,7 +15188,7 @@ fn test_variable_request_host_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -15199,7 +15199,7 @@ fn test_variable_request_host_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target/request-header/example.com"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_variable_request_method() -> Router<Rule> {
@@ -15230,7 +15230,7 @@ fn test_variable_request_method_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -15241,7 +15241,7 @@ fn test_variable_request_method_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target/request-header/GET"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -15260,7 +15260,7 @@ fn test_variable_request_method_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -15271,7 +15271,7 @@ fn test_variable_request_method_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target/request-header/POST"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_variable_request_path() -> Router<Rule> {
@@ -15302,7 +15302,7 @@ fn test_variable_request_path_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -15313,7 +15313,7 @@ fn test_variable_request_path_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target/request-header/variable/request-header"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 fn setup_variable_request_scheme() -> Router<Rule> {
@@ -15344,7 +15344,7 @@ fn test_variable_request_scheme_1() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -15355,7 +15355,7 @@ fn test_variable_request_scheme_1() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target/request-header/https"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 #[test]
@@ -15374,7 +15374,7 @@ fn test_variable_request_scheme_2() {
     assert_eq!(!matched.is_empty(), true);
     assert_eq!(!routes_traces.is_empty(), true);
 
-    let mut action = Action::from_routes_rule(matched, &request_configured, None);
+    let mut action = Action::from_routes_rule(&router, matched, &request_configured, None);
     let response_status_code = 0;
 
     let action_status_code = action.get_status_code(response_status_code, None);
@@ -15385,7 +15385,7 @@ fn test_variable_request_scheme_2() {
     let target_header = headers.first().unwrap();
     assert_eq!(target_header.name, "Location");
     assert_eq!(target_header.value, r#"/target/request-header/http"#);
-    assert_eq!(action.should_log_request(true, response_status_code, None), true);
+    assert!(action.should_log_request(true, response_status_code, None));
 }
 
 
@@ -8,6 +8,11 @@ mod generated_tests {
     use serde_json::{from_str as json_decode, to_string_pretty as json_encode};
     use std::env;
 
+    #[test]
+    fn test_examples_case_sensitivity_override() {
+        do_test("case_sensitivity_override");
+    }
+
     #[test]
     fn test_examples_configuration_log_off() {
         do_test("configuration_log_off");
@@ -18,11 +23,26 @@ mod generated_tests {
         do_test("configuration_reset_on");
     }
 
+    #[test]
+    fn test_examples_edit_json() {
+        do_test("edit_json");
+    }
+
     #[test]
     fn test_examples_filter_html() {
         do_test("filter_html");
     }
 
+    #[test]
+    fn test_examples_filter_html_attributes() {
+        do_test("filter_html_attributes");
+    }
+
+    #[test]
+    fn test_examples_head_metadata() {
+        do_test("head_metadata");
+    }
+
     #[test]
     fn test_examples_header_add() {
         do_test("header_add");
@@ -43,6 +63,11 @@ mod generated_tests {
         do_test("header_replace");
     }
 
+    #[test]
+    fn test_examples_internal_rewrite() {
+        do_test("internal_rewrite");
+    }
+
     #[test]
     fn test_examples_must_match_false_broken() {
         do_test("must_match_false_broken");
@@ -68,6 +93,51 @@ mod generated_tests {
         do_test("one_rule_one_example");
     }
 
+    #[test]
+    fn test_examples_peer_pool() {
+        do_test("peer_pool");
+    }
+
+    #[test]
+    fn test_examples_replace_regex() {
+        do_test("replace_regex");
+    }
+
+    #[test]
+    fn test_examples_request_header_filters() {
+        do_test("request_header_filters");
+    }
+
+    #[test]
+    fn test_examples_response_header_conditions() {
+        do_test("response_header_conditions");
+    }
+
+    #[test]
+    fn test_examples_response_status_code_ranges() {
+        do_test("response_status_code_ranges");
+    }
+
+    #[test]
+    fn test_examples_rewrite_passes() {
+        do_test("rewrite_passes");
+    }
+
+    #[test]
+    fn test_examples_synthetic_response() {
+        do_test("synthetic_response");
+    }
+
+    #[test]
+    fn test_examples_typed_markers() {
+        do_test("typed_markers");
+    }
+
+    #[test]
+    fn test_examples_xml_sitemap() {
+        do_test("xml_sitemap");
+    }
+
     fn do_test(name: &str) {
         let json_in = std::fs::read_to_string(format!("tests/test_examples/{}.in.json", name)).unwrap();
         let test_examples_input: TestExamplesInput = json_decode(&json_in).unwrap();